@@ -0,0 +1,149 @@
+//! Measures how long `execute_block` takes for blocks of text transactions.
+//!
+//! Every transaction is signed by its own account, so that the transactions of a block are
+//! independent of each other, like the texts of many chat users would be.
+
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use astria_core::{
+    crypto::SigningKey,
+    generated::astria::{
+        execution::v1::{execution_service_server::ExecutionService as _, ExecuteBlockRequest},
+        primitive::v1::{Address, Uint128},
+        sequencerblock::v1::{rollup_data::Value, RollupData},
+    },
+    primitive::v1::Address as DomainAddress,
+    Protobuf as _,
+};
+use bytes::Bytes;
+use chat_rollup::{
+    execution_service::RollupExecutionService,
+    rollup::{init_genesis_state, RollupConfig},
+};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use prost::Message as _;
+use rollup_core::{
+    generated::protocol::genesis::v1::{Account, GenesisAppState},
+    transaction::v1::{action::SendText, Action, TransactionBody},
+};
+
+const ROLLUP_NAME: &str = "astria-chat";
+
+fn signing_key(index: usize) -> SigningKey {
+    let mut bytes = [1; 32];
+    bytes[..8].copy_from_slice(&(index as u64).to_le_bytes());
+    SigningKey::from(bytes)
+}
+
+fn genesis(accounts: usize) -> GenesisAppState {
+    GenesisAppState {
+        rollup_name: ROLLUP_NAME.to_string(),
+        accounts: (0..accounts)
+            .map(|index| Account {
+                address: Some(Address {
+                    bech32m: DomainAddress::builder()
+                        .prefix("astria")
+                        .array(signing_key(index).address_bytes())
+                        .try_build()
+                        .unwrap()
+                        .to_string(),
+                }),
+                balance: Some(Uint128 { lo: 1_000, hi: 0 }),
+            })
+            .collect(),
+        ..GenesisAppState::default()
+    }
+}
+
+fn text_transaction(index: usize) -> Bytes {
+    TransactionBody::builder()
+        .nonce(0)
+        .chain_id(ROLLUP_NAME)
+        .actions(vec![Action::Text(SendText {
+            text: format!("gm from account {index}"),
+            from: format!("user-{index}"),
+            fee_asset: "nria".parse().unwrap(),
+        })])
+        .try_build()
+        .unwrap()
+        .sign(&signing_key(index))
+        .into_raw()
+        .encode_to_vec()
+        .into()
+}
+
+/// Returns an execution service over fresh storage holding the genesis state, and a request to
+/// execute a block of `transactions` text transactions on top of it.
+async fn setup(
+    transactions: usize,
+) -> (
+    cnidarium::TempStorage,
+    Arc<RollupExecutionService>,
+    ExecuteBlockRequest,
+) {
+    let storage = cnidarium::TempStorage::new().await.unwrap();
+    init_genesis_state(&storage, &genesis(transactions))
+        .await
+        .unwrap();
+    let service = Arc::new(RollupExecutionService::new(
+        cnidarium::Storage::clone(&storage),
+        RollupConfig {
+            execution_grpc_addr: String::new(),
+            composer_addr: String::new(),
+            rollup_name: ROLLUP_NAME.to_string(),
+            sequencer_genesis_block_height: 0,
+            celestia_genesis_block_height: 0,
+            celestia_block_variance: 0,
+            bridge_accounts: Vec::new(),
+        },
+    ));
+    let request = ExecuteBlockRequest {
+        prev_block_hash: Bytes::from_static(&[69; 32]),
+        transactions: (0..transactions)
+            .map(|index| RollupData {
+                value: Some(Value::SequencedData(text_transaction(index))),
+            })
+            .collect(),
+        timestamp: Some(pbjson_types::Timestamp {
+            seconds: 1,
+            nanos: 0,
+        }),
+    };
+    (storage, service, request)
+}
+
+fn execute_block(c: &mut Criterion) {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let mut group = c.benchmark_group("execute_block");
+    group.sample_size(10);
+    for transactions in [1_000, 10_000] {
+        group.throughput(Throughput::Elements(transactions as u64));
+        group.bench_with_input(
+            BenchmarkId::new("text_transactions", transactions),
+            &transactions,
+            |b, &transactions| {
+                // every block needs fresh state, as its transactions consume their nonces
+                b.to_async(&runtime).iter_custom(|iterations| async move {
+                    let mut elapsed = Duration::ZERO;
+                    for _ in 0..iterations {
+                        let (_storage, service, request) = setup(transactions).await;
+                        let start = Instant::now();
+                        service
+                            .execute_block(tonic::Request::new(request))
+                            .await
+                            .unwrap();
+                        elapsed += start.elapsed();
+                    }
+                    elapsed
+                });
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, execute_block);
+criterion_main!(benches);
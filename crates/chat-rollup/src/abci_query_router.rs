@@ -0,0 +1,192 @@
+//! Routes ABCI-style queries to the handlers reading the rollup state.
+//!
+//! Queries are addressed by a path such as `accounts/balance/<address>`, mirroring the
+//! `abci_query` paths served by the sequencer, so that existing tooling can query the rollup
+//! and decode the protobuf encoded responses.
+
+use std::{future::Future, pin::Pin, sync::Arc};
+
+use astria_core::protocol::abci::AbciErrorCode;
+use astria_eyre::eyre::{Result, WrapErr as _};
+use cnidarium::Storage;
+use tendermint::abci::{request, response, Code};
+
+type BoxedQueryFuture = Pin<Box<dyn Future<Output = response::Query> + Send>>;
+
+type BoxedQueryHandler =
+    Arc<dyn Fn(Storage, request::Query, Vec<(String, String)>) -> BoxedQueryFuture + Send + Sync>;
+
+/// Maps query paths to the handlers serving them.
+#[derive(Clone)]
+pub(crate) struct Router {
+    query_router: matchit::Router<BoxedQueryHandler>,
+}
+
+impl Router {
+    /// Returns a router serving all queries supported by the rollup.
+    pub(crate) fn new() -> Result<Self> {
+        let mut router = Self {
+            query_router: matchit::Router::new(),
+        };
+        router.insert(
+            "accounts/balance/:account",
+            crate::accounts::query::balance_request,
+        )?;
+        router.insert(
+            "accounts/nonce/:account",
+            crate::accounts::query::nonce_request,
+        )?;
+        router.insert("asset/denom/:id", crate::assets::query::denom_request)?;
+        Ok(router)
+    }
+
+    fn insert<F, Fut>(&mut self, route: &str, handler: F) -> Result<()>
+    where
+        F: Fn(Storage, request::Query, Vec<(String, String)>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = response::Query> + Send + 'static,
+    {
+        let handler: BoxedQueryHandler = Arc::new(
+            move |storage: Storage,
+                  request: request::Query,
+                  params: Vec<(String, String)>|
+                  -> BoxedQueryFuture { Box::pin(handler(storage, request, params)) },
+        );
+        self.query_router
+            .insert(route, handler)
+            .wrap_err_with(|| format!("failed to register query route `{route}`"))
+    }
+
+    /// Serves `request` with the handler registered for its path, passing it the parameters
+    /// captured from the path.
+    pub(crate) async fn handle(
+        &self,
+        storage: Storage,
+        request: request::Query,
+    ) -> response::Query {
+        let (handler, params) = match self.query_router.at(&request.path) {
+            Ok(matched) => (
+                matched.value.clone(),
+                matched
+                    .params
+                    .iter()
+                    .map(|(key, value)| (key.to_string(), value.to_string()))
+                    .collect(),
+            ),
+            Err(err) => {
+                return response::Query {
+                    code: Code::Err(AbciErrorCode::UNKNOWN_PATH.value()),
+                    info: AbciErrorCode::UNKNOWN_PATH.info(),
+                    log: format!("provided path `{}` is unknown: {err:#}", request.path),
+                    ..response::Query::default()
+                };
+            }
+        };
+        handler(storage, request, params).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{
+        primitive::v1::asset,
+        protocol::{account::v1::NonceResponse, asset::v1::DenomResponse},
+        Protobuf,
+    };
+    use cnidarium::StateDelta;
+    use prost::Message as _;
+
+    use super::*;
+    use crate::{
+        accounts::{state_ext::astria_address, StateWriteExt as _},
+        assets::StateWriteExt as _,
+        execution_service::commit_state_delta,
+        rollup::state_ext::StateWriteExt as _,
+    };
+
+    fn query(path: impl Into<String>) -> request::Query {
+        request::Query {
+            data: Default::default(),
+            path: path.into(),
+            height: 0u32.into(),
+            prove: false,
+        }
+    }
+
+    async fn storage_with_state() -> cnidarium::TempStorage {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_block_height(1).unwrap();
+        state
+            .put_account_nonce(&astria_address(&[42u8; 20]), 3)
+            .unwrap();
+        state
+            .put_ibc_asset("transfer/channel-0/utia".parse().unwrap())
+            .unwrap();
+        commit_state_delta(&storage, state).await.unwrap();
+        storage
+    }
+
+    #[tokio::test]
+    async fn unknown_path_is_rejected() {
+        let storage = storage_with_state().await;
+        let router = Router::new().unwrap();
+
+        let response = router
+            .handle((*storage).clone(), query("accounts/unknown/path"))
+            .await;
+        assert_eq!(
+            response.code,
+            Code::Err(AbciErrorCode::UNKNOWN_PATH.value())
+        );
+    }
+
+    #[tokio::test]
+    async fn nonce_is_routed() {
+        let storage = storage_with_state().await;
+        let router = Router::new().unwrap();
+
+        let address = astria_address(&[42u8; 20]);
+        let response = router
+            .handle(
+                (*storage).clone(),
+                query(format!("accounts/nonce/{address}")),
+            )
+            .await;
+        assert_eq!(response.code, Code::Ok, "{}", response.log);
+        let nonce = NonceResponse::try_from_raw(
+            <NonceResponse as Protobuf>::Raw::decode(response.value).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(nonce.nonce, 3);
+        assert_eq!(nonce.height, 1);
+    }
+
+    #[tokio::test]
+    async fn denom_is_routed() {
+        let storage = storage_with_state().await;
+        let router = Router::new().unwrap();
+
+        let denom: asset::TracePrefixed = "transfer/channel-0/utia".parse().unwrap();
+        let id = hex::encode(denom.to_ibc_prefixed().get());
+        let response = router
+            .handle((*storage).clone(), query(format!("asset/denom/{id}")))
+            .await;
+        assert_eq!(response.code, Code::Ok, "{}", response.log);
+        let response = DenomResponse::try_from_raw(
+            <DenomResponse as Protobuf>::Raw::decode(response.value).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(response.denom, asset::Denom::from(denom));
+
+        let response = router
+            .handle(
+                (*storage).clone(),
+                query(format!("asset/denom/{}", hex::encode([0u8; 32]))),
+            )
+            .await;
+        assert_eq!(
+            response.code,
+            Code::Err(AbciErrorCode::VALUE_NOT_FOUND.value())
+        );
+    }
+}
@@ -1,4 +1,6 @@
 pub(crate) mod action;
+pub mod proof;
+pub(crate) mod query;
 pub(crate) mod state_ext;
 pub(crate) mod storage;
 
@@ -0,0 +1,108 @@
+use astria_core::primitive::v1::{asset, Address};
+use astria_eyre::eyre::{Result, WrapErr as _};
+use cnidarium::Snapshot;
+use rollup_core::proof::StateProof;
+
+use super::storage::{self, keys};
+use crate::storage::StoredValue;
+
+/// Returns the balance of `asset` held by `address` together with a proof against the state
+/// root of `snapshot`.
+pub(crate) async fn balance_with_proof(
+    snapshot: &Snapshot,
+    address: &Address,
+    asset: &asset::Denom,
+) -> Result<(u128, StateProof)> {
+    let proof = crate::proof::get_with_proof(snapshot, keys::balance(address, asset)).await?;
+    let balance = decode_balance(proof.value.as_deref())?;
+    Ok((balance, proof))
+}
+
+/// Returns the nonce of `address` together with a proof against the state root of `snapshot`.
+pub(crate) async fn nonce_with_proof(
+    snapshot: &Snapshot,
+    address: &Address,
+) -> Result<(u32, StateProof)> {
+    let proof = crate::proof::get_with_proof(snapshot, keys::nonce(address)).await?;
+    let nonce = decode_nonce(proof.value.as_deref())?;
+    Ok((nonce, proof))
+}
+
+/// Decodes the raw value of a balance proof. An absent value is a balance of zero.
+///
+/// # Errors
+/// Returns an error if the bytes are not a stored balance.
+pub fn decode_balance(value: Option<&[u8]>) -> Result<u128> {
+    let Some(bytes) = value else {
+        return Ok(0);
+    };
+    StoredValue::deserialize(bytes)
+        .and_then(|value| storage::Balance::try_from(value).map(u128::from))
+        .wrap_err("invalid balance bytes")
+}
+
+/// Decodes the raw value of a nonce proof. An absent value is a nonce of zero.
+///
+/// # Errors
+/// Returns an error if the bytes are not a stored nonce.
+pub fn decode_nonce(value: Option<&[u8]>) -> Result<u32> {
+    let Some(bytes) = value else {
+        return Ok(0);
+    };
+    StoredValue::deserialize(bytes)
+        .and_then(|value| storage::Nonce::try_from(value).map(u32::from))
+        .wrap_err("invalid nonce bytes")
+}
+
+#[cfg(test)]
+mod tests {
+    use cnidarium::StateDelta;
+
+    use super::*;
+    use crate::{
+        accounts::{state_ext::astria_address, StateWriteExt as _},
+        rollup::state_ext::StateWriteExt as _,
+    };
+
+    #[tokio::test]
+    async fn balance_with_proof_verifies_against_state_root() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let address = astria_address(&[42u8; 20]);
+        let other = astria_address(&[43u8; 20]);
+        let asset: asset::Denom = "asset_0".parse().unwrap();
+        state.put_block_height(1).unwrap();
+        state.put_account_balance(&address, &asset, 1000).unwrap();
+        state.put_account_nonce(&address, 3).unwrap();
+        let state_root = crate::execution_service::commit_state_delta(&storage, state)
+            .await
+            .unwrap();
+        let snapshot = storage.latest_snapshot();
+
+        let (balance, proof) = balance_with_proof(&snapshot, &address, &asset)
+            .await
+            .unwrap();
+        assert_eq!(balance, 1000);
+        assert_eq!(proof.height, 1);
+        proof.verify_against(&state_root.0).unwrap();
+
+        let (nonce, proof) = nonce_with_proof(&snapshot, &address).await.unwrap();
+        assert_eq!(nonce, 3);
+        proof.verify_against(&state_root.0).unwrap();
+
+        // absent keys are proven by a non-membership proof
+        let (balance, proof) = balance_with_proof(&snapshot, &other, &asset).await.unwrap();
+        assert_eq!(balance, 0);
+        assert!(proof.value.is_none());
+        proof.verify_against(&state_root.0).unwrap();
+
+        // a tampered value must not verify
+        let (_, mut proof) = balance_with_proof(&snapshot, &address, &asset)
+            .await
+            .unwrap();
+        proof.value = Some(vec![0; 4]);
+        proof.verify_against(&state_root.0).unwrap_err();
+    }
+}
@@ -27,8 +27,10 @@ async fn ibc_to_trace<S: StateRead>(
         .ok_or_eyre("asset not found when user has balance of it; this is a bug")
 }
 
+/// Returns the balances of all assets held by `address`, with the assets mapped to their
+/// trace-prefixed denoms. The balances are not sorted.
 #[instrument(skip_all, fields(%address))]
-async fn get_trace_prefixed_account_balances<S: StateRead>(
+pub(crate) async fn get_trace_prefixed_account_balances<S: StateRead>(
     state: &S,
     address: &Address,
 ) -> Result<Vec<AssetBalance>> {
@@ -191,3 +193,56 @@ async fn preprocess_request(
     };
     Ok((address, snapshot, height))
 }
+
+#[cfg(test)]
+mod tests {
+    use cnidarium::StateDelta;
+
+    use super::*;
+    use crate::{
+        accounts::{state_ext::astria_address, StateWriteExt as _},
+        assets::StateWriteExt as _,
+    };
+
+    #[tokio::test]
+    async fn trace_prefixed_account_balances() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let asset_0: asset::TracePrefixed = "nria".parse().unwrap();
+        let asset_1: asset::TracePrefixed = "transfer/channel-0/utia".parse().unwrap();
+        state.put_ibc_asset(asset_0.clone()).unwrap();
+        state.put_ibc_asset(asset_1.clone()).unwrap();
+
+        let address = astria_address(&[42u8; 20]);
+        state.put_account_balance(&address, &asset_0, 1).unwrap();
+        state.put_account_balance(&address, &asset_1, 2).unwrap();
+
+        let mut balances = get_trace_prefixed_account_balances(&state, &address)
+            .await
+            .unwrap();
+        balances.sort_unstable_by(|a, b| a.denom.cmp(&b.denom));
+
+        assert_eq!(balances.len(), 2);
+        assert_eq!(balances[0].denom, asset::Denom::from(asset_0));
+        assert_eq!(balances[0].balance, 1);
+        assert_eq!(balances[1].denom, asset::Denom::from(asset_1));
+        assert_eq!(balances[1].balance, 2);
+    }
+
+    #[tokio::test]
+    async fn trace_prefixed_account_balances_unregistered_asset_fails() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let asset: asset::TracePrefixed = "nria".parse().unwrap();
+        let address = astria_address(&[42u8; 20]);
+        state.put_account_balance(&address, &asset, 1).unwrap();
+
+        get_trace_prefixed_account_balances(&state, &address)
+            .await
+            .unwrap_err();
+    }
+}
@@ -7,6 +7,7 @@ use astria_eyre::{
 };
 use async_trait::async_trait;
 use cnidarium::{StateRead, StateWrite};
+use futures::TryStreamExt as _;
 use tracing::instrument;
 
 use super::storage::{
@@ -69,11 +70,26 @@ pub(crate) trait StateReadExt: StateRead {
             })
             .wrap_err("invalid ibc asset bytes")
     }
+
+    /// Returns all registered ibc assets in the order of their ibc prefixed ids.
+    #[instrument(skip_all)]
+    async fn get_ibc_assets(&self) -> Result<Vec<asset::TracePrefixed>> {
+        self.prefix_raw(keys::IBC_ASSET_PREFIX)
+            .map_err(|err| anyhow_to_eyre(err).wrap_err("failed reading raw assets from state"))
+            .and_then(|(_, bytes)| async move {
+                StoredValue::deserialize(&bytes)
+                    .and_then(|value| {
+                        storage::TracePrefixedDenom::try_from(value).map(asset::TracePrefixed::from)
+                    })
+                    .wrap_err("invalid ibc asset bytes")
+            })
+            .try_collect()
+            .await
+    }
 }
 
 impl<T: ?Sized + StateRead> StateReadExt for T {}
 
-#[allow(dead_code)]
 #[async_trait]
 pub(crate) trait StateWriteExt: StateWrite {
     #[instrument(skip_all)]
@@ -168,6 +184,28 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn get_ibc_assets() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        assert!(state.get_ibc_assets().await.unwrap().is_empty());
+
+        // the native asset is not listed unless registered as an ibc asset
+        let native: asset::TracePrefixed = "nria".parse().unwrap();
+        state.put_native_asset(native.clone()).unwrap();
+        assert!(state.get_ibc_assets().await.unwrap().is_empty());
+
+        state.put_ibc_asset(native.clone()).unwrap();
+        state
+            .put_ibc_asset(asset_0().unwrap_trace_prefixed())
+            .unwrap();
+        let mut assets = state.get_ibc_assets().await.unwrap();
+        assets.sort_unstable_by_key(ToString::to_string);
+        assert_eq!(assets, vec![asset_0().unwrap_trace_prefixed(), native]);
+    }
+
     #[tokio::test]
     async fn has_ibc_asset() {
         let storage = cnidarium::TempStorage::new().await.unwrap();
@@ -5,6 +5,7 @@ use astria_core::primitive::v1::asset::IbcPrefixed;
 use crate::storage::keys::Asset;
 
 pub(in crate::assets) const NATIVE_ASSET: &str = "assets/native_asset";
+pub(in crate::assets) const IBC_ASSET_PREFIX: &str = "assets/ibc/";
 
 /// Example: `assets/ibc/0101....0101`.
 ///                     |64 hex chars|
@@ -32,4 +33,10 @@ mod tests {
         assert!(NATIVE_ASSET.starts_with(COMPONENT_PREFIX));
         assert!(asset(&test_asset()).starts_with(COMPONENT_PREFIX));
     }
+
+    #[test]
+    fn asset_keys_should_have_ibc_asset_prefix() {
+        assert!(asset(&test_asset()).starts_with(IBC_ASSET_PREFIX));
+        assert!(!NATIVE_ASSET.starts_with(IBC_ASSET_PREFIX));
+    }
 }
@@ -0,0 +1,516 @@
+use astria_core::{
+    primitive::v1::{asset, Address, RollupId},
+    sequencerblock::v1::block::Deposit,
+};
+use astria_eyre::eyre::{Result, WrapErr as _};
+use cnidarium::{StateRead, StateWrite};
+use serde::Deserialize;
+use tracing::debug;
+
+use super::{
+    state_ext::{StateReadExt as _, StateWriteExt as _},
+    DepositStatus, Refund,
+};
+use crate::{
+    accounts::StateWriteExt as _,
+    assets::{StateReadExt as _, StateWriteExt as _},
+    rollup::block_limits::BlockBudget,
+    text::{
+        action::{check_text_length, post_text},
+        StateReadExt as _,
+    },
+};
+
+/// Why a deposit may not be processed by the rollup.
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub(crate) enum DepositRejected {
+    #[error("`{0}` is not a bridge account")]
+    NotBridge(Address),
+    #[error("bridge account has no config")]
+    NoBridgeConfig,
+    #[error(
+        "deposit is for rollup `{deposit_rollup_id}` but the bridge account deposits to rollup \
+         `{bridge_rollup_id}`"
+    )]
+    OtherRollup {
+        deposit_rollup_id: RollupId,
+        bridge_rollup_id: RollupId,
+    },
+    #[error("bridge account does not lock asset `{0}`")]
+    OtherAsset(asset::Denom),
+    #[error("deposit was already processed")]
+    AlreadyProcessed,
+}
+
+/// Checks whether `deposit` may be processed by the rollup, returning why not if it may not.
+///
+/// A deposit is only processed if it was made through a known bridge account, is addressed to
+/// the rollup the bridge account deposits to, is denominated in the asset the bridge account
+/// locks, and was not processed before.
+///
+/// # Errors
+/// Returns an error if reading the state fails.
+pub(crate) async fn check_deposit<S: StateRead>(
+    state: &S,
+    deposit: &Deposit,
+) -> Result<Option<DepositRejected>> {
+    if state
+        .is_deposit_processed(
+            &deposit.source_transaction_id.get(),
+            deposit.source_action_index,
+        )
+        .await
+        .wrap_err("failed to check whether deposit was already processed")?
+    {
+        return Ok(Some(DepositRejected::AlreadyProcessed));
+    }
+    if !state
+        .is_bridge(&deposit.bridge_address)
+        .await
+        .wrap_err("failed to check whether deposit address is a bridge account")?
+    {
+        return Ok(Some(DepositRejected::NotBridge(deposit.bridge_address)));
+    }
+    let Some(config) = state
+        .get_bridge_config(&deposit.bridge_address)
+        .await
+        .wrap_err("failed to read bridge config")?
+    else {
+        return Ok(Some(DepositRejected::NoBridgeConfig));
+    };
+    if deposit.rollup_id != config.rollup_id {
+        return Ok(Some(DepositRejected::OtherRollup {
+            deposit_rollup_id: deposit.rollup_id,
+            bridge_rollup_id: config.rollup_id,
+        }));
+    }
+    if deposit.asset.to_ibc_prefixed() != config.asset {
+        return Ok(Some(DepositRejected::OtherAsset(deposit.asset.clone())));
+    }
+    Ok(None)
+}
+
+/// Executes a deposit that passed [`check_deposit`] as part of the block at `height`.
+///
+/// The deposit is credited to its destination address. If the destination is invalid the deposit
+/// is recorded as failed instead, and a [`Refund`] withdrawal event is emitted for the bridge
+/// withdrawer to return the funds on the sequencer.
+///
+/// The message a deposit carries (if any) is posted attributed to the depositor: the rollup
+/// address the memo credits, as deposits do not name the sequencer account they were made from.
+/// A message longer than the text length limit, or exceeding the text bytes left in the block of
+/// `budget`, is not posted, but the deposit is still credited.
+///
+/// Returns the status the deposit was recorded with.
+pub(crate) async fn execute_deposit<S: StateWrite>(
+    state: &mut S,
+    deposit: &Deposit,
+    height: u32,
+    budget: &mut BlockBudget,
+) -> Result<DepositStatus> {
+    let source_transaction_id = deposit.source_transaction_id.get();
+    let status = match parse_destination(&deposit.destination_chain_address) {
+        Ok((destination, message)) => {
+            // register the asset so that balances of it can be mapped back to its denom
+            if let Some(asset) = deposit.asset.as_trace_prefixed() {
+                if !state
+                    .has_ibc_asset(asset)
+                    .await
+                    .wrap_err("failed to check whether deposit asset is registered")?
+                {
+                    state
+                        .put_ibc_asset(asset.clone())
+                        .wrap_err("failed to register deposit asset")?;
+                }
+            }
+            state
+                .increase_balance(&destination, &deposit.asset, deposit.amount)
+                .await
+                .wrap_err("failed to credit deposit")?;
+            if let Some(message) = message.filter(|message| !message.is_empty()) {
+                let params = state
+                    .get_text_params()
+                    .await
+                    .wrap_err("failed to get text params")?;
+                let checked = check_text_length(&message, &params).and_then(|()| {
+                    budget
+                        .try_add_deposit_text(message.len().try_into().unwrap_or(u64::MAX))
+                        .map_err(Into::into)
+                });
+                match checked {
+                    Ok(()) => {
+                        post_text(state, message, destination.to_string())
+                            .await
+                            .wrap_err("failed to post deposit message")?;
+                    }
+                    Err(err) => debug!(error = %err, "did not post deposit message"),
+                }
+            }
+            DepositStatus::Credited
+        }
+        Err(err) => return refund_deposit(state, deposit, height, format!("{err:#}")),
+    };
+    state
+        .put_deposit_status(&source_transaction_id, deposit.source_action_index, &status)
+        .wrap_err("failed to record deposit status")?;
+    Ok(status)
+}
+
+/// Records `deposit` as failed for `reason` without crediting it, and emits a [`Refund`]
+/// withdrawal event at `height` returning the funds to the `refund_address` of the deposit memo,
+/// or to the signer of the deposit's source transaction if the memo names none.
+///
+/// Returns the status the deposit was recorded with.
+pub(crate) fn refund_deposit<S: StateWrite>(
+    state: &mut S,
+    deposit: &Deposit,
+    height: u32,
+    reason: String,
+) -> Result<DepositStatus> {
+    let source_transaction_id = deposit.source_transaction_id.get();
+    state
+        .put_refund(
+            height,
+            &Refund {
+                source_transaction_id,
+                source_action_index: deposit.source_action_index,
+                bridge_address: deposit.bridge_address,
+                recipient: parse_memo(&deposit.destination_chain_address)
+                    .refund_address
+                    .and_then(|refund_address| refund_address.parse().ok()),
+                asset: deposit.asset.clone(),
+                amount: deposit.amount,
+                reason: reason.clone(),
+            },
+        )
+        .wrap_err("failed to record refund")?;
+    let status = DepositStatus::Failed { reason };
+    state
+        .put_deposit_status(&source_transaction_id, deposit.source_action_index, &status)
+        .wrap_err("failed to record deposit status")?;
+    Ok(status)
+}
+
+/// The payload a deposit can carry in its `destination_chain_address`.
+#[derive(Debug, Deserialize)]
+struct DepositMemo {
+    address: String,
+    #[serde(default)]
+    message: Option<String>,
+    /// The sequencer address the deposit is refunded to if it cannot be credited.
+    #[serde(default)]
+    refund_address: Option<String>,
+}
+
+/// Parses the `destination_chain_address` of a deposit into its memo.
+///
+/// The destination is either a plain rollup address, or a JSON object
+/// `{"address": <address>, "message": <message>, "refund_address": <address>}` whose message and
+/// refund address are optional.
+fn parse_memo(destination_chain_address: &str) -> DepositMemo {
+    serde_json::from_str(destination_chain_address).unwrap_or_else(|_| DepositMemo {
+        address: destination_chain_address.to_string(),
+        message: None,
+        refund_address: None,
+    })
+}
+
+/// Parses the `destination_chain_address` of a deposit into the address to credit and the
+/// message to post.
+fn parse_destination(destination_chain_address: &str) -> Result<(Address, Option<String>)> {
+    let memo = parse_memo(destination_chain_address);
+    let address = memo
+        .address
+        .parse::<Address>()
+        .wrap_err_with(|| format!("invalid destination chain address `{}`", memo.address))?;
+    Ok((address, memo.message))
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::primitive::v1::TransactionId;
+    use cnidarium::StateDelta;
+    use rollup_core::genesis::v1::{BlockParams, TextParams};
+
+    use super::*;
+    use crate::{
+        accounts::{state_ext::astria_address, StateReadExt as _},
+        bridge::BridgeConfig,
+        text::{StateReadExt as _, StateWriteExt as _},
+    };
+
+    fn rollup_id() -> RollupId {
+        RollupId::from_unhashed_bytes("astria-chat")
+    }
+
+    fn asset() -> asset::Denom {
+        "ntia".parse().unwrap()
+    }
+
+    fn deposit(destination_chain_address: &str) -> Deposit {
+        Deposit {
+            bridge_address: astria_address(&[1u8; 20]),
+            rollup_id: rollup_id(),
+            amount: 100,
+            asset: asset(),
+            destination_chain_address: destination_chain_address.to_string(),
+            source_transaction_id: TransactionId::new([2u8; 32]),
+            source_action_index: 0,
+        }
+    }
+
+    fn budget() -> BlockBudget {
+        BlockBudget::new(BlockParams::default())
+    }
+
+    fn put_bridge<S: StateWrite>(state: &mut S) {
+        let bridge_address = astria_address(&[1u8; 20]);
+        state.put_bridge_account(&bridge_address).unwrap();
+        state
+            .put_bridge_config(
+                &bridge_address,
+                &BridgeConfig {
+                    asset: asset().to_ibc_prefixed(),
+                    rollup_id: rollup_id(),
+                },
+            )
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn check_deposit_rejects_invalid_deposits() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+        let destination = astria_address(&[3u8; 20]).to_string();
+
+        // unknown bridge account
+        assert_eq!(
+            check_deposit(&state, &deposit(&destination)).await.unwrap(),
+            Some(DepositRejected::NotBridge(astria_address(&[1u8; 20])))
+        );
+
+        // bridge account without config
+        state
+            .put_bridge_account(&astria_address(&[1u8; 20]))
+            .unwrap();
+        assert_eq!(
+            check_deposit(&state, &deposit(&destination)).await.unwrap(),
+            Some(DepositRejected::NoBridgeConfig)
+        );
+
+        put_bridge(&mut state);
+        assert_eq!(
+            check_deposit(&state, &deposit(&destination)).await.unwrap(),
+            None
+        );
+
+        let mut other_rollup = deposit(&destination);
+        other_rollup.rollup_id = RollupId::from_unhashed_bytes("other-rollup");
+        assert!(matches!(
+            check_deposit(&state, &other_rollup).await.unwrap(),
+            Some(DepositRejected::OtherRollup { .. })
+        ));
+
+        let mut other_asset = deposit(&destination);
+        other_asset.asset = "nria".parse().unwrap();
+        assert!(matches!(
+            check_deposit(&state, &other_asset).await.unwrap(),
+            Some(DepositRejected::OtherAsset(_))
+        ));
+
+        // a deposit is only processed once, even if it was refunded
+        refund_deposit(&mut state, &other_asset, 1, "other asset".to_string()).unwrap();
+        assert_eq!(
+            check_deposit(&state, &other_asset).await.unwrap(),
+            Some(DepositRejected::AlreadyProcessed)
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_deposit_credits_destination() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+        put_bridge(&mut state);
+        let destination = astria_address(&[3u8; 20]);
+        let deposit = deposit(&destination.to_string());
+
+        let status = execute_deposit(&mut state, &deposit, 1, &mut budget())
+            .await
+            .unwrap();
+        assert_eq!(status, DepositStatus::Credited);
+        assert_eq!(
+            state
+                .get_account_balance(&destination, &asset())
+                .await
+                .unwrap(),
+            100
+        );
+        assert_eq!(
+            state.get_deposit_status(&[2u8; 32], 0).await.unwrap(),
+            Some(DepositStatus::Credited)
+        );
+        assert!(state.get_refunds(1).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn execute_deposit_with_invalid_destination_records_refund() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+        put_bridge(&mut state);
+        let deposit = deposit("not an address");
+
+        let status = execute_deposit(&mut state, &deposit, 1, &mut budget())
+            .await
+            .unwrap();
+        let DepositStatus::Failed { reason } = status else {
+            panic!("expected a failed deposit, got {status:?}");
+        };
+        assert_eq!(
+            state.get_deposit_status(&[2u8; 32], 0).await.unwrap(),
+            Some(DepositStatus::Failed {
+                reason: reason.clone()
+            })
+        );
+        let refunds = state.get_refunds(1).await.unwrap();
+        assert_eq!(
+            refunds,
+            vec![Refund {
+                source_transaction_id: [2u8; 32],
+                source_action_index: 0,
+                bridge_address: astria_address(&[1u8; 20]),
+                recipient: None,
+                asset: asset(),
+                amount: 100,
+                reason,
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn refund_is_withdrawn_to_refund_address_of_memo() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+        put_bridge(&mut state);
+        let refund_address = astria_address(&[4u8; 20]);
+        let deposit = deposit(&format!(
+            r#"{{"address":"not an address","refund_address":"{refund_address}"}}"#
+        ));
+
+        execute_deposit(&mut state, &deposit, 1, &mut budget())
+            .await
+            .unwrap();
+        let refunds = state.get_refunds(1).await.unwrap();
+        assert_eq!(refunds.len(), 1);
+        assert_eq!(refunds[0].recipient, Some(refund_address));
+        assert_eq!(
+            refunds[0].withdrawal_event_id(),
+            format!("{}.0", hex::encode([2u8; 32]))
+        );
+    }
+
+    #[test]
+    fn parse_destination_accepts_plain_address_and_memo() {
+        let address = astria_address(&[3u8; 20]);
+
+        assert_eq!(
+            parse_destination(&address.to_string()).unwrap(),
+            (address, None)
+        );
+        assert_eq!(
+            parse_destination(&format!(r#"{{"address":"{address}","message":"gm"}}"#)).unwrap(),
+            (address, Some("gm".to_string()))
+        );
+        assert_eq!(
+            parse_destination(&format!(r#"{{"address":"{address}"}}"#)).unwrap(),
+            (address, None)
+        );
+        parse_destination(r#"{"address":"not an address","message":"gm"}"#).unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn execute_deposit_with_message_posts_text() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+        put_bridge(&mut state);
+        state.put_last_text_id(1).unwrap();
+        let destination = astria_address(&[3u8; 20]);
+        let deposit = deposit(&format!(
+            r#"{{"address":"{destination}","message":"hello from the sequencer"}}"#
+        ));
+
+        let status = execute_deposit(&mut state, &deposit, 1, &mut budget())
+            .await
+            .unwrap();
+        assert_eq!(status, DepositStatus::Credited);
+        assert_eq!(
+            state
+                .get_account_balance(&destination, &asset())
+                .await
+                .unwrap(),
+            100
+        );
+        assert_eq!(
+            String::from(state.get_text(1).await.unwrap()),
+            format!("{destination}:hello from the sequencer")
+        );
+        assert_eq!(u64::from(state.get_last_text_id().await.unwrap()), 2);
+    }
+
+    #[tokio::test]
+    async fn deposit_message_beyond_text_limits_is_not_posted() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+        put_bridge(&mut state);
+        state.put_last_text_id(1).unwrap();
+        state
+            .put_text_params(&TextParams {
+                max_text_length: 5,
+                ..TextParams::default()
+            })
+            .unwrap();
+        let destination = astria_address(&[3u8; 20]);
+
+        // too long for the text length limit
+        let too_long = deposit(&format!(
+            r#"{{"address":"{destination}","message":"hello from the sequencer"}}"#
+        ));
+        let status = execute_deposit(&mut state, &too_long, 1, &mut budget())
+            .await
+            .unwrap();
+        assert_eq!(status, DepositStatus::Credited);
+        assert_eq!(
+            state
+                .get_account_balance(&destination, &asset())
+                .await
+                .unwrap(),
+            100
+        );
+        assert_eq!(u64::from(state.get_last_text_id().await.unwrap()), 1);
+
+        // beyond the text bytes left in the block
+        let mut full_block = deposit(&format!(r#"{{"address":"{destination}","message":"gm"}}"#));
+        full_block.source_action_index = 1;
+        let mut budget = BlockBudget::new(BlockParams {
+            max_text_bytes: 1,
+            ..BlockParams::default()
+        });
+        let status = execute_deposit(&mut state, &full_block, 1, &mut budget)
+            .await
+            .unwrap();
+        assert_eq!(status, DepositStatus::Credited);
+        assert_eq!(
+            state
+                .get_account_balance(&destination, &asset())
+                .await
+                .unwrap(),
+            200
+        );
+        assert_eq!(u64::from(state.get_last_text_id().await.unwrap()), 1);
+    }
+}
@@ -1,2 +1,174 @@
+pub(crate) mod deposit;
 pub(crate) mod state_ext;
 pub(crate) mod storage;
+
+use std::str::FromStr as _;
+
+use astria_core::primitive::v1::{asset, Address, RollupId};
+use astria_eyre::eyre::{OptionExt as _, Result, WrapErr as _};
+use cnidarium::StateWrite;
+use rollup_core::generated::protocol::genesis::v1::BridgeAccount;
+
+use self::state_ext::{StateReadExt as _, StateWriteExt as _};
+
+/// The configuration of a bridge account, restricting which deposits made through it are
+/// credited on the rollup.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct BridgeConfig {
+    /// The asset the bridge account locks on the sequencer.
+    pub(crate) asset: asset::IbcPrefixed,
+    /// The rollup the bridge account deposits to.
+    pub(crate) rollup_id: RollupId,
+}
+
+/// The outcome of processing a deposit.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum DepositStatus {
+    /// The deposit was credited to its destination address.
+    Credited,
+    /// The deposit could not be credited and a [`Refund`] was recorded for it.
+    Failed { reason: String },
+}
+
+/// The withdrawal event returning the funds of a deposit that could not be credited.
+///
+/// The funds remain locked in the bridge account on the sequencer until a bridge withdrawer
+/// unlocks them to `recipient`, identifying the withdrawal by the rollup height it was recorded
+/// at and its [`Refund::withdrawal_event_id`]. Deposits do not carry the account that locked the
+/// funds, so the recipient is the `refund_address` named in the deposit memo, or the signer of
+/// the sequencer transaction `source_transaction_id` if the memo names none.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Refund {
+    pub(crate) source_transaction_id: [u8; 32],
+    pub(crate) source_action_index: u64,
+    pub(crate) bridge_address: Address,
+    pub(crate) recipient: Option<Address>,
+    pub(crate) asset: asset::Denom,
+    pub(crate) amount: u128,
+    pub(crate) reason: String,
+}
+
+impl Refund {
+    /// Returns the id of the withdrawal event, unique as every deposit is refunded at most once.
+    pub(crate) fn withdrawal_event_id(&self) -> String {
+        format!(
+            "{}.{}",
+            hex::encode(self.source_transaction_id),
+            self.source_action_index
+        )
+    }
+}
+
+/// Parses a bridge account of the genesis into its address and the asset it locks.
+pub(crate) fn parse_genesis_bridge_account(
+    bridge_account: &BridgeAccount,
+) -> Result<(Address, asset::Denom)> {
+    let address = bridge_account
+        .address
+        .as_ref()
+        .ok_or_eyre("bridge account has no address")?;
+    let address = Address::from_str(&address.bech32m)
+        .wrap_err_with(|| format!("invalid bridge account address `{}`", address.bech32m))?;
+    let asset = asset::Denom::from_str(&bridge_account.asset)
+        .wrap_err_with(|| format!("invalid bridge account asset `{}`", bridge_account.asset))?;
+    Ok((address, asset))
+}
+
+/// Writes the config of every registered bridge account that has none, taken from its entry in
+/// the genesis `bridge_accounts`.
+///
+/// Bridge accounts registered before their configs were stored have none, so every deposit made
+/// through them would be refunded. Their configs are written as part of executing a block, so
+/// that the state root changes with a block and not at startup.
+pub(crate) async fn migrate_bridge_configs<S: StateWrite>(
+    state: &mut S,
+    rollup_id: RollupId,
+    genesis_bridge_accounts: &[BridgeAccount],
+) -> Result<()> {
+    for bridge_account in genesis_bridge_accounts {
+        let (address, asset) = parse_genesis_bridge_account(bridge_account)
+            .wrap_err("invalid bridge account in genesis")?;
+        if !state
+            .is_bridge(&address)
+            .await
+            .wrap_err("failed to check whether genesis address is a bridge account")?
+            || state
+                .get_bridge_config(&address)
+                .await
+                .wrap_err("failed to read bridge config")?
+                .is_some()
+        {
+            continue;
+        }
+        state
+            .put_bridge_config(
+                &address,
+                &BridgeConfig {
+                    asset: asset.to_ibc_prefixed(),
+                    rollup_id,
+                },
+            )
+            .wrap_err("failed to write bridge config")?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::generated::astria::primitive::v1::Address as RawAddress;
+    use cnidarium::StateDelta;
+
+    use super::*;
+    use crate::accounts::state_ext::astria_address;
+
+    #[tokio::test]
+    async fn bridge_accounts_without_config_get_their_genesis_config() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        let rollup_id = RollupId::from_unhashed_bytes("astria-chat");
+        let legacy = astria_address(&[1u8; 20]);
+        let configured = astria_address(&[2u8; 20]);
+        let configured_config = BridgeConfig {
+            asset: "nria".parse::<asset::Denom>().unwrap().to_ibc_prefixed(),
+            rollup_id: RollupId::from_unhashed_bytes("other-rollup"),
+        };
+        state.put_bridge_account(&legacy).unwrap();
+        state.put_bridge_account(&configured).unwrap();
+        state
+            .put_bridge_config(&configured, &configured_config)
+            .unwrap();
+        let genesis_bridge_accounts: Vec<_> = [legacy, configured, astria_address(&[3u8; 20])]
+            .iter()
+            .map(|address| BridgeAccount {
+                address: Some(RawAddress {
+                    bech32m: address.to_string(),
+                }),
+                asset: "ntia".to_string(),
+            })
+            .collect();
+
+        migrate_bridge_configs(&mut state, rollup_id, &genesis_bridge_accounts)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            state.get_bridge_config(&legacy).await.unwrap(),
+            Some(BridgeConfig {
+                asset: "ntia".parse::<asset::Denom>().unwrap().to_ibc_prefixed(),
+                rollup_id,
+            })
+        );
+        // configs are never overwritten, and accounts that are not bridges get none
+        assert_eq!(
+            state.get_bridge_config(&configured).await.unwrap(),
+            Some(configured_config)
+        );
+        assert_eq!(
+            state
+                .get_bridge_config(&astria_address(&[3u8; 20]))
+                .await
+                .unwrap(),
+            None
+        );
+    }
+}
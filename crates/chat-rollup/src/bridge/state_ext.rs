@@ -12,10 +12,14 @@ use astria_eyre::{
 };
 use async_trait::async_trait;
 use cnidarium::{StateRead, StateWrite};
+use futures::TryStreamExt as _;
 use pin_project_lite::pin_project;
 use tracing::{debug, instrument, warn};
 
-use super::storage::keys;
+use super::{
+    storage::{self, keys},
+    BridgeConfig, DepositStatus, Refund,
+};
 
 #[allow(dead_code)]
 pub(crate) fn nria() -> TracePrefixed {
@@ -80,6 +84,81 @@ pub(crate) trait StateReadExt: StateRead + crate::assets::StateReadExt {
             })
             .wrap_err("invalid bridge account sudo address bytes")
     }
+
+    #[instrument(skip_all, fields(bridge_address = %bridge_address.display_address()))]
+    async fn get_bridge_config<T: AddressBytes>(
+        &self,
+        bridge_address: &T,
+    ) -> Result<Option<BridgeConfig>> {
+        let Some(bytes) = self
+            .get_raw(&keys::bridge_config(bridge_address))
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading raw bridge config from state")?
+        else {
+            return Ok(None);
+        };
+        StoredValue::deserialize(&bytes)
+            .and_then(|value| {
+                storage::values::BridgeConfig::try_from(value).map(|config| Some(config.into()))
+            })
+            .wrap_err("invalid bridge config bytes")
+    }
+
+    /// Returns whether the deposit emitted by the action at `source_action_index` of the
+    /// sequencer transaction `source_transaction_id` was already processed by the rollup.
+    #[instrument(skip_all)]
+    async fn is_deposit_processed(
+        &self,
+        source_transaction_id: &[u8; 32],
+        source_action_index: u64,
+    ) -> Result<bool> {
+        Ok(self
+            .get_raw(&keys::deposit(source_transaction_id, source_action_index))
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading raw processed deposit from state")?
+            .is_some())
+    }
+
+    /// Returns the status of the deposit emitted by the action at `source_action_index` of the
+    /// sequencer transaction `source_transaction_id`, or `None` if it was not processed.
+    #[instrument(skip_all)]
+    async fn get_deposit_status(
+        &self,
+        source_transaction_id: &[u8; 32],
+        source_action_index: u64,
+    ) -> Result<Option<DepositStatus>> {
+        let Some(bytes) = self
+            .get_raw(&keys::deposit(source_transaction_id, source_action_index))
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading raw deposit status from state")?
+        else {
+            return Ok(None);
+        };
+        StoredValue::deserialize(&bytes)
+            .and_then(|value| {
+                storage::values::DepositStatus::try_from(value).map(|status| Some(status.into()))
+            })
+            .wrap_err("invalid deposit status bytes")
+    }
+
+    /// Returns the refunds recorded while executing the block at `height`.
+    #[instrument(skip_all)]
+    async fn get_refunds(&self, height: u32) -> Result<Vec<Refund>> {
+        self.prefix_raw(&keys::refunds_by_height(height))
+            .map_err(|err| anyhow_to_eyre(err).wrap_err("failed reading raw refunds from state"))
+            .and_then(|(_, bytes)| async move {
+                StoredValue::deserialize(&bytes)
+                    .and_then(|value| {
+                        storage::values::Refund::try_from(value).and_then(Refund::try_from)
+                    })
+                    .wrap_err("invalid refund bytes")
+            })
+            .try_collect()
+            .await
+    }
 }
 
 impl<T: StateRead + ?Sized> StateReadExt for T {}
@@ -101,6 +180,170 @@ pub(crate) trait StateWriteExt: StateWrite {
         self.put_raw(keys::bridge(bridge_address), bytes);
         Ok(())
     }
+
+    #[instrument(skip_all)]
+    fn put_bridge_config<T>(&mut self, bridge_address: &T, config: &BridgeConfig) -> Result<()>
+    where
+        T: AddressBytes,
+    {
+        let bytes = StoredValue::from(storage::values::BridgeConfig::from(config))
+            .serialize()
+            .context("failed to serialize bridge config")?;
+        self.put_raw(keys::bridge_config(bridge_address), bytes);
+        Ok(())
+    }
+
+    /// Records the status of the deposit emitted by the action at `source_action_index` of the
+    /// sequencer transaction `source_transaction_id`, marking it as processed so that it is
+    /// never processed twice.
+    #[instrument(skip_all)]
+    fn put_deposit_status(
+        &mut self,
+        source_transaction_id: &[u8; 32],
+        source_action_index: u64,
+        status: &DepositStatus,
+    ) -> Result<()> {
+        let bytes = StoredValue::from(storage::values::DepositStatus::from(status))
+            .serialize()
+            .context("failed to serialize deposit status")?;
+        self.put_raw(
+            keys::deposit(source_transaction_id, source_action_index),
+            bytes,
+        );
+        Ok(())
+    }
+
+    #[instrument(skip_all)]
+    fn put_refund(&mut self, height: u32, refund: &Refund) -> Result<()> {
+        let bytes = StoredValue::from(storage::values::Refund::from(refund))
+            .serialize()
+            .context("failed to serialize refund")?;
+        self.put_raw(
+            keys::refund(
+                height,
+                &refund.source_transaction_id,
+                refund.source_action_index,
+            ),
+            bytes,
+        );
+        Ok(())
+    }
 }
 
 impl<T: StateWrite> StateWriteExt for T {}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::primitive::v1::RollupId;
+    use cnidarium::StateDelta;
+
+    use super::*;
+
+    fn bridge_config() -> BridgeConfig {
+        BridgeConfig {
+            asset: "ntia".parse::<asset::Denom>().unwrap().to_ibc_prefixed(),
+            rollup_id: RollupId::from_unhashed_bytes("astria-chat"),
+        }
+    }
+
+    #[tokio::test]
+    async fn put_and_get_bridge_account() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let address = astria_address(&[42u8; 20]);
+        assert!(!state.is_bridge(&address).await.unwrap());
+
+        state.put_bridge_account(&address).unwrap();
+        assert!(state.is_bridge(&address).await.unwrap());
+        assert!(!state.is_bridge(&astria_address(&[43u8; 20])).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn put_and_get_bridge_config() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let address = astria_address(&[42u8; 20]);
+        assert_eq!(state.get_bridge_config(&address).await.unwrap(), None);
+
+        state.put_bridge_config(&address, &bridge_config()).unwrap();
+        assert_eq!(
+            state.get_bridge_config(&address).await.unwrap(),
+            Some(bridge_config())
+        );
+    }
+
+    #[tokio::test]
+    async fn put_deposit_status() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let source_transaction_id = [1u8; 32];
+        assert!(!state
+            .is_deposit_processed(&source_transaction_id, 0)
+            .await
+            .unwrap());
+        assert_eq!(
+            state
+                .get_deposit_status(&source_transaction_id, 0)
+                .await
+                .unwrap(),
+            None
+        );
+
+        let status = DepositStatus::Failed {
+            reason: "invalid destination chain address".to_string(),
+        };
+        state
+            .put_deposit_status(&source_transaction_id, 0, &status)
+            .unwrap();
+        assert!(state
+            .is_deposit_processed(&source_transaction_id, 0)
+            .await
+            .unwrap());
+        assert_eq!(
+            state
+                .get_deposit_status(&source_transaction_id, 0)
+                .await
+                .unwrap(),
+            Some(status)
+        );
+        // other actions of the same transaction are unaffected
+        assert!(!state
+            .is_deposit_processed(&source_transaction_id, 1)
+            .await
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn put_and_get_refunds() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let refund = |source_action_index| Refund {
+            source_transaction_id: [1u8; 32],
+            source_action_index,
+            bridge_address: astria_address(&[42u8; 20]),
+            recipient: Some(astria_address(&[43u8; 20])),
+            asset: "ntia".parse().unwrap(),
+            amount: 100,
+            reason: "invalid destination chain address".to_string(),
+        };
+        assert!(state.get_refunds(1).await.unwrap().is_empty());
+
+        state.put_refund(1, &refund(0)).unwrap();
+        state.put_refund(1, &refund(1)).unwrap();
+        state.put_refund(10, &refund(2)).unwrap();
+
+        assert_eq!(
+            state.get_refunds(1).await.unwrap(),
+            vec![refund(0), refund(1)]
+        );
+        assert_eq!(state.get_refunds(10).await.unwrap(), vec![refund(2)]);
+    }
+}
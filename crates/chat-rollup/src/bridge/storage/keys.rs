@@ -1,6 +1,9 @@
 use crate::{accounts::AddressBytes, storage::keys::AccountPrefixer};
 
 pub(in crate::bridge) const BRIDGE_ACCOUNT_PREFIX: &str = "bridge/account";
+const BRIDGE_CONFIG_PREFIX: &str = "bridge/config/";
+const DEPOSIT_PREFIX: &str = "bridge/deposit/";
+const REFUND_PREFIX: &str = "bridge/refund/";
 
 /// Example: `accounts/gGhH....zZ4=/balance/`.
 ///                   |base64 chars|
@@ -8,3 +11,76 @@ pub(in crate::bridge) const BRIDGE_ACCOUNT_PREFIX: &str = "bridge/account";
 pub(in crate::bridge) fn bridge<TAddress: AddressBytes>(address: &TAddress) -> String {
     format!("{}", AccountPrefixer::new(BRIDGE_ACCOUNT_PREFIX, address))
 }
+
+/// Example: `bridge/config/gGhH....zZ4=`.
+///                        |base64 chars|
+pub(in crate::bridge) fn bridge_config<TAddress: AddressBytes>(address: &TAddress) -> String {
+    format!("{}", AccountPrefixer::new(BRIDGE_CONFIG_PREFIX, address))
+}
+
+/// Example: `bridge/deposit/0101....0101/0`.
+///                         |64 hex chars|action index
+pub(in crate::bridge) fn deposit(
+    source_transaction_id: &[u8; 32],
+    source_action_index: u64,
+) -> String {
+    format!(
+        "{DEPOSIT_PREFIX}{}/{source_action_index}",
+        hex::encode(source_transaction_id)
+    )
+}
+
+/// Example: `bridge/refund/42/`.
+///                        |height|
+pub(in crate::bridge) fn refunds_by_height(height: u32) -> String {
+    format!("{REFUND_PREFIX}{height}/")
+}
+
+/// Example: `bridge/refund/42/0101....0101/0`.
+///                        |height|64 hex chars|action index
+pub(in crate::bridge) fn refund(
+    height: u32,
+    source_transaction_id: &[u8; 32],
+    source_action_index: u64,
+) -> String {
+    format!(
+        "{}{}/{source_action_index}",
+        refunds_by_height(height),
+        hex::encode(source_transaction_id)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::primitive::v1::Address;
+
+    use super::*;
+
+    const COMPONENT_PREFIX: &str = "bridge/";
+
+    fn address() -> Address {
+        "astria1rsxyjrcm255ds9euthjx6yc3vrjt9sxrm9cfgm"
+            .parse()
+            .unwrap()
+    }
+
+    #[test]
+    fn keys_should_have_component_prefix() {
+        assert!(bridge(&address()).starts_with(COMPONENT_PREFIX));
+        assert!(bridge_config(&address()).starts_with(COMPONENT_PREFIX));
+        assert!(deposit(&[1; 32], 0).starts_with(COMPONENT_PREFIX));
+        assert!(refund(1, &[1; 32], 0).starts_with(COMPONENT_PREFIX));
+    }
+
+    #[test]
+    fn refund_keys_are_grouped_by_height() {
+        assert!(refund(1, &[1; 32], 0).starts_with(&refunds_by_height(1)));
+        assert!(!refund(10, &[1; 32], 0).starts_with(&refunds_by_height(1)));
+    }
+
+    #[test]
+    fn deposit_keys_are_unique_per_action() {
+        assert_ne!(deposit(&[1; 32], 0), deposit(&[1; 32], 1));
+        assert_ne!(deposit(&[1; 32], 0), deposit(&[2; 32], 0));
+    }
+}
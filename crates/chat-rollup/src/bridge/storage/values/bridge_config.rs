@@ -0,0 +1,47 @@
+use astria_core::primitive::v1::{asset::IbcPrefixed, RollupId};
+use astria_eyre::eyre::bail;
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use super::{Value, ValueImpl};
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(in crate::bridge) struct BridgeConfig {
+    asset: [u8; 32],
+    rollup_id: [u8; 32],
+}
+
+impl From<&crate::bridge::BridgeConfig> for BridgeConfig {
+    fn from(config: &crate::bridge::BridgeConfig) -> Self {
+        BridgeConfig {
+            asset: config.asset.get(),
+            rollup_id: config.rollup_id.get(),
+        }
+    }
+}
+
+impl From<BridgeConfig> for crate::bridge::BridgeConfig {
+    fn from(config: BridgeConfig) -> Self {
+        crate::bridge::BridgeConfig {
+            asset: IbcPrefixed::new(config.asset),
+            rollup_id: RollupId::new(config.rollup_id),
+        }
+    }
+}
+
+impl From<BridgeConfig> for crate::storage::StoredValue<'_> {
+    fn from(config: BridgeConfig) -> Self {
+        crate::storage::StoredValue::Bridge(Value(ValueImpl::BridgeConfig(config)))
+    }
+}
+
+impl TryFrom<crate::storage::StoredValue<'_>> for BridgeConfig {
+    type Error = astria_eyre::eyre::Error;
+
+    fn try_from(value: crate::storage::StoredValue<'_>) -> Result<Self, Self::Error> {
+        let crate::storage::StoredValue::Bridge(Value(ValueImpl::BridgeConfig(config))) = value
+        else {
+            bail!("bridge stored value type mismatch: expected bridge config, found {value:?}");
+        };
+        Ok(config)
+    }
+}
@@ -0,0 +1,48 @@
+use astria_eyre::eyre::bail;
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use super::{Value, ValueImpl};
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(in crate::bridge) enum DepositStatus {
+    Credited,
+    Failed { reason: String },
+}
+
+impl From<&crate::bridge::DepositStatus> for DepositStatus {
+    fn from(status: &crate::bridge::DepositStatus) -> Self {
+        match status {
+            crate::bridge::DepositStatus::Credited => DepositStatus::Credited,
+            crate::bridge::DepositStatus::Failed { reason } => DepositStatus::Failed {
+                reason: reason.clone(),
+            },
+        }
+    }
+}
+
+impl From<DepositStatus> for crate::bridge::DepositStatus {
+    fn from(status: DepositStatus) -> Self {
+        match status {
+            DepositStatus::Credited => crate::bridge::DepositStatus::Credited,
+            DepositStatus::Failed { reason } => crate::bridge::DepositStatus::Failed { reason },
+        }
+    }
+}
+
+impl From<DepositStatus> for crate::storage::StoredValue<'_> {
+    fn from(status: DepositStatus) -> Self {
+        crate::storage::StoredValue::Bridge(Value(ValueImpl::DepositStatus(status)))
+    }
+}
+
+impl TryFrom<crate::storage::StoredValue<'_>> for DepositStatus {
+    type Error = astria_eyre::eyre::Error;
+
+    fn try_from(value: crate::storage::StoredValue<'_>) -> Result<Self, Self::Error> {
+        let crate::storage::StoredValue::Bridge(Value(ValueImpl::DepositStatus(status))) = value
+        else {
+            bail!("bridge stored value type mismatch: expected deposit status, found {value:?}");
+        };
+        Ok(status)
+    }
+}
@@ -1,5 +1,12 @@
 pub(in crate::bridge) mod address_bytes;
+mod bridge_config;
+mod deposit_status;
+mod refund;
+
 pub(super) use address_bytes::AddressBytes;
+pub(in crate::bridge) use bridge_config::BridgeConfig;
+pub(in crate::bridge) use deposit_status::DepositStatus;
+pub(in crate::bridge) use refund::Refund;
 use borsh::{BorshDeserialize, BorshSerialize};
 #[derive(Debug, BorshSerialize, BorshDeserialize)]
 pub(crate) struct Value<'a>(ValueImpl<'a>);
@@ -7,4 +14,7 @@ pub(crate) struct Value<'a>(ValueImpl<'a>);
 #[derive(Debug, BorshSerialize, BorshDeserialize)]
 enum ValueImpl<'a> {
     AddressBytes(AddressBytes<'a>),
+    BridgeConfig(BridgeConfig),
+    DepositStatus(DepositStatus),
+    Refund(Refund),
 }
@@ -0,0 +1,69 @@
+use astria_eyre::eyre::{bail, WrapErr as _};
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use super::{Value, ValueImpl};
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(in crate::bridge) struct Refund {
+    source_transaction_id: [u8; 32],
+    source_action_index: u64,
+    bridge_address: String,
+    recipient: Option<String>,
+    asset: String,
+    amount: u128,
+    reason: String,
+}
+
+impl From<&crate::bridge::Refund> for Refund {
+    fn from(refund: &crate::bridge::Refund) -> Self {
+        Refund {
+            source_transaction_id: refund.source_transaction_id,
+            source_action_index: refund.source_action_index,
+            bridge_address: refund.bridge_address.to_string(),
+            recipient: refund.recipient.as_ref().map(ToString::to_string),
+            asset: refund.asset.to_string(),
+            amount: refund.amount,
+            reason: refund.reason.clone(),
+        }
+    }
+}
+
+impl TryFrom<Refund> for crate::bridge::Refund {
+    type Error = astria_eyre::eyre::Error;
+
+    fn try_from(refund: Refund) -> Result<Self, Self::Error> {
+        Ok(crate::bridge::Refund {
+            source_transaction_id: refund.source_transaction_id,
+            source_action_index: refund.source_action_index,
+            bridge_address: refund
+                .bridge_address
+                .parse()
+                .wrap_err("invalid refund bridge address")?,
+            recipient: refund
+                .recipient
+                .map(|recipient| recipient.parse())
+                .transpose()
+                .wrap_err("invalid refund recipient")?,
+            asset: refund.asset.parse().wrap_err("invalid refund asset")?,
+            amount: refund.amount,
+            reason: refund.reason,
+        })
+    }
+}
+
+impl From<Refund> for crate::storage::StoredValue<'_> {
+    fn from(refund: Refund) -> Self {
+        crate::storage::StoredValue::Bridge(Value(ValueImpl::Refund(refund)))
+    }
+}
+
+impl TryFrom<crate::storage::StoredValue<'_>> for Refund {
+    type Error = astria_eyre::eyre::Error;
+
+    fn try_from(value: crate::storage::StoredValue<'_>) -> Result<Self, Self::Error> {
+        let crate::storage::StoredValue::Bridge(Value(ValueImpl::Refund(refund))) = value else {
+            bail!("bridge stored value type mismatch: expected refund, found {value:?}");
+        };
+        Ok(refund)
+    }
+}
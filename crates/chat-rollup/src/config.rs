@@ -3,6 +3,8 @@ use std::path::PathBuf;
 use config::ConfigError;
 use serde::{Deserialize, Serialize};
 
+use crate::submission::SubmissionBackendKind;
+
 #[derive(Clone, Debug, Deserialize, Serialize, Default)]
 pub struct Config {
     pub genesis_filepath: PathBuf,
@@ -22,10 +24,75 @@ pub struct Config {
     pub pretty_print: bool,
     /// The address of the Composer service.
     pub composer_addr: String,
+    /// Where submitted transactions are sent: `composer` (the default) or `sequencer`.
+    #[serde(default)]
+    pub submission_backend: SubmissionBackendKind,
+    /// The CometBFT RPC endpoint of the sequencer, used by the `sequencer` submission backend.
+    #[serde(default)]
+    pub sequencer_rpc_addr: String,
+    /// The chain id of the sequencer, used by the `sequencer` submission backend.
+    #[serde(default)]
+    pub sequencer_chain_id: String,
+    /// The file holding the hex encoded private key signing sequencer transactions, used by the
+    /// `sequencer` submission backend.
+    #[serde(default)]
+    pub sequencer_private_key_file: PathBuf,
+    /// The asset sequencer fees are paid in, used by the `sequencer` submission backend.
+    #[serde(default)]
+    pub sequencer_fee_asset: String,
+    /// The hex encoded private key signing messages posted to `/message`. Takes precedence over
+    /// `relayer_private_key_file`; if neither is set, the endpoint is disabled.
+    #[serde(default)]
+    pub relayer_private_key: String,
+    /// The file holding the hex encoded private key signing messages posted to `/message`.
+    #[serde(default)]
+    pub relayer_private_key_file: PathBuf,
+    /// How many messages a single client, identified by its IP address, may post to `/message`
+    /// per minute.
+    #[serde(default = "default_relayer_sender_messages_per_minute")]
+    pub relayer_sender_messages_per_minute: u64,
+    /// How many messages all clients together may post to `/message` per minute.
+    #[serde(default = "default_relayer_messages_per_minute")]
+    pub relayer_messages_per_minute: u64,
+    /// The most fees the relayer pays per day.
+    #[serde(default = "default_relayer_daily_spend_cap")]
+    pub relayer_daily_spend_cap: u64,
+    /// How long the relayer buffers messages before posting them together in one transaction.
+    #[serde(default = "default_relayer_batch_interval_ms")]
+    pub relayer_batch_interval_ms: u64,
+    /// The most messages the relayer posts in one transaction. Batches are further capped at the
+    /// actions and compute units a transaction may use.
+    #[serde(default = "default_relayer_max_batch_size")]
+    pub relayer_max_batch_size: usize,
+    /// Whether the client IP address the relayer limits are keyed on is read from the last
+    /// entry of the `X-Forwarded-For` header instead of the peer address. Only set this if the
+    /// rollup is served behind a reverse proxy which sets the header.
+    #[serde(default)]
+    pub relayer_trust_forwarded_for: bool,
     /// The endpoint which will be listened on for serving prometheus metrics
     pub metrics_http_listener_addr: String,
 }
 
+fn default_relayer_sender_messages_per_minute() -> u64 {
+    10
+}
+
+fn default_relayer_messages_per_minute() -> u64 {
+    120
+}
+
+fn default_relayer_daily_spend_cap() -> u64 {
+    100_000
+}
+
+fn default_relayer_batch_interval_ms() -> u64 {
+    500
+}
+
+fn default_relayer_max_batch_size() -> usize {
+    20
+}
+
 impl Config {
     /// Load configuration from environment variables and `.env` file.
     pub fn from_env() -> Result<Self, ConfigError> {
@@ -1,52 +1,203 @@
 use crate::accounts::action::execute_transfer;
-use crate::accounts::{AddressBytes, StateWriteExt};
-use crate::bridge::state_ext::StateReadExt;
+use crate::accounts::AddressBytes;
+use crate::bridge::deposit::DepositRejected;
+use crate::bridge::DepositStatus;
+use crate::mempool::Mempool;
+use crate::multisig::action::execute_register_multisig;
+use crate::receipts::{self, Receipt, StateWriteExt as _};
 use crate::text::action::execute_send_text;
+use crate::transaction::metering::MeteredState;
+use crate::transaction::tracker::TransactionTracker;
 
 // use crate::accounts::StateWriteExt;
 #[allow(unused_imports)]
 use crate::rollup;
+use crate::rollup::block::{self, BlockHeader};
+use crate::rollup::block_limits::{self, BlockBudget};
 use crate::rollup::state_ext::{StateReadExt as _, StateWriteExt as _};
 use crate::rollup::RollupConfig;
-use astria_core::execution::v1::Block;
+use crate::session_keys::action::{execute_grant_session_key, execute_revoke_session_key};
+use crate::sponsors::action::execute_set_sponsor_limit;
 
 use astria_core::generated::astria::execution::v1::execution_service_server::ExecutionService;
 use astria_core::generated::astria::execution::v1::{self as execution};
 use astria_core::generated::astria::sequencerblock::v1::rollup_data::Value::{
     Deposit, SequencedData,
 };
-use pbjson_types::Timestamp;
-use sha2::Digest;
 
 use astria_core::primitive::v1::RollupId;
 use astria_core::Protobuf as _;
+use astria_eyre::{anyhow_to_eyre, eyre::WrapErr as _};
 use bytes::Bytes;
-use cnidarium::{StateDelta, Storage};
+use cnidarium::{RootHash, Snapshot, StateDelta, Storage};
 use prost::Message as _;
-use std::str::FromStr;
-use std::sync::Arc;
+use std::{collections::HashSet, sync::Arc};
 use tracing::debug;
 
 use tonic::{Request, Response, Status};
-use tracing::error;
-
-fn compute_block_hash(
-    prev_block_hash: &[u8],
-    tx_merkle_root: &[u8],
-    deposit_merkle_root: &[u8],
-    timestamp: Timestamp,
-) -> Vec<u8> {
-    let mut hasher = sha2::Sha256::new();
-    hasher.update(prev_block_hash);
-    hasher.update(tx_merkle_root);
-    hasher.update(deposit_merkle_root);
-    hasher.update(timestamp.encode_to_vec());
-    hasher.finalize().to_vec()
+use tracing::{error, info};
+
+/// Commits `delta` to `storage`, returning the root hash of the resulting application state.
+pub(crate) async fn commit_state_delta(
+    storage: &Storage,
+    delta: StateDelta<Snapshot>,
+) -> astria_eyre::eyre::Result<RootHash> {
+    let write_batch = storage
+        .prepare_commit(delta)
+        .await
+        .map_err(anyhow_to_eyre)
+        .wrap_err("failed to prepare commit")?;
+    storage
+        .commit_batch(write_batch)
+        .map_err(anyhow_to_eyre)
+        .wrap_err("failed to commit batch to storage")
 }
 
-pub(crate) struct RollupExecutionService {
-    pub storage: Storage,
-    pub config: RollupConfig,
+/// Reads the block at `height` from `snapshot` for a response of the execution service.
+///
+/// A block that was not executed yet is an invalid argument, while failing to read an executed
+/// block is an internal error.
+async fn read_block(snapshot: &Snapshot, height: u32) -> Result<execution::Block, Status> {
+    let latest_height = snapshot
+        .get_block_height()
+        .await
+        .map_err(|err| Status::internal(format!("failed to get block height: {err:#}")))?;
+    if u64::from(height) > latest_height {
+        return Err(Status::invalid_argument(format!(
+            "block `{height}` was not executed yet, latest block is `{latest_height}`"
+        )));
+    }
+    let (block, _) = block::get_block(snapshot, height)
+        .await
+        .map_err(|err| Status::internal(format!("failed to get block `{height}`: {err:#}")))?;
+    Ok(block)
+}
+
+/// Decodes and verifies the sequenced transaction `bytes`.
+fn decode_transaction(
+    bytes: &[u8],
+) -> astria_eyre::eyre::Result<rollup_core::transaction::v1::Transaction> {
+    let raw_transaction =
+        rollup_core::generated::protocol::transaction::v1::Transaction::decode(bytes)
+            .wrap_err("failed to decode transaction")?;
+    rollup_core::transaction::v1::Transaction::try_from_raw(raw_transaction)
+        .wrap_err("failed to verify transaction")
+}
+
+/// Decodes and verifies `transactions` on the blocking thread pool, returning the results in
+/// the order of `transactions`.
+///
+/// Signature verification dominates the cost of a transaction, so it is done for all
+/// transactions of a block up front and in parallel, while execution stays sequential.
+async fn decode_transactions(
+    transactions: &[Bytes],
+) -> Vec<astria_eyre::eyre::Result<rollup_core::transaction::v1::Transaction>> {
+    let threads = std::thread::available_parallelism().map_or(1, std::num::NonZeroUsize::get);
+    let chunk_size = transactions.len().div_ceil(threads).max(1);
+    let tasks: Vec<_> = transactions
+        .chunks(chunk_size)
+        .map(|chunk| {
+            let chunk = chunk.to_vec();
+            tokio::task::spawn_blocking(move || {
+                chunk
+                    .iter()
+                    .map(|bytes| decode_transaction(bytes))
+                    .collect::<Vec<_>>()
+            })
+        })
+        .collect();
+    let mut decoded = Vec::with_capacity(transactions.len());
+    for task in tasks {
+        decoded.extend(task.await.expect("decoding transactions must not panic"));
+    }
+    decoded
+}
+
+/// Executes the sequenced `transaction`.
+///
+/// A transaction passing [`crate::transaction::check_transaction`] consumes its nonce. Its
+/// actions are then executed against a fork of `state` which is only applied if all of them
+/// succeed and the compute units they used are paid for, so a failing action leaves `state`
+/// unchanged apart from the consumed nonce.
+async fn execute_transaction(
+    state: &mut StateDelta<Snapshot>,
+    transaction: &rollup_core::transaction::v1::Transaction,
+    chain_id: &str,
+) -> astria_eyre::eyre::Result<()> {
+    crate::transaction::check_transaction(
+        transaction,
+        chain_id,
+        &*state,
+        crate::transaction::NonceCheck::State,
+    )
+    .await
+    .wrap_err("transaction failed checks")?;
+    crate::transaction::consume_nonce(transaction, &mut *state)?;
+    // the multisig account or the granter if the transaction acts for one
+    let sender = transaction.address_bytes();
+    let fee_payer = transaction.fee_payer_address_bytes();
+
+    let mut transaction_state = StateDelta::new(&mut *state);
+    crate::transaction::spend_sponsor_limit_for_flat_fees(transaction, &mut transaction_state)
+        .await?;
+    // every action is charged for the state it reads and writes while it is executed
+    let mut usages = Vec::with_capacity(transaction.actions().len());
+    for action in transaction.actions() {
+        let mut action_state = MeteredState::new(&mut transaction_state);
+        match action {
+            rollup_core::transaction::v1::Action::Transfer(transfer) => {
+                execute_transfer(transfer, sender, &mut action_state)
+                    .await
+                    .wrap_err("failed executing transfer")?;
+            }
+            rollup_core::transaction::v1::Action::Text(send_text) => {
+                execute_send_text(send_text, sender, fee_payer, &mut action_state)
+                    .await
+                    .wrap_err("failed executing send text")?;
+            }
+            rollup_core::transaction::v1::Action::SetSponsorLimit(set_sponsor_limit) => {
+                execute_set_sponsor_limit(set_sponsor_limit, sender, &mut action_state)
+                    .wrap_err("failed executing set sponsor limit")?;
+            }
+            rollup_core::transaction::v1::Action::GrantSessionKey(grant_session_key) => {
+                execute_grant_session_key(grant_session_key, sender, &mut action_state)
+                    .wrap_err("failed executing grant session key")?;
+            }
+            rollup_core::transaction::v1::Action::RevokeSessionKey(revoke_session_key) => {
+                execute_revoke_session_key(revoke_session_key, sender, &mut action_state);
+            }
+            rollup_core::transaction::v1::Action::RegisterMultisig(register_multisig) => {
+                execute_register_multisig(register_multisig, &mut action_state)
+                    .await
+                    .wrap_err("failed executing register multisig")?;
+            }
+        };
+        usages.push(action_state.usage());
+    }
+    crate::transaction::pay_compute_fees(transaction, &usages, &mut transaction_state).await?;
+    transaction_state.apply();
+    Ok(())
+}
+
+pub struct RollupExecutionService {
+    pub(crate) storage: Storage,
+    pub(crate) config: RollupConfig,
+    pub(crate) mempool: Mempool,
+    pub(crate) tracker: TransactionTracker,
+}
+
+impl RollupExecutionService {
+    /// Returns a service executing blocks against `storage`, which must hold the genesis state
+    /// (see [`crate::rollup::init_genesis_state`]).
+    #[must_use]
+    pub fn new(storage: Storage, config: RollupConfig) -> Self {
+        Self {
+            storage,
+            mempool: Mempool::new(config.rollup_name.clone()),
+            config,
+            tracker: TransactionTracker::new(),
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -70,13 +221,11 @@ impl ExecutionService for RollupExecutionService {
     ) -> Result<Response<execution::Block>, Status> {
         let request = request.into_inner();
         let snapshot = self.storage.latest_snapshot();
-        let state_delta = StateDelta::new(snapshot);
         match request.identifier {
             Some(identidfier) => match identidfier.identifier {
                 Some(id) => match id {
                     execution::block_identifier::Identifier::BlockNumber(height) => {
-                        let block = state_delta.get_block(height).await.unwrap();
-                        Ok(Response::new(block.into_raw()))
+                        Ok(Response::new(read_block(&snapshot, height).await?))
                     }
                     execution::block_identifier::Identifier::BlockHash(_) => {
                         Err(Status::unimplemented("Get Block by hash not implemented"))
@@ -94,20 +243,14 @@ impl ExecutionService for RollupExecutionService {
     ) -> Result<Response<execution::BatchGetBlocksResponse>, Status> {
         let request = request.into_inner();
         let snapshot = self.storage.latest_snapshot();
-        let state_delta = StateDelta::new(snapshot);
         let mut blocks = execution::BatchGetBlocksResponse { blocks: Vec::new() };
         for identifier in request.identifiers {
             match identifier.identifier {
                 Some(id) => match id {
                     execution::block_identifier::Identifier::BlockNumber(block_number) => {
-                        blocks.blocks.push(
-                            state_delta
-                                .get_block(block_number)
-                                .await
-                                .unwrap()
-                                .to_owned()
-                                .into_raw(),
-                        );
+                        blocks
+                            .blocks
+                            .push(read_block(&snapshot, block_number).await?);
                     }
                     execution::block_identifier::Identifier::BlockHash(_) => {
                         return Err(Status::unimplemented("Get Block by hash not implemented"))
@@ -125,7 +268,9 @@ impl ExecutionService for RollupExecutionService {
     ) -> Result<Response<execution::Block>, Status> {
         debug!("executing block");
         let request = request.into_inner();
-        let timestamp = request.timestamp.unwrap();
+        let timestamp = request
+            .timestamp
+            .ok_or_else(|| Status::invalid_argument("missing block timestamp"))?;
         let mut transactions: Vec<Bytes> = Vec::new();
         let mut deposits = Vec::new();
 
@@ -140,10 +285,44 @@ impl ExecutionService for RollupExecutionService {
         }
 
         let snapshot = self.storage.latest_snapshot();
+        // only executing blocks writes verifiable state, so the root of the snapshot is the state
+        // root of the parent block
+        let parent_state_root = snapshot.root_hash().await.map_err(|err| {
+            Status::internal(format!("failed to get state root of parent block: {err:#}"))
+        })?;
+        let storage_version = snapshot.version().wrapping_add(1);
         let mut state_delta = StateDelta::new(snapshot);
-        let commitment = state_delta.get_commitment_state().await.unwrap();
+        let commitment = state_delta
+            .get_commitment_state()
+            .await
+            .map_err(|err| Status::internal(format!("failed to get commitment state: {err:#}")))?;
         let block_height = commitment.soft;
 
+        let height = block_height + 1;
+        state_delta
+            .put_block_state_root(block_height, parent_state_root.0)
+            .map_err(|err| {
+                Status::internal(format!(
+                    "failed to write state root of parent block: {err:#}"
+                ))
+            })?;
+        // transactions are checked for expiry against the timestamp of the block they are in
+        state_delta
+            .put_block_timestamp(timestamp.clone())
+            .map_err(|err| Status::internal(format!("failed to write block timestamp: {err:#}")))?;
+        let block_params = state_delta
+            .get_block_params()
+            .await
+            .map_err(|err| Status::internal(format!("failed to get block params: {err:#}")))?;
+        let mut budget = BlockBudget::new(block_params);
+        crate::bridge::migrate_bridge_configs(
+            &mut state_delta,
+            RollupId::from_unhashed_bytes(&self.config.rollup_name),
+            &self.config.bridge_accounts,
+        )
+        .await
+        .map_err(|err| Status::internal(format!("failed to migrate bridge configs: {err:#}")))?;
+
         // Execute transactions
         debug!("number of deposits detected: {:?}", deposits.len());
         let mut executed_deposits: Vec<
@@ -152,115 +331,202 @@ impl ExecutionService for RollupExecutionService {
         for raw_deposit in deposits {
             let deposit =
                 astria_core::sequencerblock::v1::block::Deposit::try_from_raw(raw_deposit.clone())
-                    .unwrap();
-            // fn try_deposit
-            // - fn parse_deposit
-            // - fn verify_deposit
-            // - fn execute_deposit
-            let deposit_address = match astria_core::primitive::v1::Address::from_str(
-                &deposit.destination_chain_address,
-            ) {
-                Ok(address) => address,
-                Err(_) => {
-                    debug!(
-                        "failed verifying deposit address: {:?}",
-                        deposit.destination_chain_address
-                    );
-                    continue;
-                }
-            };
+                    .map_err(|err| Status::invalid_argument(format!("invalid deposit: {err:#}")))?;
             debug!(
-                deposit_address = deposit_address.display_address().to_string(),
+                destination_chain_address = %deposit.destination_chain_address,
                 bridge_address = deposit.bridge_address.display_address().to_string(),
             );
 
-            if state_delta
-                .is_bridge(&deposit.bridge_address)
+            let rejected = crate::bridge::deposit::check_deposit(&state_delta, &deposit)
                 .await
-                .unwrap()
-            {
-                state_delta
-                    .increase_balance(&deposit_address, &deposit.asset, deposit.amount)
+                .map_err(|err| Status::internal(format!("failed to check deposit: {err:#}")))?;
+            // deposits that cannot be credited are refunded, except for deposits that were
+            // already processed, which were credited or refunded before
+            let status = match rejected {
+                Some(DepositRejected::AlreadyProcessed) => {
+                    debug!("skipped deposit that was already processed");
+                    continue;
+                }
+                Some(rejected) => crate::bridge::deposit::refund_deposit(
+                    &mut state_delta,
+                    &deposit,
+                    height,
+                    rejected.to_string(),
+                )
+                .map_err(|err| Status::internal(format!("failed to refund deposit: {err:#}")))?,
+                // deposits beyond the block limit are refunded, as they cannot be sequenced again
+                None => match budget.try_add_deposit() {
+                    Ok(()) => crate::bridge::deposit::execute_deposit(
+                        &mut state_delta,
+                        &deposit,
+                        height,
+                        &mut budget,
+                    )
                     .await
-                    .unwrap();
-                executed_deposits.push(raw_deposit.clone());
+                    .map_err(|err| {
+                        Status::internal(format!("failed to execute deposit: {err:#}"))
+                    })?,
+                    Err(exceeded) => crate::bridge::deposit::refund_deposit(
+                        &mut state_delta,
+                        &deposit,
+                        height,
+                        exceeded.to_string(),
+                    )
+                    .map_err(|err| {
+                        Status::internal(format!("failed to refund deposit: {err:#}"))
+                    })?,
+                },
+            };
+            if let DepositStatus::Failed { reason } = status {
+                debug!(%reason, "deposit failed, recorded refund");
             }
+            executed_deposits.push(raw_deposit.clone());
         }
 
         debug!("number of transactions detected: {:?}", transactions.len());
         let mut executed_transaction = Vec::new();
-        for tx in transactions {
-            // fn try_execute_transaction
-            // - fn parse_transaction
-            // - fn verify_transaction
-            // - fn execute_transaction
-            let raw_transaction =
-                rollup_core::generated::protocol::transaction::v1::Transaction::decode(tx.clone())
-                    .unwrap();
-
-            let transaction =
-                rollup_core::transaction::v1::Transaction::try_from_raw(raw_transaction).unwrap();
-            let sender = transaction.verification_key().address_bytes();
-            let actions = transaction.actions();
-            for action in actions {
-                match action {
-                    rollup_core::transaction::v1::Action::Transfer(transfer) => {
-                        execute_transfer(transfer, sender, &mut state_delta)
-                            .await
-                            .unwrap();
-                    }
-                    rollup_core::transaction::v1::Action::Text(send_text) => {
-                        execute_send_text(send_text, sender, &mut state_delta)
-                            .await
-                            .unwrap();
-                    }
+        let mut executed_transaction_hashes = Vec::new();
+        let mut skipped_transactions = Vec::new();
+        let decoded_transactions = decode_transactions(&transactions).await;
+        let transactions = crate::transaction::ordering::sort_for_execution(
+            transactions.into_iter().zip(decoded_transactions).collect(),
+            |(_, transaction)| {
+                let transaction = transaction.as_ref().ok()?;
+                Some((
+                    transaction.group(),
+                    *transaction.address_bytes(),
+                    transaction.nonce(),
+                ))
+            },
+        );
+        let mut skipped_accounts = HashSet::new();
+        for (tx, transaction) in transactions {
+            let transaction_hash = receipts::transaction_hash(&tx);
+            let bytes = tx.len().try_into().unwrap_or(u64::MAX);
+            let text_bytes = transaction.as_ref().map_or(0, block_limits::text_bytes);
+            let account = transaction
+                .as_ref()
+                .ok()
+                .map(|transaction| *transaction.address_bytes());
+            // a transaction too large for any block fails below without taking up room
+            let fits_block = block_limits::check_fits_block(budget.params(), bytes, text_bytes);
+            if fits_block.is_ok() {
+                // transactions beyond the block limits are left out of the block without a
+                // receipt and without consuming their nonces, and so are the later transactions
+                // of their sender, whose nonces would not follow anymore. They are not sequenced
+                // again, so once the block is committed they are reported as dropped and their
+                // nonces are released
+                let skipped = if account.is_some_and(|account| skipped_accounts.contains(&account))
+                {
+                    Err("an earlier transaction of the sender was skipped".to_string())
+                } else {
+                    budget
+                        .try_add_transaction(bytes, text_bytes)
+                        .map_err(|exceeded| exceeded.to_string())
                 };
+                if let Err(reason) = skipped {
+                    debug!(
+                        transaction_hash = %hex::encode(transaction_hash),
+                        reason,
+                        "skipped transaction",
+                    );
+                    skipped_accounts.extend(account);
+                    skipped_transactions.push((transaction_hash, transaction.ok()));
+                    continue;
+                }
             }
+            // a failing transaction is still included in the block, but none of its writes are
+            // applied and the failure is recorded in its receipt
+            let result = match (transaction, fits_block) {
+                (Ok(transaction), Ok(())) => {
+                    execute_transaction(&mut state_delta, &transaction, &self.config.rollup_name)
+                        .await
+                }
+                (Ok(_), Err(too_large)) => Err(too_large.into()),
+                (Err(err), _) => Err(err),
+            };
+            let error = match result {
+                Ok(()) => None,
+                Err(err) => {
+                    debug!(
+                        transaction_hash = %hex::encode(transaction_hash),
+                        error = %err,
+                        "transaction failed",
+                    );
+                    Some(format!("{err:#}"))
+                }
+            };
+            let index = executed_transaction
+                .len()
+                .try_into()
+                .map_err(|_| Status::invalid_argument("block holds too many transactions"))?;
+            state_delta
+                .put_receipt(&Receipt {
+                    transaction_hash,
+                    height,
+                    index,
+                    error,
+                })
+                .await
+                .map_err(|err| Status::internal(format!("failed to write receipt: {err:#}")))?;
             executed_transaction.push(tx);
+            executed_transaction_hashes.push(transaction_hash);
         }
 
-        // calculate the block parameters and store it
-        // fn finalize_block
-        // - fn calculate_merkle_root
-        // - fn compute_block_hash
-        // - fn store_block
+        if !skipped_transactions.is_empty() {
+            info!(
+                height,
+                skipped_transactions = skipped_transactions.len(),
+                "block limits reached, skipped transactions"
+            );
+        }
 
         // calculate merkle root of executed transactions and deposits
         let mut executed_transactions_merkle = merkle::Tree::new();
         let mut executed_deposits_merkle = merkle::Tree::new();
 
         for executed_tx in executed_deposits {
-            executed_deposits_merkle
-                .push(executed_tx.source_transaction_id.unwrap().inner.as_ref());
+            // the source transaction id was checked when the deposit was decoded
+            let source_transaction_id = executed_tx.source_transaction_id.unwrap_or_default();
+            executed_deposits_merkle.push(source_transaction_id.inner.as_ref());
         }
 
         for executed_tx in executed_transaction {
             executed_transactions_merkle.push(executed_tx.as_ref());
         }
 
-        let transaction_merkle_root = executed_transactions_merkle.root();
-        let deposit_merkle_root = executed_deposits_merkle.root();
-
-        let block_hash = compute_block_hash(
-            &request.prev_block_hash,
-            &transaction_merkle_root,
-            &deposit_merkle_root,
-            timestamp.clone(),
-        );
-
-        let new_block = astria_core::generated::astria::execution::v1::Block {
-            number: block_height + 1,
-            parent_block_hash: request.prev_block_hash, // get last block hash
-            hash: Bytes::copy_from_slice(&block_hash), // hash with previous block hash and transactions
-            timestamp: Some(timestamp),
+        // the header is committed together with the executed state, and the block hash is
+        // derived from it and the resulting state root
+        let header = BlockHeader {
+            number: height,
+            parent_block_hash: request.prev_block_hash,
+            transactions_root: executed_transactions_merkle.root(),
+            deposits_root: executed_deposits_merkle.root(),
+            timestamp,
         };
-        let block = Block::try_from_raw(new_block.clone()).unwrap();
-
-        state_delta.put_block(block, block_height + 1).unwrap();
-        let write_batch: cnidarium::StagedWriteBatch =
-            self.storage.prepare_commit(state_delta).await.unwrap();
-
-        let _hash = self.storage.commit_batch(write_batch).unwrap();
+        state_delta
+            .put_block_header(&header)
+            .and_then(|()| state_delta.put_block_height(height.into()))
+            .and_then(|()| {
+                state_delta.put_storage_version_by_height(height.into(), storage_version)
+            })
+            .map_err(|err| Status::internal(format!("failed to write block: {err:#}")))?;
+        let state_root = commit_state_delta(&self.storage, state_delta)
+            .await
+            .map_err(|err| Status::internal(format!("failed to commit block: {err:#}")))?;
+        debug!(
+            height,
+            state_root = %hex::encode(state_root.0),
+            "executed block"
+        );
+        let new_block = header.into_block(&state_root.0);
+        self.tracker.sequenced(&executed_transaction_hashes, height);
+        for (transaction_hash, transaction) in skipped_transactions {
+            self.tracker.dropped(transaction_hash);
+            if let Some(transaction) = transaction {
+                self.mempool.release(&transaction);
+            }
+        }
 
         Ok(Response::new(new_block))
     }
@@ -270,20 +536,13 @@ impl ExecutionService for RollupExecutionService {
         _request: Request<execution::GetCommitmentStateRequest>,
     ) -> Result<Response<execution::CommitmentState>, Status> {
         let snapshot = self.storage.latest_snapshot();
-        let delta_state = StateDelta::new(snapshot);
-        let commitment_state = delta_state.get_commitment_state().await.unwrap();
-
-        let soft = delta_state
-            .get_block(commitment_state.soft)
+        let commitment_state = snapshot
+            .get_commitment_state()
             .await
-            .unwrap()
-            .into_raw();
+            .map_err(|err| Status::internal(format!("failed to get commitment state: {err:#}")))?;
 
-        let firm = delta_state
-            .get_block(commitment_state.firm)
-            .await
-            .unwrap()
-            .into_raw();
+        let soft = read_block(&snapshot, commitment_state.soft).await?;
+        let firm = read_block(&snapshot, commitment_state.firm).await?;
 
         let celestia_height = commitment_state.celestia;
         Ok(Response::new(execution::CommitmentState {
@@ -298,36 +557,46 @@ impl ExecutionService for RollupExecutionService {
         request: Request<execution::UpdateCommitmentStateRequest>,
     ) -> Result<Response<execution::CommitmentState>, Status> {
         let snapshot = self.storage.latest_snapshot();
-        let mut state_delta = StateDelta::new(snapshot);
-        let commitment_state_request = request.into_inner().commitment_state.unwrap();
-
-        let soft_block_request = commitment_state_request.soft.as_ref().unwrap();
-        let firm_block_request = commitment_state_request.firm.as_ref().unwrap();
+        let mut state_delta = StateDelta::new(snapshot.clone());
+        let commitment_state_request = request
+            .into_inner()
+            .commitment_state
+            .ok_or_else(|| Status::invalid_argument("missing commitment state"))?;
+
+        let soft_block_request = commitment_state_request
+            .soft
+            .as_ref()
+            .ok_or_else(|| Status::invalid_argument("missing soft block"))?;
+        let firm_block_request = commitment_state_request
+            .firm
+            .as_ref()
+            .ok_or_else(|| Status::invalid_argument("missing firm block"))?;
         let soft_request = soft_block_request.number;
         let firm_request = firm_block_request.number;
-        let soft_block = state_delta.get_block(soft_request).await.unwrap();
-        let firm_block = state_delta.get_block(firm_request).await.unwrap();
+        let soft_block = read_block(&snapshot, soft_request).await?;
+        let firm_block = read_block(&snapshot, firm_request).await?;
 
-        if *soft_block.hash() != soft_block_request.hash {
+        if soft_block.hash != soft_block_request.hash {
             error!(
                 "soft block hash does not match: current: {:?},  request: {:?}",
-                soft_block.hash().to_owned(),
-                soft_block_request.hash
+                soft_block.hash, soft_block_request.hash
             );
             return Err(Status::invalid_argument("Soft block hash does not match"));
         }
 
-        if *firm_block.hash() != firm_block_request.hash {
+        if firm_block.hash != firm_block_request.hash {
             return Err(Status::invalid_argument("Firm block hash does not match"));
         }
 
+        let base_celestia_height = commitment_state_request
+            .base_celestia_height
+            .try_into()
+            .map_err(|_| Status::invalid_argument("base celestia height does not fit into u32"))?;
         state_delta
-            .put_commitment_state(
-                soft_request,
-                firm_request,
-                commitment_state_request.base_celestia_height as u32,
-            )
-            .unwrap();
+            .put_commitment_state(soft_request, firm_request, base_celestia_height)
+            .map_err(|err| {
+                Status::internal(format!("failed to write commitment state: {err:#}"))
+            })?;
 
         let new_commitment_state = execution::CommitmentState {
             soft: Some(soft_block_request.to_owned()),
@@ -335,9 +604,363 @@ impl ExecutionService for RollupExecutionService {
             base_celestia_height: commitment_state_request.base_celestia_height,
         };
 
-        let write_batch = self.storage.prepare_commit(state_delta).await.unwrap();
-        let _hash = self.storage.commit_batch(write_batch).unwrap();
+        commit_state_delta(&self.storage, state_delta)
+            .await
+            .map_err(|err| {
+                Status::internal(format!("failed to commit commitment state: {err:#}"))
+            })?;
+        self.tracker.commitment_updated(soft_request, firm_request);
 
         Ok(Response::new(new_commitment_state))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{
+        crypto::SigningKey, generated::astria::primitive::v1::Uint128,
+        generated::astria::sequencerblock::v1::RollupData,
+    };
+    use rollup_core::{
+        generated::protocol::genesis::v1::{Account, GenesisAppState},
+        transaction::v1::{action::SendText, Action, TransactionBody},
+    };
+
+    use super::*;
+    use crate::{
+        accounts::{state_ext::astria_address, StateReadExt as _},
+        receipts::StateReadExt as _,
+        transaction::tracker::TransactionStatus,
+    };
+
+    const ROLLUP_NAME: &str = "astria-chat";
+
+    /// Returns a genesis state in which the accounts of `signing_keys` are funded.
+    fn genesis(signing_keys: &[SigningKey]) -> GenesisAppState {
+        GenesisAppState {
+            rollup_name: ROLLUP_NAME.to_string(),
+            accounts: signing_keys
+                .iter()
+                .map(|signing_key| Account {
+                    address: Some(astria_address(&signing_key.address_bytes()).into_raw()),
+                    balance: Some(Uint128 { lo: 1_000, hi: 0 }),
+                })
+                .collect(),
+            ..GenesisAppState::default()
+        }
+    }
+
+    /// Returns an execution service over fresh storage holding a genesis state in which the
+    /// accounts of `signing_keys` are funded.
+    async fn service(
+        signing_keys: &[SigningKey],
+    ) -> (cnidarium::TempStorage, Arc<RollupExecutionService>) {
+        service_with_genesis(&genesis(signing_keys)).await
+    }
+
+    /// Returns an execution service over fresh storage holding `genesis`.
+    async fn service_with_genesis(
+        genesis: &GenesisAppState,
+    ) -> (cnidarium::TempStorage, Arc<RollupExecutionService>) {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        crate::rollup::init_genesis_state(&storage, genesis)
+            .await
+            .unwrap();
+        let service = Arc::new(RollupExecutionService::new(
+            Storage::clone(&storage),
+            RollupConfig {
+                execution_grpc_addr: String::new(),
+                composer_addr: String::new(),
+                rollup_name: ROLLUP_NAME.to_string(),
+                sequencer_genesis_block_height: 0,
+                celestia_genesis_block_height: 0,
+                celestia_block_variance: 0,
+                bridge_accounts: Vec::new(),
+            },
+        ));
+        (storage, service)
+    }
+
+    /// Executes a block of `transactions` on top of the soft block and makes it the new soft and
+    /// firm block.
+    async fn execute_block(
+        service: &Arc<RollupExecutionService>,
+        transactions: Vec<Bytes>,
+    ) -> execution::Block {
+        let snapshot = service.storage.latest_snapshot();
+        let soft = snapshot.get_commitment_state().await.unwrap().soft;
+        let (parent, _) = block::get_block(&snapshot, soft).await.unwrap();
+        let block = service
+            .clone()
+            .execute_block(Request::new(execution::ExecuteBlockRequest {
+                prev_block_hash: parent.hash,
+                transactions: transactions
+                    .into_iter()
+                    .map(|transaction| RollupData {
+                        value: Some(SequencedData(transaction)),
+                    })
+                    .collect(),
+                timestamp: Some(pbjson_types::Timestamp {
+                    seconds: (soft + 1).into(),
+                    nanos: 0,
+                }),
+            }))
+            .await
+            .unwrap()
+            .into_inner();
+        service
+            .clone()
+            .update_commitment_state(Request::new(execution::UpdateCommitmentStateRequest {
+                commitment_state: Some(execution::CommitmentState {
+                    soft: Some(block.clone()),
+                    firm: Some(block.clone()),
+                    base_celestia_height: 0,
+                }),
+            }))
+            .await
+            .unwrap();
+        block
+    }
+
+    fn transaction(nonce: u32) -> Bytes {
+        text_transaction(&SigningKey::from([1; 32]), nonce, "gm")
+    }
+
+    /// Returns an encoded transaction of `signing_key` posting `text`.
+    fn text_transaction(signing_key: &SigningKey, nonce: u32, text: &str) -> Bytes {
+        TransactionBody::builder()
+            .nonce(nonce)
+            .chain_id("astria-chat")
+            .actions(vec![Action::Text(SendText {
+                text: text.to_string(),
+                from: "alice".to_string(),
+                fee_asset: "nria".parse().unwrap(),
+            })])
+            .try_build()
+            .unwrap()
+            .sign(signing_key)
+            .into_raw()
+            .encode_to_vec()
+            .into()
+    }
+
+    #[tokio::test]
+    async fn decode_transactions_keeps_sequenced_order() {
+        let mut transactions: Vec<_> = (0..100).map(transaction).collect();
+        transactions[50] = Bytes::from_static(b"not a transaction");
+
+        let decoded = decode_transactions(&transactions).await;
+        assert_eq!(decoded.len(), transactions.len());
+        for (nonce, transaction) in decoded.into_iter().enumerate() {
+            if nonce == 50 {
+                transaction.unwrap_err();
+            } else {
+                assert_eq!(transaction.unwrap().nonce(), u32::try_from(nonce).unwrap());
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn sequencing_the_same_transaction_again_keeps_its_receipt() {
+        let (storage, service) = service(&[SigningKey::from([1; 32])]).await;
+        let hash = receipts::transaction_hash(&transaction(0));
+
+        execute_block(&service, vec![transaction(0)]).await;
+        let receipt = storage
+            .latest_snapshot()
+            .get_receipt(&hash)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(receipt.height, 1);
+        assert_eq!(receipt.error, None);
+
+        // the same bytes sequenced again fail on the consumed nonce, but keep the first receipt
+        let block = execute_block(&service, vec![transaction(0)]).await;
+        assert_eq!(block.number, 2);
+        assert_eq!(
+            storage.latest_snapshot().get_receipt(&hash).await.unwrap(),
+            Some(receipt)
+        );
+    }
+
+    #[tokio::test]
+    async fn mixed_block_executes_undecodable_transactions_last() {
+        let (storage, service) = service(&[SigningKey::from([1; 32])]).await;
+        let undecodable = Bytes::from_static(b"not a transaction");
+
+        execute_block(
+            &service,
+            vec![undecodable.clone(), transaction(0), transaction(1)],
+        )
+        .await;
+
+        let snapshot = storage.latest_snapshot();
+        let mut order = Vec::new();
+        for transaction in [transaction(0), transaction(1), undecodable] {
+            let receipt = snapshot
+                .get_receipt(&receipts::transaction_hash(&transaction))
+                .await
+                .unwrap()
+                .unwrap();
+            order.push((receipt.index, receipt.error.is_some()));
+        }
+        // the sender's transactions run in nonce order ahead of the undecodable one
+        assert_eq!(order, vec![(0, false), (1, false), (2, true)]);
+    }
+
+    #[tokio::test]
+    async fn transactions_of_an_account_run_in_nonce_order() {
+        let alice = SigningKey::from([1; 32]);
+        let bob = SigningKey::from([2; 32]);
+        let (storage, service) = service(&[alice.clone(), bob.clone()]).await;
+        let transactions = vec![
+            text_transaction(&alice, 1, "second"),
+            text_transaction(&bob, 0, "gm"),
+            text_transaction(&alice, 0, "first"),
+        ];
+
+        execute_block(&service, transactions.clone()).await;
+
+        let snapshot = storage.latest_snapshot();
+        let mut order = Vec::new();
+        for transaction in &transactions {
+            let receipt = snapshot
+                .get_receipt(&receipts::transaction_hash(transaction))
+                .await
+                .unwrap()
+                .unwrap();
+            order.push((receipt.index, receipt.error.is_some()));
+        }
+        // alice's transactions take the places of hers in the sequenced order by nonce
+        assert_eq!(order, vec![(2, false), (1, false), (0, false)]);
+        assert_eq!(
+            snapshot
+                .get_account_nonce(&alice.address_bytes())
+                .await
+                .unwrap(),
+            2
+        );
+    }
+
+    #[tokio::test]
+    async fn transactions_beyond_block_limits_are_dropped() {
+        let signing_key = SigningKey::from([1; 32]);
+        let (storage, service) = service_with_genesis(&GenesisAppState {
+            block_params: Some(rollup_core::generated::protocol::genesis::v1::BlockParams {
+                max_transactions: 1,
+                ..Default::default()
+            }),
+            ..genesis(&[signing_key.clone()])
+        })
+        .await;
+        let transactions = vec![transaction(0), transaction(1)];
+        for (data, decoded) in transactions
+            .iter()
+            .zip(decode_transactions(&transactions).await)
+        {
+            service
+                .mempool
+                .check_and_insert(&decoded.unwrap(), &storage.latest_snapshot())
+                .await
+                .unwrap();
+            service.tracker.submitted(receipts::transaction_hash(data));
+        }
+
+        execute_block(&service, transactions).await;
+
+        let snapshot = storage.latest_snapshot();
+        let skipped = receipts::transaction_hash(&transaction(1));
+        assert_eq!(snapshot.get_receipt(&skipped).await.unwrap(), None);
+        assert_eq!(
+            service.tracker.status(&skipped, &snapshot).await.unwrap(),
+            Some(TransactionStatus::Dropped),
+        );
+        // the nonce of the skipped transaction can be used again
+        assert_eq!(
+            service
+                .mempool
+                .next_nonce(&signing_key.address_bytes(), &snapshot)
+                .await
+                .unwrap(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn oversized_transaction_does_not_keep_others_out_of_the_block() {
+        let keys: Vec<_> = (1..=4).map(|seed| SigningKey::from([seed; 32])).collect();
+        let (storage, service) = service_with_genesis(&GenesisAppState {
+            block_params: Some(rollup_core::generated::protocol::genesis::v1::BlockParams {
+                max_text_bytes: 4,
+                ..Default::default()
+            }),
+            ..genesis(&keys)
+        })
+        .await;
+        let oversized = text_transaction(&keys[0], 0, "good morning");
+        let fits = [
+            text_transaction(&keys[1], 0, "gm"),
+            text_transaction(&keys[2], 0, "gm"),
+        ];
+        let beyond_limit = text_transaction(&keys[3], 0, "gm");
+        let mut transactions = vec![oversized.clone()];
+        transactions.extend(fits.iter().cloned());
+        transactions.push(beyond_limit.clone());
+
+        execute_block(&service, transactions).await;
+
+        let snapshot = storage.latest_snapshot();
+        // the oversized transaction fails without taking up room in the block
+        let receipt = snapshot
+            .get_receipt(&receipts::transaction_hash(&oversized))
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(receipt.error.is_some());
+        for (index, transaction) in (1..).zip(&fits) {
+            let receipt = snapshot
+                .get_receipt(&receipts::transaction_hash(transaction))
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!((receipt.index, receipt.error), (index, None));
+        }
+        // only the transaction exceeding the remaining room is left out
+        assert_eq!(
+            snapshot
+                .get_receipt(&receipts::transaction_hash(&beyond_limit))
+                .await
+                .unwrap(),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn malformed_requests_are_invalid_arguments() {
+        let (_storage, service) = service(&[]).await;
+
+        let err = service
+            .clone()
+            .get_block(Request::new(execution::GetBlockRequest {
+                identifier: Some(execution::BlockIdentifier {
+                    identifier: Some(execution::block_identifier::Identifier::BlockNumber(1)),
+                }),
+            }))
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), tonic::Code::InvalidArgument);
+
+        let parent = execute_block(&service, Vec::new()).await;
+        let err = service
+            .clone()
+            .execute_block(Request::new(execution::ExecuteBlockRequest {
+                prev_block_hash: parent.hash,
+                transactions: Vec::new(),
+                timestamp: None,
+            }))
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), tonic::Code::InvalidArgument);
+    }
+}
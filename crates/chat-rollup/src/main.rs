@@ -1,13 +1,24 @@
+pub mod abci_query_router;
 pub mod accounts;
 pub mod address;
 pub mod assets;
 pub mod bridge;
 pub mod config;
 pub mod execution_service;
+pub mod mempool;
+pub mod multisig;
+pub mod proof;
+pub mod query_service;
+pub mod receipts;
+pub mod relayer;
 pub mod rollup;
+pub mod session_keys;
 pub mod snapshot;
+pub mod sponsors;
 pub mod storage;
+pub mod submission;
 pub mod text;
+pub mod transaction;
 
 use astria_eyre::eyre::WrapErr as _;
 use astria_sequencer::BUILD_INFO;
@@ -0,0 +1,308 @@
+//! Validates transactions submitted to the rollup before they are forwarded to the composer.
+//!
+//! Transactions are checked like they are before execution, so that transactions which would
+//! fail anyway are rejected before they cost sequencer fees. To allow clients to submit several
+//! transactions before the first one is executed, the mempool tracks the nonces of the
+//! transactions it forwarded per sender.
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use astria_core::{primitive::v1::ADDRESS_LEN, Protobuf as _};
+use astria_eyre::eyre::{ensure, Result, WrapErr as _};
+use cnidarium::StateRead;
+use prost::Message as _;
+use rollup_core::transaction::v1::Transaction;
+
+use crate::{
+    accounts::StateReadExt as _,
+    rollup::{block_limits, state_ext::StateReadExt as _},
+    transaction::{check_transaction, NonceCheck},
+};
+
+/// How long a forwarded transaction reserves its nonce. If the transaction is not executed in
+/// that time (for example because it was dropped by the sequencer), its nonce can be reused.
+const PENDING_NONCE_TTL: Duration = Duration::from_secs(60);
+
+#[derive(Clone)]
+pub(crate) struct Mempool {
+    chain_id: String,
+    pending_nonce_ttl: Duration,
+    pending_nonces: Arc<Mutex<HashMap<[u8; ADDRESS_LEN], PendingNonce>>>,
+}
+
+struct PendingNonce {
+    /// The nonce following the last nonce forwarded for the sender.
+    next_nonce: u32,
+    expires_at: Instant,
+}
+
+impl Mempool {
+    /// Returns a mempool accepting transactions for the rollup with `chain_id`.
+    pub(crate) fn new(chain_id: String) -> Self {
+        Self {
+            chain_id,
+            pending_nonce_ttl: PENDING_NONCE_TTL,
+            pending_nonces: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Checks `transaction` against `state`, and reserves its nonce if it passes.
+    ///
+    /// The transaction must use the next nonce of its signer: the nonce in `state`, or the nonce
+    /// following the last one forwarded for the signer if that is still pending. It must also fit
+    /// into a block, as a transaction too large for one always fails.
+    ///
+    /// # Errors
+    /// Returns an error describing why the transaction was rejected.
+    pub(crate) async fn check_and_insert<S: StateRead>(
+        &self,
+        transaction: &Transaction,
+        state: &S,
+    ) -> Result<()> {
+        check_transaction(transaction, &self.chain_id, state, NonceCheck::Caller).await?;
+        let block_params = state
+            .get_block_params()
+            .await
+            .wrap_err("failed to get block params")?;
+        block_limits::check_fits_block(
+            &block_params,
+            u64::try_from(transaction.to_raw().encoded_len()).unwrap_or(u64::MAX),
+            block_limits::text_bytes(transaction),
+        )?;
+        let current_nonce = state
+            .get_account_nonce(transaction.address_bytes())
+            .await
+            .wrap_err("failed to get account nonce")?;
+
+        let now = Instant::now();
+        let mut pending_nonces = self
+            .pending_nonces
+            .lock()
+            .expect("mempool lock must not be poisoned");
+        pending_nonces.retain(|_, pending| pending.expires_at > now);
+        let expected_nonce =
+            next_nonce(&pending_nonces, transaction.address_bytes(), current_nonce);
+        ensure!(
+            transaction.nonce() == expected_nonce,
+            "invalid nonce: expected `{expected_nonce}`, got `{}`",
+            transaction.nonce(),
+        );
+        pending_nonces.insert(
+            *transaction.address_bytes(),
+            PendingNonce {
+                next_nonce: expected_nonce.saturating_add(1),
+                expires_at: now + self.pending_nonce_ttl,
+            },
+        );
+        Ok(())
+    }
+
+    /// Returns the nonce the next transaction of the account with `address_bytes` must use to be
+    /// accepted: the nonce in `state`, or the nonce following the last one forwarded for the
+    /// account if that is still pending.
+    ///
+    /// # Errors
+    /// Returns an error if the nonce cannot be read from `state`.
+    pub(crate) async fn next_nonce<S: StateRead>(
+        &self,
+        address_bytes: &[u8; ADDRESS_LEN],
+        state: &S,
+    ) -> Result<u32> {
+        let current_nonce = state
+            .get_account_nonce(address_bytes)
+            .await
+            .wrap_err("failed to get account nonce")?;
+        let now = Instant::now();
+        let mut pending_nonces = self
+            .pending_nonces
+            .lock()
+            .expect("mempool lock must not be poisoned");
+        pending_nonces.retain(|_, pending| pending.expires_at > now);
+        Ok(next_nonce(&pending_nonces, address_bytes, current_nonce))
+    }
+
+    /// Releases the nonce reserved by `transaction`, for example because it could not be
+    /// forwarded.
+    pub(crate) fn remove(&self, transaction: &Transaction) {
+        let mut pending_nonces = self
+            .pending_nonces
+            .lock()
+            .expect("mempool lock must not be poisoned");
+        if let Some(pending) = pending_nonces.get_mut(transaction.address_bytes()) {
+            // only roll back if no later transaction of the sender was accepted since
+            if pending.next_nonce == transaction.nonce().saturating_add(1) {
+                pending.next_nonce = transaction.nonce();
+            }
+        }
+    }
+
+    /// Releases the nonce of `transaction`, which was left out of a block and will not be
+    /// executed, so that its sender can use the nonce again.
+    ///
+    /// Nonces reserved after it are released as well, as the transactions following it in the
+    /// block were left out too. Nonces consumed in the meantime stay used, as the next nonce is
+    /// never below the nonce in state.
+    pub(crate) fn release(&self, transaction: &Transaction) {
+        let mut pending_nonces = self
+            .pending_nonces
+            .lock()
+            .expect("mempool lock must not be poisoned");
+        if let Some(pending) = pending_nonces.get_mut(transaction.address_bytes()) {
+            pending.next_nonce = pending.next_nonce.min(transaction.nonce());
+        }
+    }
+}
+
+fn next_nonce(
+    pending_nonces: &HashMap<[u8; ADDRESS_LEN], PendingNonce>,
+    address_bytes: &[u8; ADDRESS_LEN],
+    current_nonce: u32,
+) -> u32 {
+    pending_nonces
+        .get(address_bytes)
+        .map_or(current_nonce, |pending| {
+            pending.next_nonce.max(current_nonce)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{crypto::SigningKey, primitive::v1::asset};
+    use cnidarium::StateDelta;
+    use rollup_core::transaction::v1::{action::SendText, Action, TransactionBody};
+
+    use super::*;
+    use crate::{accounts::StateWriteExt as _, rollup::state_ext::StateWriteExt as _};
+
+    const CHAIN_ID: &str = "astria-chat";
+
+    fn transaction(nonce: u32) -> Transaction {
+        TransactionBody::builder()
+            .nonce(nonce)
+            .chain_id(CHAIN_ID)
+            .actions(vec![Action::Text(SendText {
+                text: "gm".to_string(),
+                from: "alice".to_string(),
+                fee_asset: "nria".parse().unwrap(),
+            })])
+            .try_build()
+            .unwrap()
+            .sign(&SigningKey::from([1; 32]))
+    }
+
+    fn funded_state(storage: &cnidarium::TempStorage) -> StateDelta<cnidarium::Snapshot> {
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        let fee_asset: asset::Denom = "nria".parse().unwrap();
+        state
+            .put_account_balance(transaction(0).address_bytes(), &fee_asset, 10)
+            .unwrap();
+        state
+    }
+
+    #[tokio::test]
+    async fn accepts_consecutive_nonces() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let state = funded_state(&storage);
+        let mempool = Mempool::new(CHAIN_ID.to_string());
+
+        mempool
+            .check_and_insert(&transaction(0), &state)
+            .await
+            .unwrap();
+        mempool
+            .check_and_insert(&transaction(1), &state)
+            .await
+            .unwrap();
+        assert_eq!(
+            mempool
+                .next_nonce(transaction(0).address_bytes(), &state)
+                .await
+                .unwrap(),
+            2
+        );
+        // replayed and skipped nonces are rejected
+        mempool
+            .check_and_insert(&transaction(1), &state)
+            .await
+            .unwrap_err();
+        mempool
+            .check_and_insert(&transaction(3), &state)
+            .await
+            .unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn removed_transaction_releases_its_nonce() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let state = funded_state(&storage);
+        let mempool = Mempool::new(CHAIN_ID.to_string());
+
+        mempool
+            .check_and_insert(&transaction(0), &state)
+            .await
+            .unwrap();
+        mempool.remove(&transaction(0));
+        mempool
+            .check_and_insert(&transaction(0), &state)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn pending_nonces_expire() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let state = funded_state(&storage);
+        let mut mempool = Mempool::new(CHAIN_ID.to_string());
+        mempool.pending_nonce_ttl = Duration::ZERO;
+
+        mempool
+            .check_and_insert(&transaction(0), &state)
+            .await
+            .unwrap();
+        // the transaction was never executed, so its nonce can be used again
+        mempool
+            .check_and_insert(&transaction(0), &state)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn rejects_transactions_failing_checks() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let state = funded_state(&storage);
+
+        // wrong chain id
+        Mempool::new("other-chain".to_string())
+            .check_and_insert(&transaction(0), &state)
+            .await
+            .unwrap_err();
+
+        // no balance to pay fees
+        let state = StateDelta::new(storage.latest_snapshot());
+        Mempool::new(CHAIN_ID.to_string())
+            .check_and_insert(&transaction(0), &state)
+            .await
+            .unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn rejects_transactions_too_large_for_any_block() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = funded_state(&storage);
+        state
+            .put_block_params(&rollup_core::genesis::v1::BlockParams {
+                max_text_bytes: 1,
+                ..Default::default()
+            })
+            .unwrap();
+
+        Mempool::new(CHAIN_ID.to_string())
+            .check_and_insert(&transaction(0), &state)
+            .await
+            .unwrap_err();
+    }
+}
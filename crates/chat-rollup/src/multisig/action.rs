@@ -0,0 +1,35 @@
+use astria_eyre::eyre::{ensure, Result, WrapErr as _};
+use cnidarium::StateWrite;
+use rollup_core::transaction::v1::action::RegisterMultisig;
+
+use crate::multisig::{Multisig, StateReadExt as _, StateWriteExt as _};
+
+/// Registers the multisig account of `action` at the address derived from its keys and
+/// threshold.
+pub(crate) async fn execute_register_multisig<S: StateWrite>(
+    action: &RegisterMultisig,
+    mut state: S,
+) -> Result<()> {
+    let address = action.address_bytes();
+    ensure!(
+        state
+            .get_multisig(&address)
+            .await
+            .wrap_err("failed to get multisig account")?
+            .is_none(),
+        "multisig account is already registered",
+    );
+    state
+        .put_multisig(
+            &address,
+            &Multisig {
+                members: action
+                    .public_keys()
+                    .iter()
+                    .map(|key| *key.address_bytes())
+                    .collect(),
+                threshold: action.threshold(),
+            },
+        )
+        .wrap_err("failed to put multisig account")
+}
@@ -0,0 +1,21 @@
+//! Multisig accounts: accounts controlled by a set of keys, of which a threshold must sign.
+//!
+//! A multisig account is registered with a `RegisterMultisig` action and its address is derived
+//! from its keys and threshold, so changing either yields a new account. Transactions of the
+//! account name it in their body and carry the signatures of its members.
+
+pub(crate) mod action;
+pub(crate) mod state_ext;
+pub(crate) mod storage;
+
+use astria_core::primitive::v1::ADDRESS_LEN;
+pub(crate) use state_ext::{StateReadExt, StateWriteExt};
+
+/// A registered multisig account.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Multisig {
+    /// The addresses of the keys controlling the account.
+    pub(crate) members: Vec<[u8; ADDRESS_LEN]>,
+    /// The number of members that must sign a transaction of the account.
+    pub(crate) threshold: u32,
+}
@@ -0,0 +1,82 @@
+use astria_eyre::{
+    anyhow_to_eyre,
+    eyre::{Result, WrapErr as _},
+};
+use async_trait::async_trait;
+use cnidarium::{StateRead, StateWrite};
+use tracing::instrument;
+
+use super::{
+    storage::{self, keys},
+    Multisig,
+};
+use crate::{accounts::AddressBytes, storage::StoredValue};
+
+#[async_trait]
+pub(crate) trait StateReadExt: StateRead {
+    /// Returns the multisig account at `address`, or `None` if no such account was registered.
+    #[instrument(skip_all, fields(address = %address.display_address()), err)]
+    async fn get_multisig<TAddress: AddressBytes>(
+        &self,
+        address: &TAddress,
+    ) -> Result<Option<Multisig>> {
+        let Some(bytes) = self
+            .get_raw(&keys::multisig(address))
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading raw multisig account from state")?
+        else {
+            return Ok(None);
+        };
+        StoredValue::deserialize(&bytes)
+            .and_then(|value| {
+                storage::Multisig::try_from(value).map(|multisig| Some(multisig.into()))
+            })
+            .wrap_err("invalid multisig account bytes")
+    }
+}
+
+impl<T: StateRead + ?Sized> StateReadExt for T {}
+
+#[async_trait]
+pub(crate) trait StateWriteExt: StateWrite {
+    #[instrument(skip_all, fields(address = %address.display_address()), err)]
+    fn put_multisig<TAddress: AddressBytes>(
+        &mut self,
+        address: &TAddress,
+        multisig: &Multisig,
+    ) -> Result<()> {
+        let bytes = StoredValue::from(storage::Multisig::from(multisig))
+            .serialize()
+            .wrap_err("failed to serialize multisig account")?;
+        self.put_raw(keys::multisig(address), bytes);
+        Ok(())
+    }
+}
+
+impl<T: StateWrite> StateWriteExt for T {}
+
+#[cfg(test)]
+mod tests {
+    use cnidarium::StateDelta;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn put_and_get_multisig() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        let multisig = Multisig {
+            members: vec![[1u8; 20], [2u8; 20], [3u8; 20]],
+            threshold: 2,
+        };
+
+        assert_eq!(state.get_multisig(&[9u8; 20]).await.unwrap(), None);
+        state.put_multisig(&[9u8; 20], &multisig).unwrap();
+        assert_eq!(
+            state.get_multisig(&[9u8; 20]).await.unwrap(),
+            Some(multisig)
+        );
+        assert_eq!(state.get_multisig(&[8u8; 20]).await.unwrap(), None);
+    }
+}
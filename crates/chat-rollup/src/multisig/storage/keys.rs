@@ -0,0 +1,19 @@
+use crate::{accounts::AddressBytes, storage::keys::AccountPrefixer};
+
+const COMPONENT_PREFIX: &str = "multisig/";
+
+/// Example: `multisig/gGhH....zZ4=`.
+///                   |base64 chars|
+pub(in crate::multisig) fn multisig<TAddress: AddressBytes>(address: &TAddress) -> String {
+    AccountPrefixer::new(COMPONENT_PREFIX, address).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keys_have_component_prefix() {
+        assert!(multisig(&[1u8; 20]).starts_with(COMPONENT_PREFIX));
+    }
+}
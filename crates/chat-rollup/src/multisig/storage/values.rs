@@ -0,0 +1,53 @@
+use astria_core::primitive::v1::ADDRESS_LEN;
+use astria_eyre::eyre::bail;
+use borsh::{BorshDeserialize, BorshSerialize};
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(crate) struct Value(ValueImpl);
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+enum ValueImpl {
+    Multisig(Multisig),
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(in crate::multisig) struct Multisig {
+    members: Vec<[u8; ADDRESS_LEN]>,
+    threshold: u32,
+}
+
+impl From<&crate::multisig::Multisig> for Multisig {
+    fn from(multisig: &crate::multisig::Multisig) -> Self {
+        Self {
+            members: multisig.members.clone(),
+            threshold: multisig.threshold,
+        }
+    }
+}
+
+impl From<Multisig> for crate::multisig::Multisig {
+    fn from(multisig: Multisig) -> Self {
+        Self {
+            members: multisig.members,
+            threshold: multisig.threshold,
+        }
+    }
+}
+
+impl From<Multisig> for crate::storage::StoredValue<'_> {
+    fn from(multisig: Multisig) -> Self {
+        crate::storage::StoredValue::Multisig(Value(ValueImpl::Multisig(multisig)))
+    }
+}
+
+impl TryFrom<crate::storage::StoredValue<'_>> for Multisig {
+    type Error = astria_eyre::eyre::Error;
+
+    fn try_from(value: crate::storage::StoredValue) -> Result<Self, Self::Error> {
+        let crate::storage::StoredValue::Multisig(Value(ValueImpl::Multisig(multisig))) = value
+        else {
+            bail!("multisig stored value type mismatch: expected multisig, found {value:?}");
+        };
+        Ok(multisig)
+    }
+}
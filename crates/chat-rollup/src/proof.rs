@@ -0,0 +1,62 @@
+use astria_eyre::{
+    anyhow_to_eyre,
+    eyre::{OptionExt as _, Result, WrapErr as _},
+};
+use cnidarium::{Snapshot, Storage};
+use prost::Message as _;
+use rollup_core::proof::StateProof;
+
+use crate::rollup::state_ext::StateReadExt as _;
+
+/// Returns the snapshot of the state as it was committed to by the block at `height`, or by the
+/// latest block if `height` is `None`.
+///
+/// The root hash of the returned snapshot is the state root stored alongside the block, so
+/// proofs read from it can be verified against that block.
+pub(crate) async fn snapshot_at_height(storage: &Storage, height: Option<u64>) -> Result<Snapshot> {
+    let latest = storage.latest_snapshot();
+    let height = match height {
+        Some(height) => height,
+        None => latest
+            .get_block_height()
+            .await
+            .wrap_err("failed to get latest block height")?,
+    };
+    let version = latest
+        .get_storage_version_by_height(height)
+        .await
+        .wrap_err("failed to get storage version from height")?;
+    storage
+        .snapshot(version)
+        .ok_or_eyre("failed to get storage at version")
+}
+
+/// Reads the raw value stored under `key` in `snapshot` together with a proof of its inclusion
+/// (or exclusion) in the snapshot's state root.
+pub(crate) async fn get_with_proof(snapshot: &Snapshot, key: String) -> Result<StateProof> {
+    let height = snapshot
+        .get_block_height()
+        .await
+        .wrap_err("failed to get block height from snapshot")?;
+    let state_root = snapshot
+        .root_hash()
+        .await
+        .map_err(anyhow_to_eyre)
+        .wrap_err("failed to get root hash of snapshot")?;
+    let (value, proof) = snapshot
+        .get_with_proof(key.as_bytes().to_vec())
+        .await
+        .map_err(anyhow_to_eyre)
+        .wrap_err("failed reading value with proof from state")?;
+    Ok(StateProof {
+        height,
+        state_root: state_root.0,
+        key,
+        value,
+        proofs: proof
+            .proofs
+            .iter()
+            .map(|proof| proof.encode_to_vec())
+            .collect(),
+    })
+}
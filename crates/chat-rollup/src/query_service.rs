@@ -0,0 +1,242 @@
+use std::sync::Arc;
+
+use astria_core::{primitive::v1::Address, Protobuf as _};
+use cnidarium::{Snapshot, Storage};
+use rollup_core::generated::protocol::query::v1::{
+    self as query, query_service_server::QueryService,
+};
+use tonic::{Request, Response, Status};
+
+use crate::{
+    accounts::StateReadExt as _, receipts::StateReadExt as _, rollup::state_ext::StateReadExt as _,
+    text::StateReadExt as _,
+};
+
+/// Serves typed reads of the rollup state over gRPC, next to the execution service.
+pub(crate) struct RollupQueryService {
+    pub storage: Storage,
+}
+
+impl RollupQueryService {
+    /// Returns the snapshot committed to by the block at `height`, or by the latest block if
+    /// `height` is 0.
+    async fn snapshot(&self, height: u64) -> Result<Snapshot, Status> {
+        if height == 0 {
+            return Ok(self.storage.latest_snapshot());
+        }
+        crate::proof::snapshot_at_height(&self.storage, Some(height))
+            .await
+            .map_err(|err| Status::not_found(format!("no state at the requested height: {err:#}")))
+    }
+}
+
+fn parse_address(
+    address: Option<astria_core::generated::astria::primitive::v1::Address>,
+) -> Result<Address, Status> {
+    let address = address.ok_or_else(|| Status::invalid_argument("address must be set"))?;
+    Address::try_from_raw(address)
+        .map_err(|err| Status::invalid_argument(format!("invalid address: {err}")))
+}
+
+async fn snapshot_height(snapshot: &Snapshot) -> Result<u64, Status> {
+    snapshot
+        .get_block_height()
+        .await
+        .map_err(|err| Status::internal(format!("failed to get block height: {err:#}")))
+}
+
+#[async_trait::async_trait]
+impl QueryService for RollupQueryService {
+    async fn get_balances(
+        self: Arc<Self>,
+        request: Request<query::GetBalancesRequest>,
+    ) -> Result<Response<query::GetBalancesResponse>, Status> {
+        let request = request.into_inner();
+        let address = parse_address(request.address)?;
+        let snapshot = self.snapshot(request.height).await?;
+        let mut balances =
+            crate::accounts::query::get_trace_prefixed_account_balances(&snapshot, &address)
+                .await
+                .map_err(|err| Status::internal(format!("failed to get balances: {err:#}")))?;
+        balances.sort_unstable_by(|a, b| a.denom.cmp(&b.denom));
+        Ok(Response::new(query::GetBalancesResponse {
+            height: snapshot_height(&snapshot).await?,
+            balances: balances
+                .into_iter()
+                .map(|balance| query::AssetBalance {
+                    denom: balance.denom.to_string(),
+                    balance: Some(balance.balance.into()),
+                })
+                .collect(),
+        }))
+    }
+
+    async fn get_nonce(
+        self: Arc<Self>,
+        request: Request<query::GetNonceRequest>,
+    ) -> Result<Response<query::GetNonceResponse>, Status> {
+        let request = request.into_inner();
+        let address = parse_address(request.address)?;
+        let snapshot = self.snapshot(request.height).await?;
+        let nonce = snapshot
+            .get_account_nonce(&address)
+            .await
+            .map_err(|err| Status::internal(format!("failed to get nonce: {err:#}")))?;
+        Ok(Response::new(query::GetNonceResponse {
+            height: snapshot_height(&snapshot).await?,
+            nonce,
+        }))
+    }
+
+    async fn get_message(
+        self: Arc<Self>,
+        request: Request<query::GetMessageRequest>,
+    ) -> Result<Response<query::GetMessageResponse>, Status> {
+        let id = request.into_inner().id;
+        let snapshot = self.storage.latest_snapshot();
+        let text = snapshot
+            .get_text(id)
+            .await
+            .map_err(|err| Status::not_found(format!("failed to get message `{id}`: {err:#}")))?;
+        Ok(Response::new(query::GetMessageResponse {
+            id,
+            message: text.into(),
+        }))
+    }
+
+    async fn get_block(
+        self: Arc<Self>,
+        request: Request<query::GetBlockRequest>,
+    ) -> Result<Response<query::GetBlockResponse>, Status> {
+        let snapshot = self.storage.latest_snapshot();
+        let height = match request.into_inner().height {
+            0 => snapshot_height(&snapshot)
+                .await?
+                .try_into()
+                .map_err(|_| Status::internal("latest block height does not fit into u32"))?,
+            height => height,
+        };
+        let (block, state_root) = crate::rollup::block::get_block(&snapshot, height)
+            .await
+            .map_err(|err| Status::not_found(format!("failed to get block `{height}`: {err:#}")))?;
+        Ok(Response::new(query::GetBlockResponse {
+            number: block.number,
+            hash: block.hash,
+            parent_block_hash: block.parent_block_hash,
+            timestamp: block.timestamp,
+            state_root: state_root.to_vec().into(),
+        }))
+    }
+
+    async fn get_receipt(
+        self: Arc<Self>,
+        request: Request<query::GetReceiptRequest>,
+    ) -> Result<Response<query::GetReceiptResponse>, Status> {
+        let transaction_hash = <[u8; 32]>::try_from(&*request.into_inner().transaction_hash)
+            .map_err(|_| Status::invalid_argument("transaction hash must be 32 bytes"))?;
+        let snapshot = self.storage.latest_snapshot();
+        let receipt = snapshot
+            .get_receipt(&transaction_hash)
+            .await
+            .map_err(|err| Status::internal(format!("failed to get receipt: {err:#}")))?
+            .ok_or_else(|| Status::not_found("no transaction with the given hash was executed"))?;
+        Ok(Response::new(query::GetReceiptResponse {
+            receipt: Some(query::Receipt {
+                transaction_hash: receipt.transaction_hash.to_vec().into(),
+                height: receipt.height,
+                index: receipt.index,
+                success: receipt.error.is_none(),
+                error: receipt.error.unwrap_or_default(),
+            }),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cnidarium::StateDelta;
+
+    use super::*;
+    use crate::{
+        accounts::{state_ext::astria_address, StateWriteExt as _},
+        execution_service::commit_state_delta,
+        receipts::{Receipt, StateWriteExt as _},
+        rollup::state_ext::StateWriteExt as _,
+    };
+
+    async fn service() -> (cnidarium::TempStorage, Arc<RollupQueryService>) {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_block_height(1).unwrap();
+        state
+            .put_account_nonce(&astria_address(&[42u8; 20]), 3)
+            .unwrap();
+        state
+            .put_receipt(&Receipt {
+                transaction_hash: [1u8; 32],
+                height: 1,
+                index: 0,
+                error: None,
+            })
+            .await
+            .unwrap();
+        commit_state_delta(&storage, state).await.unwrap();
+        let service = Arc::new(RollupQueryService {
+            storage: (*storage).clone(),
+        });
+        (storage, service)
+    }
+
+    #[tokio::test]
+    async fn get_nonce_reads_latest_state() {
+        let (_storage, service) = service().await;
+        let response = service
+            .get_nonce(Request::new(query::GetNonceRequest {
+                address: Some(astria_address(&[42u8; 20]).into_raw()),
+                height: 0,
+            }))
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(response.nonce, 3);
+        assert_eq!(response.height, 1);
+    }
+
+    #[tokio::test]
+    async fn get_nonce_without_address_is_invalid() {
+        let (_storage, service) = service().await;
+        let status = service
+            .get_nonce(Request::new(query::GetNonceRequest {
+                address: None,
+                height: 0,
+            }))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    }
+
+    #[tokio::test]
+    async fn get_receipt() {
+        let (_storage, service) = service().await;
+        let receipt = service
+            .clone()
+            .get_receipt(Request::new(query::GetReceiptRequest {
+                transaction_hash: vec![1u8; 32].into(),
+            }))
+            .await
+            .unwrap()
+            .into_inner()
+            .receipt
+            .unwrap();
+        assert!(receipt.success);
+        assert_eq!(receipt.height, 1);
+
+        let status = service
+            .get_receipt(Request::new(query::GetReceiptRequest {
+                transaction_hash: vec![2u8; 32].into(),
+            }))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::NotFound);
+    }
+}
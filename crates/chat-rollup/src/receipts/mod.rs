@@ -0,0 +1,24 @@
+pub(crate) mod state_ext;
+pub(crate) mod storage;
+
+use sha2::{Digest as _, Sha256};
+
+pub(crate) use state_ext::{StateReadExt, StateWriteExt};
+
+/// The outcome of executing a transaction included in a rollup block.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Receipt {
+    pub(crate) transaction_hash: [u8; 32],
+    /// The height of the block including the transaction.
+    pub(crate) height: u32,
+    /// The position of the transaction within the block.
+    pub(crate) index: u32,
+    /// The reason the transaction failed, or `None` if it succeeded.
+    pub(crate) error: Option<String>,
+}
+
+/// Returns the hash receipts are stored under: the sha256 hash of the protobuf encoded
+/// transaction as it was sequenced.
+pub(crate) fn transaction_hash(bytes: &[u8]) -> [u8; 32] {
+    Sha256::digest(bytes).into()
+}
@@ -0,0 +1,115 @@
+use astria_eyre::{
+    anyhow_to_eyre,
+    eyre::{Result, WrapErr as _},
+};
+use async_trait::async_trait;
+use cnidarium::{StateRead, StateWrite};
+use tracing::instrument;
+
+use super::{
+    storage::{self, keys},
+    Receipt,
+};
+use crate::storage::StoredValue;
+
+#[async_trait]
+pub(crate) trait StateReadExt: StateRead {
+    /// Returns the receipt of the transaction with hash `transaction_hash`, or `None` if no such
+    /// transaction was executed.
+    #[instrument(skip_all)]
+    async fn get_receipt(&self, transaction_hash: &[u8; 32]) -> Result<Option<Receipt>> {
+        let Some(bytes) = self
+            .get_raw(&keys::receipt(transaction_hash))
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading raw receipt from state")?
+        else {
+            return Ok(None);
+        };
+        StoredValue::deserialize(&bytes)
+            .and_then(|value| {
+                storage::values::Receipt::try_from(value).map(|receipt| Some(receipt.into()))
+            })
+            .wrap_err("invalid receipt bytes")
+    }
+}
+
+impl<T: StateRead + ?Sized> StateReadExt for T {}
+
+#[async_trait]
+pub(crate) trait StateWriteExt: StateWrite {
+    /// Writes `receipt`, unless a receipt for its transaction exists already.
+    ///
+    /// Receipts are keyed by transaction hash, so identical transaction bytes sequenced again
+    /// keep the receipt of their first execution instead of overwriting it with the outcome of
+    /// the later one, which fails on the already consumed nonce.
+    #[instrument(skip_all)]
+    async fn put_receipt(&mut self, receipt: &Receipt) -> Result<()> {
+        if self
+            .get_receipt(&receipt.transaction_hash)
+            .await
+            .wrap_err("failed to check for an existing receipt")?
+            .is_some()
+        {
+            return Ok(());
+        }
+        let bytes = StoredValue::from(storage::values::Receipt::from(receipt))
+            .serialize()
+            .wrap_err("failed to serialize receipt")?;
+        self.put_raw(keys::receipt(&receipt.transaction_hash), bytes);
+        Ok(())
+    }
+}
+
+impl<T: StateWrite> StateWriteExt for T {}
+
+#[cfg(test)]
+mod tests {
+    use cnidarium::StateDelta;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn put_and_get_receipt() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let receipt = Receipt {
+            transaction_hash: [1u8; 32],
+            height: 2,
+            index: 3,
+            error: Some("failed decreasing `from` account balance".to_string()),
+        };
+        assert_eq!(state.get_receipt(&[1u8; 32]).await.unwrap(), None);
+
+        state.put_receipt(&receipt).await.unwrap();
+        assert_eq!(state.get_receipt(&[1u8; 32]).await.unwrap(), Some(receipt));
+        assert_eq!(state.get_receipt(&[2u8; 32]).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn first_receipt_of_a_transaction_is_kept() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let first = Receipt {
+            transaction_hash: [1u8; 32],
+            height: 2,
+            index: 3,
+            error: None,
+        };
+        state.put_receipt(&first).await.unwrap();
+        state
+            .put_receipt(&Receipt {
+                transaction_hash: [1u8; 32],
+                height: 4,
+                index: 0,
+                error: Some("invalid nonce".to_string()),
+            })
+            .await
+            .unwrap();
+        assert_eq!(state.get_receipt(&[1u8; 32]).await.unwrap(), Some(first));
+    }
+}
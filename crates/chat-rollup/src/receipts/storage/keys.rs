@@ -0,0 +1,17 @@
+const RECEIPT_PREFIX: &str = "receipts/";
+
+/// Example: `receipts/0101....0101`.
+///                   |64 hex chars|
+pub(in crate::receipts) fn receipt(transaction_hash: &[u8; 32]) -> String {
+    format!("{RECEIPT_PREFIX}{}", hex::encode(transaction_hash))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keys_have_expected_format() {
+        assert_eq!(receipt(&[1u8; 32]), format!("receipts/{}", "01".repeat(32)));
+    }
+}
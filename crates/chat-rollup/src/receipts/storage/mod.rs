@@ -0,0 +1,4 @@
+pub(crate) mod keys;
+pub(crate) mod values;
+
+pub(crate) use values::Value;
@@ -0,0 +1,12 @@
+mod receipt;
+use borsh::{BorshDeserialize, BorshSerialize};
+
+pub(in crate::receipts) use self::receipt::Receipt;
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(crate) struct Value(ValueImpl);
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+enum ValueImpl {
+    Receipt(Receipt),
+}
@@ -0,0 +1,51 @@
+use astria_eyre::eyre::bail;
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use super::{Value, ValueImpl};
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(in crate::receipts) struct Receipt {
+    transaction_hash: [u8; 32],
+    height: u32,
+    index: u32,
+    error: Option<String>,
+}
+
+impl From<&crate::receipts::Receipt> for Receipt {
+    fn from(receipt: &crate::receipts::Receipt) -> Self {
+        Self {
+            transaction_hash: receipt.transaction_hash,
+            height: receipt.height,
+            index: receipt.index,
+            error: receipt.error.clone(),
+        }
+    }
+}
+
+impl From<Receipt> for crate::receipts::Receipt {
+    fn from(receipt: Receipt) -> Self {
+        Self {
+            transaction_hash: receipt.transaction_hash,
+            height: receipt.height,
+            index: receipt.index,
+            error: receipt.error,
+        }
+    }
+}
+
+impl From<Receipt> for crate::storage::StoredValue<'_> {
+    fn from(receipt: Receipt) -> Self {
+        crate::storage::StoredValue::Receipt(Value(ValueImpl::Receipt(receipt)))
+    }
+}
+
+impl TryFrom<crate::storage::StoredValue<'_>> for Receipt {
+    type Error = astria_eyre::eyre::Error;
+
+    fn try_from(value: crate::storage::StoredValue<'_>) -> Result<Self, Self::Error> {
+        let crate::storage::StoredValue::Receipt(Value(ValueImpl::Receipt(receipt))) = value else {
+            bail!("receipts stored value type mismatch: expected receipt, found {value:?}");
+        };
+        Ok(receipt)
+    }
+}
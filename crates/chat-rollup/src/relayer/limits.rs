@@ -0,0 +1,204 @@
+//! The rate limits and the spend cap bounding what the relayer posts on behalf of others.
+
+use std::{
+    collections::HashMap,
+    net::IpAddr,
+    time::{Duration, Instant},
+};
+
+const RATE_LIMIT_WINDOW: Duration = Duration::from_secs(60);
+
+const SPEND_CAP_WINDOW: Duration = Duration::from_secs(24 * 60 * 60);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum LimitExceeded {
+    /// The client posted too many messages in the current minute.
+    Client,
+    /// All clients together posted too many messages in the current minute.
+    Global,
+    /// The relayer spent its daily fee budget.
+    SpendCap,
+}
+
+/// Counts what was used of a limit since the start of its current window.
+struct Window {
+    started_at: Instant,
+    used: u64,
+}
+
+impl Window {
+    fn new(now: Instant) -> Self {
+        Self {
+            started_at: now,
+            used: 0,
+        }
+    }
+
+    fn is_expired(&self, length: Duration, now: Instant) -> bool {
+        now.duration_since(self.started_at) >= length
+    }
+
+    /// Returns whether `amount` fits into `limit`, starting a new window if the current one
+    /// expired.
+    fn fits(&mut self, amount: u64, limit: u64, length: Duration, now: Instant) -> bool {
+        if self.is_expired(length, now) {
+            *self = Self::new(now);
+        }
+        self.used.saturating_add(amount) <= limit
+    }
+}
+
+pub(crate) struct Limits {
+    client_messages_per_minute: u64,
+    messages_per_minute: u64,
+    daily_spend_cap: u64,
+    /// Clients are identified by their IP address rather than by the sender they name, which
+    /// they are free to choose.
+    clients: HashMap<IpAddr, Window>,
+    messages: Window,
+    spent: Window,
+}
+
+impl Limits {
+    pub(crate) fn new(
+        client_messages_per_minute: u64,
+        messages_per_minute: u64,
+        daily_spend_cap: u64,
+    ) -> Self {
+        let now = Instant::now();
+        Self {
+            client_messages_per_minute,
+            messages_per_minute,
+            daily_spend_cap,
+            clients: HashMap::new(),
+            messages: Window::new(now),
+            spent: Window::new(now),
+        }
+    }
+
+    /// Reserves `messages` messages of `client` costing `fees`, if that stays within all
+    /// limits. Nothing is reserved if any limit would be exceeded.
+    pub(crate) fn try_reserve(
+        &mut self,
+        client: IpAddr,
+        messages: u64,
+        fees: u64,
+        now: Instant,
+    ) -> Result<(), LimitExceeded> {
+        self.clients
+            .retain(|_, window| !window.is_expired(RATE_LIMIT_WINDOW, now));
+        let client_window = self
+            .clients
+            .entry(client)
+            .or_insert_with(|| Window::new(now));
+        if !client_window.fits(
+            messages,
+            self.client_messages_per_minute,
+            RATE_LIMIT_WINDOW,
+            now,
+        ) {
+            return Err(LimitExceeded::Client);
+        }
+        if !self
+            .messages
+            .fits(messages, self.messages_per_minute, RATE_LIMIT_WINDOW, now)
+        {
+            return Err(LimitExceeded::Global);
+        }
+        if !self
+            .spent
+            .fits(fees, self.daily_spend_cap, SPEND_CAP_WINDOW, now)
+        {
+            return Err(LimitExceeded::SpendCap);
+        }
+        client_window.used += messages;
+        self.messages.used += messages;
+        self.spent.used += fees;
+        Ok(())
+    }
+
+    /// Gives back `fees` reserved at `reserved_at` for messages which were not posted. Nothing
+    /// is given back if the spend cap window started anew since, as the reservation then no
+    /// longer counts against it.
+    pub(crate) fn release_fees(&mut self, fees: u64, reserved_at: Instant) {
+        if self.spent.started_at <= reserved_at {
+            self.spent.used = self.spent.used.saturating_sub(fees);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALICE: IpAddr = IpAddr::V4(std::net::Ipv4Addr::new(10, 0, 0, 1));
+    const BOB: IpAddr = IpAddr::V4(std::net::Ipv4Addr::new(10, 0, 0, 2));
+    const CAROL: IpAddr = IpAddr::V4(std::net::Ipv4Addr::new(10, 0, 0, 3));
+
+    #[test]
+    fn client_limit_applies_per_client() {
+        let now = Instant::now();
+        let mut limits = Limits::new(1, 10, 10);
+
+        limits.try_reserve(ALICE, 1, 1, now).unwrap();
+        assert_eq!(
+            limits.try_reserve(ALICE, 1, 1, now),
+            Err(LimitExceeded::Client)
+        );
+        limits.try_reserve(BOB, 1, 1, now).unwrap();
+        // the client limit resets with the next window
+        limits
+            .try_reserve(ALICE, 1, 1, now + RATE_LIMIT_WINDOW)
+            .unwrap();
+    }
+
+    #[test]
+    fn global_limit_applies_to_all_clients() {
+        let now = Instant::now();
+        let mut limits = Limits::new(10, 2, 10);
+
+        limits.try_reserve(ALICE, 1, 1, now).unwrap();
+        limits.try_reserve(BOB, 1, 1, now).unwrap();
+        assert_eq!(
+            limits.try_reserve(CAROL, 1, 1, now),
+            Err(LimitExceeded::Global)
+        );
+    }
+
+    #[test]
+    fn spend_cap_lasts_a_day() {
+        let now = Instant::now();
+        let mut limits = Limits::new(10, 10, 2);
+
+        limits.try_reserve(ALICE, 1, 2, now).unwrap();
+        let next_minute = now + RATE_LIMIT_WINDOW;
+        assert_eq!(
+            limits.try_reserve(ALICE, 1, 1, next_minute),
+            Err(LimitExceeded::SpendCap)
+        );
+        // nothing is reserved for a rejected message
+        assert_eq!(limits.clients[&ALICE].used, 0);
+        limits
+            .try_reserve(ALICE, 1, 1, now + SPEND_CAP_WINDOW)
+            .unwrap();
+    }
+
+    #[test]
+    fn released_fees_can_be_spent_again() {
+        let now = Instant::now();
+        let mut limits = Limits::new(10, 10, 2);
+
+        limits.try_reserve(ALICE, 1, 2, now).unwrap();
+        limits.release_fees(2, now);
+        limits.try_reserve(ALICE, 1, 2, now).unwrap();
+
+        // fees reserved in an earlier window are not taken out of the current one
+        let next_day = now + SPEND_CAP_WINDOW;
+        limits.try_reserve(ALICE, 1, 2, next_day).unwrap();
+        limits.release_fees(2, now);
+        assert_eq!(
+            limits.try_reserve(BOB, 1, 1, next_day),
+            Err(LimitExceeded::SpendCap),
+        );
+    }
+}
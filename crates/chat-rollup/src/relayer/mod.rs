@@ -0,0 +1,728 @@
+//! Posts messages on behalf of clients which do not sign their own transactions.
+//!
+//! Messages posted to `/message` are wrapped into transactions signed by the relayer key, whose
+//! account pays their fees. To keep the endpoint safe to leave on, the relayer enforces rate
+//! limits per client IP address and across all clients, and caps the fees it spends per day,
+//! counting both the text fee and the compute fee of every message.
+//!
+//! Messages are not submitted one by one: they are buffered for up to
+//! [`Config::relayer_batch_interval_ms`] or until [`Config::relayer_max_batch_size`] messages
+//! are pending, and then posted together in a single transaction with one action per message.
+//! Each message is checked against the text and compute params before it is buffered, and a
+//! batch never holds more actions or compute units than a transaction may use, so that one
+//! message cannot fail the others posted with it. Texts posted through the relayer count
+//! against the texts its account may post per window, so messages beyond that are held back
+//! until the window has room for them again.
+
+mod limits;
+
+use std::{
+    collections::BTreeMap,
+    net::IpAddr,
+    path::Path,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use astria_core::{crypto::SigningKey, primitive::v1::asset, Protobuf as _};
+use astria_eyre::eyre::{Result, WrapErr as _};
+use cnidarium::Storage;
+use prost::Message as _;
+use rollup_core::{
+    genesis::v1::TextParams,
+    transaction::v1::{action::SendText, Action, TransactionBody},
+};
+use thiserror::Error;
+use tokio::sync::{mpsc, oneshot};
+use tracing::{info, warn};
+
+use crate::{
+    accounts::StateReadExt as _,
+    config::Config,
+    mempool::Mempool,
+    rollup::state_ext::StateReadExt as _,
+    submission::{OnDropped, SubmissionBackend, SubmitError},
+    text::{
+        action::{check_text_length, texts_posted_in_current_window, SEND_TEXT_FEE},
+        StateReadExt as _,
+    },
+    transaction::{metering, tracker::TransactionTracker},
+};
+pub(crate) use limits::LimitExceeded;
+use limits::Limits;
+
+/// How many messages can wait for the batching task before posting blocks.
+const PENDING_MESSAGES_CAPACITY: usize = 1024;
+/// How long messages are held back before checking again whether the relayer account may post
+/// more texts in the current window.
+const HELD_BACK_RETRY_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Clone, Debug, Error)]
+pub(crate) enum RelayError {
+    #[error("relayer limit exceeded: {0:?}")]
+    LimitExceeded(LimitExceeded),
+
+    #[error("message cannot be relayed: {0}")]
+    InvalidMessage(String),
+
+    #[error("relayed transaction was rejected: {0}")]
+    Rejected(String),
+
+    #[error("failed to read relayer state: {0}")]
+    State(String),
+
+    #[error(transparent)]
+    Submit(#[from] SubmitError),
+}
+
+/// A handle to post messages through the relayer.
+#[derive(Clone)]
+pub(crate) struct Relayer {
+    limits: Arc<Mutex<Limits>>,
+    pending_messages: mpsc::Sender<PendingMessage>,
+    storage: Storage,
+    fee_asset: asset::Denom,
+}
+
+struct PendingMessage {
+    send_text: SendText,
+    /// The compute units of `send_text`.
+    units: u64,
+    /// Receives the hash of the transaction the message was posted in.
+    posted: oneshot::Sender<Result<[u8; 32], RelayError>>,
+}
+
+impl Relayer {
+    /// Returns the relayer configured by the `relayer_*` fields of `config`, and spawns the task
+    /// posting its batches. Returns `None` if no relayer key is configured.
+    ///
+    /// # Errors
+    /// Returns an error if the relayer key cannot be read or is invalid.
+    pub(crate) fn spawn(
+        config: &Config,
+        chain_id: String,
+        storage: Storage,
+        submitter: Arc<dyn SubmissionBackend>,
+        mempool: Mempool,
+        tracker: TransactionTracker,
+    ) -> Result<Option<Self>> {
+        let Some(signing_key) = signing_key(config)? else {
+            return Ok(None);
+        };
+        let address = crate::rollup::address_from_signing_key(&signing_key, "astria")?;
+        info!(%address, "relaying messages posted without a signature");
+        let limits = Limits::new(
+            config.relayer_sender_messages_per_minute,
+            config.relayer_messages_per_minute,
+            config.relayer_daily_spend_cap,
+        );
+        let batcher = Batcher {
+            signing_key,
+            chain_id,
+            storage,
+            submitter,
+            mempool,
+            tracker,
+            batch_interval: Duration::from_millis(config.relayer_batch_interval_ms),
+            max_batch_size: config.relayer_max_batch_size.max(1),
+            in_flight: BTreeMap::new(),
+        };
+        Ok(Some(Self::spawn_batcher(batcher, limits)))
+    }
+
+    fn spawn_batcher(batcher: Batcher, limits: Limits) -> Self {
+        let (pending_messages, pending) = mpsc::channel(PENDING_MESSAGES_CAPACITY);
+        let storage = batcher.storage.clone();
+        tokio::spawn(batcher.run(pending));
+        Self {
+            limits: Arc::new(Mutex::new(limits)),
+            pending_messages,
+            storage,
+            fee_asset: crate::accounts::state_ext::nria().into(),
+        }
+    }
+
+    /// Posts `text` attributed to `sender` in the next batch of the relayer on behalf of the
+    /// client at `client`, returning the hash of the transaction it was posted in.
+    ///
+    /// # Errors
+    /// Returns an error if `text` breaks the text or compute params, if posting it would exceed
+    /// one of the relayer's limits, or if the transaction could not be submitted.
+    pub(crate) async fn relay_text(
+        &self,
+        client: IpAddr,
+        sender: &str,
+        text: String,
+    ) -> Result<[u8; 32], RelayError> {
+        let send_text = SendText {
+            text,
+            from: sender.to_string(),
+            fee_asset: self.fee_asset.clone(),
+        };
+        let (units, fees) = self.check_message(&send_text).await?;
+        let reserved_at = Instant::now();
+        self.limits()
+            .try_reserve(client, 1, fees, reserved_at)
+            .map_err(RelayError::LimitExceeded)?;
+
+        let result = self.post_in_next_batch(send_text, units).await;
+        // the fees of a message which was not posted are not spent
+        if result.is_err() {
+            self.limits().release_fees(fees, reserved_at);
+        }
+        result
+    }
+
+    /// Hands `send_text` to the batcher and waits until its batch was posted.
+    async fn post_in_next_batch(
+        &self,
+        send_text: SendText,
+        units: u64,
+    ) -> Result<[u8; 32], RelayError> {
+        let (posted, posted_rx) = oneshot::channel();
+        let stopped = || RelayError::State("relayer stopped".to_string());
+        self.pending_messages
+            .send(PendingMessage {
+                send_text,
+                units,
+                posted,
+            })
+            .await
+            .map_err(|_| stopped())?;
+        posted_rx.await.map_err(|_| stopped())?
+    }
+
+    fn limits(&self) -> std::sync::MutexGuard<'_, Limits> {
+        self.limits
+            .lock()
+            .expect("relayer limits lock must not be poisoned")
+    }
+
+    /// Checks `send_text` against the text and compute params in the latest state, returning
+    /// the compute units it is estimated to use and the fees paid for it: the text fee plus its
+    /// estimated compute fee.
+    async fn check_message(&self, send_text: &SendText) -> Result<(u64, u64), RelayError> {
+        let snapshot = self.storage.latest_snapshot();
+        let text_params = snapshot
+            .get_text_params()
+            .await
+            .map_err(|err| RelayError::State(format!("{err:#}")))?;
+        check_text_length(&send_text.text, &text_params)
+            .map_err(|err| RelayError::InvalidMessage(format!("{err:#}")))?;
+
+        let compute_params = snapshot
+            .get_compute_params()
+            .await
+            .map_err(|err| RelayError::State(format!("{err:#}")))?;
+        let units = metering::estimated_action_units(&Action::Text(send_text.clone()));
+        let max_units = compute_params.max_units_per_transaction;
+        if max_units > 0 && units > max_units {
+            return Err(RelayError::InvalidMessage(format!(
+                "message uses `{units}` compute units, at most `{max_units}` are allowed"
+            )));
+        }
+        let fees = u128::from(units)
+            .saturating_mul(u128::from(compute_params.unit_price))
+            .saturating_add(SEND_TEXT_FEE);
+        Ok((units, fees.try_into().unwrap_or(u64::MAX)))
+    }
+}
+
+/// Collects pending messages into batches and posts each batch in one transaction.
+struct Batcher {
+    signing_key: SigningKey,
+    chain_id: String,
+    storage: Storage,
+    submitter: Arc<dyn SubmissionBackend>,
+    mempool: Mempool,
+    tracker: TransactionTracker,
+    batch_interval: Duration,
+    max_batch_size: usize,
+    /// The number of texts in each transaction posted by the batcher that was not executed yet,
+    /// by nonce.
+    in_flight: BTreeMap<u32, usize>,
+}
+
+impl Batcher {
+    async fn run(mut self, mut pending: mpsc::Receiver<PendingMessage>) {
+        // a message which did not fit into the previous batch starts the next one
+        let mut held_over = None;
+        loop {
+            let first = match held_over.take() {
+                Some(message) => message,
+                None => match pending.recv().await {
+                    Some(message) => message,
+                    None => break,
+                },
+            };
+            let (max_messages, max_units) = self.batch_limits().await;
+            if max_messages == 0 {
+                // the relayer account posted all texts it may in the current window
+                held_over = Some(first);
+                tokio::time::sleep(HELD_BACK_RETRY_INTERVAL).await;
+                continue;
+            }
+            let mut units = first.units;
+            let mut batch = vec![first];
+            let deadline = tokio::time::sleep(self.batch_interval);
+            tokio::pin!(deadline);
+            while batch.len() < max_messages {
+                let message = tokio::select! {
+                    message = pending.recv() => match message {
+                        Some(message) => message,
+                        None => break,
+                    },
+                    () = &mut deadline => break,
+                };
+                if max_units > 0 && units.saturating_add(message.units) > max_units {
+                    held_over = Some(message);
+                    break;
+                }
+                units = units.saturating_add(message.units);
+                batch.push(message);
+            }
+
+            let result = self.post(&batch).await;
+            if let Err(err) = &result {
+                warn!(error = %err, messages = batch.len(), "failed to post relayed messages");
+            }
+            for message in batch {
+                // the poster might have gone away, which is not an error
+                let _ = message.posted.send(result.clone());
+            }
+        }
+    }
+
+    /// Returns the most messages and compute units of the next batch: at most
+    /// `max_batch_size` messages, no more actions and compute units than a transaction may use,
+    /// and no more texts than the relayer account may still post in the current window. Zero
+    /// units means no limit.
+    async fn batch_limits(&mut self) -> (usize, u64) {
+        let snapshot = self.storage.latest_snapshot();
+        let (text_params, compute_params) =
+            match tokio::try_join!(snapshot.get_text_params(), snapshot.get_compute_params()) {
+                Ok(params) => params,
+                Err(err) => {
+                    warn!(
+                        error = %err,
+                        "failed to read params, batching by the configured batch size only",
+                    );
+                    return (self.max_batch_size, 0);
+                }
+            };
+        let max_actions =
+            usize::try_from(text_params.max_actions_per_transaction).unwrap_or(usize::MAX);
+        let max_messages = if max_actions == 0 {
+            self.max_batch_size
+        } else {
+            self.max_batch_size.min(max_actions)
+        };
+        let max_messages = if text_params.max_texts_per_window == 0 {
+            max_messages
+        } else {
+            match self.texts_left_in_window(&text_params).await {
+                Ok(texts_left) => max_messages.min(texts_left),
+                Err(err) => {
+                    warn!(
+                        error = %err,
+                        "failed to read the texts posted by the relayer, holding back messages",
+                    );
+                    0
+                }
+            }
+        };
+        (max_messages, compute_params.max_units_per_transaction)
+    }
+
+    /// Returns how many more texts the relayer account may post in the current window: the
+    /// limit, less the texts executed in the window and the texts of its transactions that were
+    /// not executed yet.
+    async fn texts_left_in_window(&mut self, text_params: &TextParams) -> Result<usize> {
+        let snapshot = self.storage.latest_snapshot();
+        let address = *self.signing_key.verification_key().address_bytes();
+        let nonce = snapshot
+            .get_account_nonce(&address)
+            .await
+            .wrap_err("failed to get relayer nonce")?;
+        // transactions below the nonce in state were executed, and their texts are counted in
+        // state if they were posted in the current window
+        self.in_flight = self.in_flight.split_off(&nonce);
+        let posted = texts_posted_in_current_window(&snapshot, &address, text_params)
+            .await
+            .wrap_err("failed to get texts posted by the relayer")?;
+        let max_texts = usize::try_from(text_params.max_texts_per_window).unwrap_or(usize::MAX);
+        let used = self.in_flight.values().fold(
+            usize::try_from(posted.count).unwrap_or(usize::MAX),
+            |used, texts| used.saturating_add(*texts),
+        );
+        Ok(max_texts.saturating_sub(used))
+    }
+
+    /// Posts `batch` in a single transaction, returning its hash.
+    ///
+    /// Batches are posted one after the other, so relayed transactions reach the sequencer in
+    /// nonce order.
+    async fn post(&mut self, batch: &[PendingMessage]) -> Result<[u8; 32], RelayError> {
+        let snapshot = self.storage.latest_snapshot();
+        let nonce = self
+            .mempool
+            .next_nonce(
+                self.signing_key.verification_key().address_bytes(),
+                &snapshot,
+            )
+            .await
+            .map_err(|err| RelayError::State(format!("{err:#}")))?;
+        let actions = batch
+            .iter()
+            .map(|message| Action::Text(message.send_text.clone()))
+            .collect();
+        let transaction = TransactionBody::builder()
+            .nonce(nonce)
+            .chain_id(self.chain_id.clone())
+            .actions(actions)
+            .try_build()
+            .map_err(|err| RelayError::Rejected(err.to_string()))?
+            .sign(&self.signing_key);
+        self.mempool
+            .check_and_insert(&transaction, &snapshot)
+            .await
+            .map_err(|err| RelayError::Rejected(format!("{err:#}")))?;
+
+        let data: bytes::Bytes = transaction.to_raw().encode_to_vec().into();
+        let transaction_hash = crate::receipts::transaction_hash(&data);
+        let on_dropped: OnDropped = {
+            let mempool = self.mempool.clone();
+            let tracker = self.tracker.clone();
+            let transaction = transaction.clone();
+            Box::new(move |reason| {
+                warn!(
+                    transaction_hash = %hex::encode(transaction_hash),
+                    reason,
+                    "queued transaction was dropped",
+                );
+                mempool.remove(&transaction);
+                tracker.dropped(transaction_hash);
+            })
+        };
+        // tracked before submitting, as a queued transaction may be dropped before `submit` returns
+        self.tracker.submitted(transaction_hash);
+        if let Err(err) = self.submitter.submit(data, on_dropped).await {
+            self.mempool.remove(&transaction);
+            self.tracker.dropped(transaction_hash);
+            return Err(err.into());
+        }
+        // transactions at or above this nonce were dropped, or their nonce would not be reused
+        self.in_flight.split_off(&nonce);
+        self.in_flight.insert(nonce, batch.len());
+        Ok(transaction_hash)
+    }
+}
+
+/// Reads the relayer key from `relayer_private_key`, or from the file at
+/// `relayer_private_key_file` if the former is not set.
+fn signing_key(config: &Config) -> Result<Option<SigningKey>> {
+    let private_key = if !config.relayer_private_key.is_empty() {
+        config.relayer_private_key.clone()
+    } else if config.relayer_private_key_file != Path::new("") {
+        std::fs::read_to_string(&config.relayer_private_key_file).wrap_err_with(|| {
+            format!(
+                "failed to read relayer key from `{}`",
+                config.relayer_private_key_file.display()
+            )
+        })?
+    } else {
+        return Ok(None);
+    };
+    crate::rollup::signing_key_from_private_key(private_key.trim())
+        .wrap_err("invalid relayer key")
+        .map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use cnidarium::StateDelta;
+    use rollup_core::{genesis::v1::ComputeParams, transaction::v1::Transaction};
+
+    use super::*;
+    use crate::{
+        accounts::StateWriteExt as _, execution_service::commit_state_delta,
+        rollup::state_ext::StateWriteExt as _, submission::MockBackend, text::StateWriteExt as _,
+    };
+
+    const CHAIN_ID: &str = "astria-chat";
+
+    const CLIENT: IpAddr = IpAddr::V4(std::net::Ipv4Addr::LOCALHOST);
+
+    async fn relayer(
+        client_messages_per_minute: u64,
+        batch_interval: Duration,
+        max_batch_size: usize,
+    ) -> (cnidarium::TempStorage, Arc<MockBackend>, Relayer) {
+        let signing_key = SigningKey::from([1; 32]);
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state
+            .put_account_balance(
+                signing_key.verification_key().address_bytes(),
+                &crate::accounts::state_ext::nria(),
+                1_000,
+            )
+            .unwrap();
+        commit_state_delta(&storage, state).await.unwrap();
+
+        let backend = Arc::new(MockBackend::default());
+        let batcher = Batcher {
+            signing_key,
+            chain_id: CHAIN_ID.to_string(),
+            storage: (*storage).clone(),
+            submitter: backend.clone(),
+            mempool: Mempool::new(CHAIN_ID.to_string()),
+            tracker: TransactionTracker::new(),
+            batch_interval,
+            max_batch_size,
+            in_flight: BTreeMap::new(),
+        };
+        let relayer =
+            Relayer::spawn_batcher(batcher, Limits::new(client_messages_per_minute, 100, 200));
+        (storage, backend, relayer)
+    }
+
+    async fn put_text_params(storage: &cnidarium::TempStorage, params: TextParams) {
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_text_params(&params).unwrap();
+        commit_state_delta(storage, state).await.unwrap();
+    }
+
+    async fn put_unit_price(storage: &cnidarium::TempStorage, unit_price: u64) {
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state
+            .put_compute_params(&ComputeParams {
+                max_units_per_transaction: 1_000_000,
+                unit_price,
+            })
+            .unwrap();
+        commit_state_delta(storage, state).await.unwrap();
+    }
+
+    fn submitted_transactions(backend: &MockBackend) -> Vec<Transaction> {
+        backend
+            .submitted
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|data| {
+                let raw = rollup_core::generated::protocol::transaction::v1::Transaction::decode(
+                    data.clone(),
+                )
+                .unwrap();
+                Transaction::try_from_raw(raw).unwrap()
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn messages_posted_together_are_batched() {
+        let (_storage, backend, relayer) = relayer(10, Duration::from_millis(50), 10).await;
+
+        let (alice, bob, carol) = tokio::join!(
+            relayer.relay_text(CLIENT, "alice", "gm".to_string()),
+            relayer.relay_text(CLIENT, "bob", "gm".to_string()),
+            relayer.relay_text(CLIENT, "carol", "gm".to_string()),
+        );
+
+        let transactions = submitted_transactions(&backend);
+        assert_eq!(transactions.len(), 1);
+        assert_eq!(transactions[0].actions().len(), 3);
+        let hash = alice.unwrap();
+        assert_eq!(bob.unwrap(), hash);
+        assert_eq!(carol.unwrap(), hash);
+    }
+
+    #[tokio::test]
+    async fn full_batch_is_posted_without_waiting() {
+        let (_storage, backend, relayer) = relayer(10, Duration::from_secs(3600), 2).await;
+
+        let (alice, bob) = tokio::time::timeout(Duration::from_secs(5), async {
+            tokio::join!(
+                relayer.relay_text(CLIENT, "alice", "gm".to_string()),
+                relayer.relay_text(CLIENT, "bob", "gm".to_string()),
+            )
+        })
+        .await
+        .expect("a full batch must be posted before the batch interval passed");
+
+        alice.unwrap();
+        bob.unwrap();
+        assert_eq!(submitted_transactions(&backend).len(), 1);
+    }
+
+    #[tokio::test]
+    async fn batches_use_consecutive_nonces() {
+        let (_storage, backend, relayer) = relayer(10, Duration::ZERO, 10).await;
+
+        relayer
+            .relay_text(CLIENT, "alice", "gm".to_string())
+            .await
+            .unwrap();
+        relayer
+            .relay_text(CLIENT, "bob", "gn".to_string())
+            .await
+            .unwrap();
+
+        let nonces: Vec<_> = submitted_transactions(&backend)
+            .iter()
+            .map(Transaction::nonce)
+            .collect();
+        assert_eq!(nonces, vec![0, 1]);
+    }
+
+    #[tokio::test]
+    async fn rate_limited_messages_are_not_posted() {
+        let (_storage, backend, relayer) = relayer(1, Duration::ZERO, 10).await;
+
+        // naming another sender does not get around the limit of the client
+        relayer
+            .relay_text(CLIENT, "alice", "gm".to_string())
+            .await
+            .unwrap();
+        let err = relayer
+            .relay_text(CLIENT, "bob", "gm again".to_string())
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            RelayError::LimitExceeded(LimitExceeded::Client)
+        ));
+        assert_eq!(submitted_transactions(&backend).len(), 1);
+    }
+
+    #[tokio::test]
+    async fn invalid_messages_are_rejected_before_batching() {
+        let (storage, backend, relayer) = relayer(10, Duration::from_millis(50), 10).await;
+        put_text_params(
+            &storage,
+            TextParams {
+                max_texts_per_window: 0,
+                window_blocks: 0,
+                max_text_length: 2,
+                max_actions_per_transaction: 0,
+            },
+        )
+        .await;
+
+        let (alice, bob) = tokio::join!(
+            relayer.relay_text(CLIENT, "alice", "gm".to_string()),
+            relayer.relay_text(CLIENT, "bob", "good morning".to_string()),
+        );
+
+        // the message breaking the text length limit does not fail the one batched with it
+        alice.unwrap();
+        assert!(matches!(bob, Err(RelayError::InvalidMessage(_))));
+        let transactions = submitted_transactions(&backend);
+        assert_eq!(transactions.len(), 1);
+        assert_eq!(transactions[0].actions().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn batches_are_capped_at_actions_per_transaction() {
+        let (storage, backend, relayer) = relayer(10, Duration::from_millis(50), 10).await;
+        put_text_params(
+            &storage,
+            TextParams {
+                max_texts_per_window: 0,
+                window_blocks: 0,
+                max_text_length: 0,
+                max_actions_per_transaction: 2,
+            },
+        )
+        .await;
+
+        let (alice, bob, carol) = tokio::join!(
+            relayer.relay_text(CLIENT, "alice", "gm".to_string()),
+            relayer.relay_text(CLIENT, "bob", "gm".to_string()),
+            relayer.relay_text(CLIENT, "carol", "gm".to_string()),
+        );
+
+        alice.unwrap();
+        bob.unwrap();
+        carol.unwrap();
+        let actions: Vec<_> = submitted_transactions(&backend)
+            .iter()
+            .map(|transaction| transaction.actions().len())
+            .collect();
+        assert_eq!(actions, vec![2, 1]);
+    }
+
+    #[tokio::test]
+    async fn spend_cap_includes_compute_fees() {
+        let (storage, backend, relayer) = relayer(10, Duration::ZERO, 10).await;
+        put_unit_price(&storage, 2).await;
+
+        // the text fee alone fits the spend cap of 200, but not together with the compute fee
+        let err = relayer
+            .relay_text(CLIENT, "alice", "gm".to_string())
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            RelayError::LimitExceeded(LimitExceeded::SpendCap)
+        ));
+        assert!(submitted_transactions(&backend).is_empty());
+    }
+
+    #[tokio::test]
+    async fn fees_of_unposted_messages_are_released() {
+        let (storage, backend, relayer) = relayer(10, Duration::ZERO, 10).await;
+        // one message fits the spend cap of 200
+        put_unit_price(&storage, 1).await;
+
+        backend
+            .unavailable
+            .store(true, std::sync::atomic::Ordering::Relaxed);
+        let err = relayer
+            .relay_text(CLIENT, "alice", "gm".to_string())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, RelayError::Submit(SubmitError::Unavailable)));
+
+        backend
+            .unavailable
+            .store(false, std::sync::atomic::Ordering::Relaxed);
+        relayer
+            .relay_text(CLIENT, "alice", "gm".to_string())
+            .await
+            .unwrap();
+        assert_eq!(submitted_transactions(&backend).len(), 1);
+    }
+
+    #[tokio::test]
+    async fn messages_beyond_the_texts_per_window_are_held_back() {
+        let (storage, backend, relayer) = relayer(10, Duration::ZERO, 10).await;
+        put_text_params(
+            &storage,
+            TextParams {
+                max_texts_per_window: 1,
+                window_blocks: 10,
+                max_text_length: 0,
+                max_actions_per_transaction: 0,
+            },
+        )
+        .await;
+
+        relayer
+            .relay_text(CLIENT, "alice", "gm".to_string())
+            .await
+            .unwrap();
+        // the first text is not executed yet, but still counts against the window
+        tokio::time::timeout(
+            Duration::from_millis(100),
+            relayer.relay_text(CLIENT, "bob", "gm".to_string()),
+        )
+        .await
+        .expect_err("a message beyond the texts per window must be held back");
+
+        assert_eq!(submitted_transactions(&backend).len(), 1);
+    }
+}
@@ -0,0 +1,94 @@
+//! The blocks executed by the rollup.
+//!
+//! The hash of a block commits to the root of the state resulting from executing it, which is
+//! only known once that state is prepared for commit. The header of a block is therefore
+//! committed in the same write as its state, and its hash is derived from the header and the
+//! state root when the block is read.
+//!
+//! Only executing a block writes verifiable state, so the root of every snapshot is the state
+//! root of the latest block in it. The state root of a block is also written alongside the next
+//! block, so it remains available once newer blocks are executed.
+
+use astria_core::generated::astria::execution::v1::Block;
+use astria_eyre::{
+    anyhow_to_eyre,
+    eyre::{Result, WrapErr as _},
+};
+use cnidarium::Snapshot;
+pub(crate) use rollup_core::block::BlockHeader;
+
+use super::state_ext::StateReadExt as _;
+
+/// Reads the block at `height` from `snapshot`, returning it together with its state root.
+pub(crate) async fn get_block(snapshot: &Snapshot, height: u32) -> Result<(Block, [u8; 32])> {
+    let header = snapshot
+        .get_block_header(height)
+        .await
+        .wrap_err("failed to get block header")?;
+    let latest_height = snapshot
+        .get_block_height()
+        .await
+        .wrap_err("failed to get latest block height")?;
+    let state_root = if u64::from(height) == latest_height {
+        snapshot
+            .root_hash()
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed to get root hash of snapshot")?
+            .0
+    } else {
+        snapshot
+            .get_block_state_root(height)
+            .await
+            .wrap_err("failed to get block state root")?
+    };
+    Ok((header.into_block(&state_root), state_root))
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+    use cnidarium::StateDelta;
+
+    use super::*;
+    use crate::{execution_service::commit_state_delta, rollup::state_ext::StateWriteExt as _};
+
+    fn header(number: u32) -> BlockHeader {
+        BlockHeader {
+            number,
+            parent_block_hash: Bytes::from_static(&[1; 32]),
+            transactions_root: [2; 32],
+            deposits_root: [3; 32],
+            timestamp: pbjson_types::Timestamp {
+                seconds: number.into(),
+                nanos: 0,
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn blocks_are_read_with_their_state_roots() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_block_header(&header(1)).unwrap();
+        state.put_block_height(1).unwrap();
+        let first_root = commit_state_delta(&storage, state).await.unwrap().0;
+
+        let (block, state_root) = get_block(&storage.latest_snapshot(), 1).await.unwrap();
+        assert_eq!(state_root, first_root);
+        assert_eq!(block.hash.as_ref(), header(1).hash(&first_root));
+
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_block_state_root(1, first_root).unwrap();
+        state.put_block_header(&header(2)).unwrap();
+        state.put_block_height(2).unwrap();
+        let second_root = commit_state_delta(&storage, state).await.unwrap().0;
+
+        let snapshot = storage.latest_snapshot();
+        let (block, state_root) = get_block(&snapshot, 1).await.unwrap();
+        assert_eq!(state_root, first_root);
+        assert_eq!(block.hash.as_ref(), header(1).hash(&first_root));
+        let (_, state_root) = get_block(&snapshot, 2).await.unwrap();
+        assert_eq!(state_root, second_root);
+    }
+}
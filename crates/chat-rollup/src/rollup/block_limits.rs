@@ -0,0 +1,272 @@
+//! Enforces the [`BlockParams`] limits on the resources a block can use.
+//!
+//! Transactions and deposits are counted in the order they are executed, so every node leaves
+//! out the same ones. A transaction that does not fit into what is left of the block is left
+//! out, but smaller transactions after it still fit, so one large transaction cannot crowd out
+//! the rest of a block. The caller leaves out the later transactions of its sender as well, so
+//! that nonces are never skipped over. A transaction too large for even an empty block never
+//! fits, and is rejected outright by [`check_fits_block`]. Once the deposit limit is reached, all
+//! following deposits are left out.
+
+use rollup_core::{genesis::v1::BlockParams, transaction::v1::Transaction};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+pub(crate) enum BlockLimitExceeded {
+    #[error("block reached its limit of {limit} transactions")]
+    Transactions { limit: u32 },
+    #[error("block reached its limit of {limit} transaction bytes")]
+    TransactionBytes { limit: u64 },
+    #[error("block reached its limit of {limit} text bytes")]
+    TextBytes { limit: u64 },
+    #[error("block reached its limit of {limit} deposits")]
+    Deposits { limit: u32 },
+    #[error(
+        "transaction of {bytes} bytes exceeds the limit of {limit} transaction bytes per block"
+    )]
+    TransactionTooLarge { bytes: u64, limit: u64 },
+    #[error(
+        "transaction posting {bytes} text bytes exceeds the limit of {limit} text bytes per block"
+    )]
+    TextsTooLarge { bytes: u64, limit: u64 },
+}
+
+/// Checks that a transaction of `bytes` encoded bytes posting `text_bytes` bytes of texts fits
+/// into an empty block under `params`. A transaction which does not can never be executed.
+pub(crate) fn check_fits_block(
+    params: &BlockParams,
+    bytes: u64,
+    text_bytes: u64,
+) -> Result<(), BlockLimitExceeded> {
+    if !fits(0, bytes, params.max_transaction_bytes) {
+        return Err(BlockLimitExceeded::TransactionTooLarge {
+            bytes,
+            limit: params.max_transaction_bytes,
+        });
+    }
+    if !fits(0, text_bytes, params.max_text_bytes) {
+        return Err(BlockLimitExceeded::TextsTooLarge {
+            bytes: text_bytes,
+            limit: params.max_text_bytes,
+        });
+    }
+    Ok(())
+}
+
+/// Returns whether `used` plus `amount` stays within `limit`, where a limit of zero means no
+/// limit.
+fn fits(used: u64, amount: u64, limit: u64) -> bool {
+    limit == 0 || used.saturating_add(amount) <= limit
+}
+
+/// Tracks what the transactions and deposits of one block used of its [`BlockParams`].
+pub(crate) struct BlockBudget {
+    params: BlockParams,
+    transactions: u32,
+    transaction_bytes: u64,
+    text_bytes: u64,
+    deposits: u32,
+    deposits_exceeded: Option<BlockLimitExceeded>,
+}
+
+impl BlockBudget {
+    pub(crate) fn new(params: BlockParams) -> Self {
+        Self {
+            params,
+            transactions: 0,
+            transaction_bytes: 0,
+            text_bytes: 0,
+            deposits: 0,
+            deposits_exceeded: None,
+        }
+    }
+
+    pub(crate) fn params(&self) -> &BlockParams {
+        &self.params
+    }
+
+    /// Reserves room for a deposit, unless the block reached its deposit limit.
+    pub(crate) fn try_add_deposit(&mut self) -> Result<(), BlockLimitExceeded> {
+        if let Some(exceeded) = self.deposits_exceeded {
+            return Err(exceeded);
+        }
+        let limit = self.params.max_deposits;
+        if !fits(self.deposits.into(), 1, limit.into()) {
+            let exceeded = BlockLimitExceeded::Deposits { limit };
+            self.deposits_exceeded = Some(exceeded);
+            return Err(exceeded);
+        }
+        self.deposits += 1;
+        Ok(())
+    }
+
+    /// Reserves room for `text_bytes` bytes of a text posted by a deposit, unless that would
+    /// exceed the text bytes limit. A text that does not fit does not affect the texts of later
+    /// deposits or transactions.
+    pub(crate) fn try_add_deposit_text(
+        &mut self,
+        text_bytes: u64,
+    ) -> Result<(), BlockLimitExceeded> {
+        let limit = self.params.max_text_bytes;
+        if !fits(self.text_bytes, text_bytes, limit) {
+            return Err(BlockLimitExceeded::TextBytes { limit });
+        }
+        self.text_bytes = self.text_bytes.saturating_add(text_bytes);
+        Ok(())
+    }
+
+    /// Reserves room for a transaction of `bytes` encoded bytes posting `text_bytes` bytes of
+    /// texts. Nothing is reserved if any limit would be exceeded, which leaves room for smaller
+    /// transactions after it.
+    pub(crate) fn try_add_transaction(
+        &mut self,
+        bytes: u64,
+        text_bytes: u64,
+    ) -> Result<(), BlockLimitExceeded> {
+        let BlockParams {
+            max_transactions,
+            max_transaction_bytes,
+            max_text_bytes,
+            ..
+        } = self.params;
+        let exceeded = if !fits(self.transactions.into(), 1, max_transactions.into()) {
+            Some(BlockLimitExceeded::Transactions {
+                limit: max_transactions,
+            })
+        } else if !fits(self.transaction_bytes, bytes, max_transaction_bytes) {
+            Some(BlockLimitExceeded::TransactionBytes {
+                limit: max_transaction_bytes,
+            })
+        } else if !fits(self.text_bytes, text_bytes, max_text_bytes) {
+            Some(BlockLimitExceeded::TextBytes {
+                limit: max_text_bytes,
+            })
+        } else {
+            None
+        };
+        if let Some(exceeded) = exceeded {
+            return Err(exceeded);
+        }
+        self.transactions += 1;
+        self.transaction_bytes = self.transaction_bytes.saturating_add(bytes);
+        self.text_bytes = self.text_bytes.saturating_add(text_bytes);
+        Ok(())
+    }
+}
+
+/// Returns the total length of the texts `transaction` posts.
+pub(crate) fn text_bytes(transaction: &Transaction) -> u64 {
+    transaction
+        .actions()
+        .iter()
+        .filter_map(|action| match action {
+            rollup_core::transaction::v1::Action::Text(send_text) => {
+                Some(u64::try_from(send_text.text.len()).unwrap_or(u64::MAX))
+            }
+            _ => None,
+        })
+        .fold(0, u64::saturating_add)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params() -> BlockParams {
+        BlockParams {
+            max_transactions: 3,
+            max_transaction_bytes: 100,
+            max_text_bytes: 10,
+            max_deposits: 1,
+        }
+    }
+
+    #[test]
+    fn zero_means_no_limit() {
+        let mut budget = BlockBudget::new(BlockParams::default());
+        for _ in 0..1000 {
+            budget.try_add_transaction(u64::MAX, u64::MAX).unwrap();
+            budget.try_add_deposit().unwrap();
+        }
+    }
+
+    #[test]
+    fn transactions_are_limited_by_count_and_bytes() {
+        let mut budget = BlockBudget::new(params());
+        budget.try_add_transaction(10, 0).unwrap();
+        budget.try_add_transaction(10, 0).unwrap();
+        budget.try_add_transaction(10, 0).unwrap();
+        assert_eq!(
+            budget.try_add_transaction(10, 0),
+            Err(BlockLimitExceeded::Transactions { limit: 3 })
+        );
+
+        let mut budget = BlockBudget::new(params());
+        budget.try_add_transaction(60, 0).unwrap();
+        assert_eq!(
+            budget.try_add_transaction(60, 0),
+            Err(BlockLimitExceeded::TransactionBytes { limit: 100 })
+        );
+
+        let mut budget = BlockBudget::new(params());
+        budget.try_add_transaction(10, 6).unwrap();
+        assert_eq!(
+            budget.try_add_transaction(10, 6),
+            Err(BlockLimitExceeded::TextBytes { limit: 10 })
+        );
+    }
+
+    #[test]
+    fn smaller_transactions_fit_after_an_exceeded_limit() {
+        let mut budget = BlockBudget::new(params());
+        budget.try_add_transaction(60, 0).unwrap();
+        assert_eq!(
+            budget.try_add_transaction(60, 0),
+            Err(BlockLimitExceeded::TransactionBytes { limit: 100 })
+        );
+        // nothing was reserved for the transaction that did not fit
+        budget.try_add_transaction(40, 0).unwrap();
+        // deposits have their own limit
+        budget.try_add_deposit().unwrap();
+        assert_eq!(
+            budget.try_add_deposit(),
+            Err(BlockLimitExceeded::Deposits { limit: 1 })
+        );
+    }
+
+    #[test]
+    fn deposit_texts_share_the_text_bytes_limit() {
+        let mut budget = BlockBudget::new(params());
+        budget.try_add_deposit_text(6).unwrap();
+        assert_eq!(
+            budget.try_add_deposit_text(6),
+            Err(BlockLimitExceeded::TextBytes { limit: 10 })
+        );
+        // a smaller text still fits, and so does a transaction
+        budget.try_add_deposit_text(4).unwrap();
+        budget.try_add_transaction(10, 0).unwrap();
+        assert_eq!(
+            budget.try_add_transaction(10, 1),
+            Err(BlockLimitExceeded::TextBytes { limit: 10 })
+        );
+    }
+
+    #[test]
+    fn transactions_too_large_for_any_block_do_not_fit() {
+        check_fits_block(&params(), 100, 10).unwrap();
+        assert_eq!(
+            check_fits_block(&params(), 101, 0),
+            Err(BlockLimitExceeded::TransactionTooLarge {
+                bytes: 101,
+                limit: 100
+            })
+        );
+        assert_eq!(
+            check_fits_block(&params(), 50, 11),
+            Err(BlockLimitExceeded::TextsTooLarge {
+                bytes: 11,
+                limit: 10
+            })
+        );
+        check_fits_block(&BlockParams::default(), u64::MAX, u64::MAX).unwrap();
+    }
+}
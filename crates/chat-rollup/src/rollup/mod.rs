@@ -1,16 +1,22 @@
+pub(crate) mod block;
+pub(crate) mod block_limits;
 pub(crate) mod state_ext;
 pub(crate) mod storage;
 use crate::accounts::{StateReadExt as _, StateWriteExt as _};
-use crate::bridge::state_ext::StateWriteExt;
+use crate::assets::{StateReadExt as _, StateWriteExt as _};
+use crate::bridge::state_ext::{StateReadExt as _, StateWriteExt};
+use crate::bridge::{BridgeConfig, DepositStatus, Refund};
 use crate::config::Config;
 use crate::execution_service;
+use crate::mempool::Mempool;
+use crate::relayer::{LimitExceeded, RelayError, Relayer};
+use crate::rollup::block::BlockHeader;
 use crate::rollup::state_ext::StateWriteExt as RollupStateExt;
 use crate::snapshot::Snapshot;
+use crate::submission::{OnDropped, Submission, SubmissionBackend, SubmitError};
 use crate::text::{StateReadExt as _, StateWriteExt as _};
+use crate::transaction::tracker::{TransactionStatus, TransactionTracker};
 use astria_core::crypto::SigningKey;
-use astria_core::execution::v1::Block;
-use astria_core::generated::astria::composer::v1::grpc_collector_service_client::GrpcCollectorServiceClient;
-use astria_core::generated::astria::composer::v1::SubmitRollupTransactionRequest;
 use astria_core::generated::astria::execution::v1::execution_service_server::ExecutionServiceServer;
 use astria_core::primitive::v1::asset::{self, Denom};
 use astria_core::primitive::v1::{Address, RollupId};
@@ -22,23 +28,25 @@ use astria_eyre::{
 use bytes::Bytes;
 use cnidarium::Storage;
 use color_eyre::eyre::{self, eyre};
+use futures::{SinkExt as _, StreamExt as _};
+use hex::FromHex as _;
 use prost::Message;
 
-use rollup_core::generated::protocol::genesis::v1::GenesisAppState;
+use rollup_core::generated::protocol::genesis::v1::{BridgeAccount, GenesisAppState};
+use rollup_core::generated::protocol::query::v1::query_service_server::QueryServiceServer;
 use rollup_core::generated::protocol::transaction::v1::Transaction;
-use rollup_core::transaction::v1::action::SendText;
-use rollup_core::transaction::v1::{Action, TransactionBody};
 use serde::{Deserialize, Serialize};
 use state_ext::StateReadExt;
 use std::fs::{self, File};
 use std::io::BufReader;
-use std::net::SocketAddr;
+use std::net::{IpAddr, SocketAddr};
 use std::path::Path;
 use std::str::FromStr;
+use std::sync::Arc;
 use thiserror::Error;
 use tonic::transport::Server;
 use tower::ServiceBuilder;
-use tracing::info;
+use tracing::{info, warn};
 use warp::Filter;
 
 pub trait LoadFromFile {
@@ -78,6 +86,9 @@ pub struct RollupConfig {
     pub sequencer_genesis_block_height: u32,
     pub celestia_genesis_block_height: u32,
     pub celestia_block_variance: u64,
+    /// The bridge accounts of the genesis, whose configs are written for bridge accounts
+    /// registered before configs were stored.
+    pub bridge_accounts: Vec<BridgeAccount>,
 }
 
 impl RollupConfig {
@@ -89,14 +100,11 @@ impl RollupConfig {
             sequencer_genesis_block_height: genesis.sequencer_genesis_block_height,
             celestia_genesis_block_height: genesis.celestia_genesis_block_height,
             celestia_block_variance: genesis.celestia_block_variance,
+            bridge_accounts: genesis.bridge_accounts,
         }
     }
 }
 
-const CHAIN_ID: &str = "astria-chat";
-const FEE_ASSET: &str = "nria";
-const SEQUENCER_PRIVATE_KEY: &str =
-    "2bd806c97f0e00af1a1fc3328fa763a9269723c8db8fac4f93af71db186d6e90";
 // const BRIDGE_ADDRESS: &str = "astria1d7zjjljc0dsmxa545xkpwxym86g8uvvwhtezcr";
 const INITIAL_HASH: [u8; 32] = [69u8; 32];
 const PREFIX: &str = "astria";
@@ -108,6 +116,26 @@ pub struct SendMessageRequest {
     pub message: String,
 }
 
+/// Query parameters of the state reads that can return a proof of the returned value.
+#[derive(Debug, Default, Deserialize)]
+pub struct ProofQuery {
+    /// Return the value together with a proof against the state root of a block.
+    #[serde(default)]
+    pub prove: bool,
+    /// The block to prove the value against. Defaults to the latest block.
+    pub height: Option<u64>,
+}
+
+/// Query parameters of `/abci_query`, following the CometBFT RPC endpoint of the same name.
+#[derive(Debug, Deserialize)]
+pub struct AbciQuery {
+    /// The query path, for example `accounts/balance/<address>`.
+    pub path: String,
+    /// The block to query the state at. Defaults to the latest block.
+    #[serde(default)]
+    pub height: u32,
+}
+
 pub struct Rollup;
 
 #[derive(Error, Debug)]
@@ -120,6 +148,15 @@ pub enum RestError {
 
     #[error("Internal server error")]
     InternalServerError,
+
+    #[error("Bad gateway: {0}")]
+    BadGateway(String),
+
+    #[error("Service unavailable: {0}")]
+    ServiceUnavailable(String),
+
+    #[error("Too many requests: {0}")]
+    TooManyRequests(String),
 }
 
 impl warp::reject::Reject for RestError {}
@@ -130,33 +167,60 @@ impl Rollup {
         let genesis_state: GenesisAppState =
             serde_json::from_str(&file_content).wrap_err("failed to parse genesis json")?;
         let addr: SocketAddr = cfg.execution_grpc_addr.parse()?;
-        let composer_addr = cfg.composer_addr.clone();
         info!("genesis state: {:?}", genesis_state);
         let rollup_id = RollupId::from_unhashed_bytes(genesis_state.rollup_name.clone());
         info!("rollup id: {:?}", rollup_id);
-        let warp_rollup_id = warp::any().map(move || rollup_id);
 
-        let composer_client = GrpcCollectorServiceClient::connect(composer_addr.clone())
-            .await
-            .wrap_err("failed to connect to composer")?;
+        // backends connect lazily, so that the rollup starts without a reachable sequencer
+        let submitter = crate::submission::from_config(&cfg, rollup_id)
+            .wrap_err("failed to construct submission backend")?;
         let storage = cnidarium::Storage::load(cfg.db_filepath.clone(), vec![])
             .await
             .map_err(anyhow_to_eyre)
             .wrap_err("failed to load storage backing chain state")?;
 
+        let mempool = Mempool::new(genesis_state.rollup_name.clone());
+        let tracker = TransactionTracker::new();
+        let relayer = Relayer::spawn(
+            &cfg,
+            genesis_state.rollup_name.clone(),
+            storage.clone(),
+            submitter.clone(),
+            mempool.clone(),
+            tracker.clone(),
+        )
+        .wrap_err("failed to construct relayer")?;
         let submit_transaction = warp::path!("submit_transaction")
             .and(warp::post())
-            .and(with_composer(composer_client.clone()))
+            .and(with_submitter(submitter.clone()))
             .and(warp::body::bytes())
-            .and(warp_rollup_id)
+            .and(with_storage(storage.clone()))
+            .and(with_mempool(mempool.clone()))
+            .and(with_tracker(tracker.clone()))
             .and_then(handle_submit_transaction);
 
+        let health = warp::path!("health")
+            .and(warp::get())
+            .and(with_submitter(submitter.clone()))
+            .and_then(handle_health);
+
+        let get_transaction_status = warp::path!("tx" / String / "status")
+            .and(warp::get())
+            .and(with_storage(storage.clone()))
+            .and(with_tracker(tracker.clone()))
+            .and_then(handle_get_transaction_status);
+
+        let subscribe_transaction_status = warp::path!("tx" / String / "subscribe")
+            .and(warp::ws())
+            .and(with_storage(storage.clone()))
+            .and(with_tracker(tracker.clone()))
+            .and_then(handle_subscribe_transaction_status);
+
         let submit_unsigned_message = warp::path!("message")
             .and(warp::post())
+            .and(with_client_ip(cfg.relayer_trust_forwarded_for))
             .and(warp::body::json())
-            .and(with_composer(composer_client.clone()))
-            .and(with_storage(storage.clone()))
-            .and(warp_rollup_id)
+            .and(with_relayer(relayer))
             .and_then(handle_submit_unsigned_text)
             .with(
                 warp::cors()
@@ -167,16 +231,24 @@ impl Rollup {
 
         let get_account_balance = warp::path!("get_account_balance" / String / String)
             .and(warp::get())
+            .and(warp::query::<ProofQuery>())
             .and(with_storage(storage.clone()))
             .and_then(handle_get_account_balance);
 
+        let get_account_balances = warp::path!("accounts" / String / "balances")
+            .and(warp::get())
+            .and(with_storage(storage.clone()))
+            .and_then(handle_get_account_balances);
+
         let get_account_nonce = warp::path!("get_account_nonce" / String)
             .and(warp::get())
+            .and(warp::query::<ProofQuery>())
             .and(with_storage(storage.clone()))
             .and_then(handle_get_account_nonce);
 
         let get_text_from_id = warp::path!("get_text_from_id" / u64)
             .and(warp::get())
+            .and(warp::query::<ProofQuery>())
             .and(with_storage(storage.clone()))
             .and_then(handle_get_text_from_id);
 
@@ -186,16 +258,62 @@ impl Rollup {
             .and_then(handle_recents)
             .with(warp::cors().allow_any_origin());
 
+        let get_block = warp::path!("block" / u32)
+            .and(warp::get())
+            .and(with_storage(storage.clone()))
+            .and_then(handle_get_block);
+
+        let get_assets = warp::path!("assets")
+            .and(warp::get())
+            .and(with_storage(storage.clone()))
+            .and_then(handle_get_assets);
+
+        let get_asset = warp::path!("assets" / String)
+            .and(warp::get())
+            .and(with_storage(storage.clone()))
+            .and_then(handle_get_asset);
+
+        let get_deposit_status = warp::path!("deposits" / String / u64)
+            .and(warp::get())
+            .and(with_storage(storage.clone()))
+            .and_then(handle_get_deposit_status);
+
+        let get_refunds = warp::path!("refunds" / u32)
+            .and(warp::get())
+            .and(with_storage(storage.clone()))
+            .and_then(handle_get_refunds);
+
+        let abci_query_router = crate::abci_query_router::Router::new()
+            .wrap_err("failed to construct abci query router")?;
+        let abci_query = warp::path!("abci_query")
+            .and(warp::get())
+            .and(warp::query::<AbciQuery>())
+            .and(with_storage(storage.clone()))
+            .and(warp::any().map(move || abci_query_router.clone()))
+            .and_then(handle_abci_query);
+
         let routes = submit_transaction
+            .or(get_block)
             .or(get_account_balance)
+            .or(get_account_balances)
             .or(get_text_from_id)
             .or(submit_unsigned_message)
             .or(get_recents)
-            .or(get_account_nonce);
+            .or(get_account_nonce)
+            .or(get_assets)
+            .or(get_asset)
+            .or(get_deposit_status)
+            .or(get_refunds)
+            .or(abci_query)
+            .or(get_transaction_status)
+            .or(subscribe_transaction_status)
+            .or(health);
 
         // Spawn the server in a separate async task so it doesn't block the main program
         tokio::spawn(async move {
-            warp::serve(routes).run(([0, 0, 0, 0], ROLLUP_PORT)).await;
+            warp::serve(routes.recover(handle_rejection))
+                .run(([0, 0, 0, 0], ROLLUP_PORT))
+                .await;
         });
 
         let snapshot_service = Snapshot;
@@ -204,64 +322,23 @@ impl Rollup {
             .concurrency_limit(3)
             .service(snapshot_service);
 
-        let latest_snapshot = storage.clone().latest_snapshot();
-        let mut delta = cnidarium::StateDelta::new(latest_snapshot);
-
-        // add first block if it doesn't exist
-        if delta.get_block_height().await.is_err() {
-            let text = "hello world".to_string();
-            let asset = crate::accounts::state_ext::nria();
-            for account in genesis_state.accounts.clone() {
-                let address: Address =
-                    Address::from_str(&account.address.unwrap().bech32m).unwrap();
-                address.to_prefix(PREFIX)?;
-                delta.put_account_balance(&address, &asset, account.balance.unwrap().into())?;
-            }
-            for bridge_account in genesis_state.bridge_accounts.clone() {
-                let bridge_address: Address =
-                    Address::from_str(bridge_account.bech32m.as_str()).unwrap();
-                bridge_address.to_prefix(PREFIX)?;
-                delta.put_bridge_account(&bridge_address).unwrap();
-            }
-
-            delta.put_text(text, PREFIX.to_string(), 0).unwrap();
-            delta.put_last_text_id(1).unwrap();
-            delta
-                .put_commitment_state(0, 0, genesis_state.celestia_genesis_block_height)
-                .unwrap();
-            let block = astria_core::generated::astria::execution::v1::Block {
-                number: 0,
-                parent_block_hash: Bytes::from_static(&INITIAL_HASH),
-                hash: Bytes::from_static(&INITIAL_HASH),
-                timestamp: Some(pbjson_types::Timestamp {
-                    seconds: 0,
-                    nanos: 0,
-                }),
-            };
+        init_genesis_state(&storage, &genesis_state).await?;
 
-            let block = Block::try_from_raw(block).unwrap();
-            delta.put_block(block, 0).unwrap();
-        }
-
-        let write = storage
-            .clone()
-            .prepare_commit(delta)
-            .await
-            .map_err(anyhow_to_eyre)
-            .wrap_err("failed to prepare commit")?;
-        storage
-            .clone()
-            .commit_batch(write)
-            .expect("must be able to successfully commit to storage");
         info!("starting snapshot service server");
         let execution_service = execution_service::RollupExecutionService {
             storage: storage.clone(),
             config: RollupConfig::new(cfg.clone(), genesis_state.clone()),
+            mempool,
+            tracker,
         };
 
         info!("starting rollup");
+        let query_service = crate::query_service::RollupQueryService {
+            storage: storage.clone(),
+        };
         Server::builder()
             .add_service(ExecutionServiceServer::new(execution_service))
+            .add_service(QueryServiceServer::new(query_service))
             .serve(addr)
             .await?;
 
@@ -269,14 +346,150 @@ impl Rollup {
     }
 }
 
-#[allow(dead_code)]
-fn with_composer(
-    composer_client: GrpcCollectorServiceClient<tonic::transport::channel::Channel>,
-) -> impl Filter<
-    Extract = (GrpcCollectorServiceClient<tonic::transport::channel::Channel>,),
-    Error = std::convert::Infallible,
-> + Clone {
-    warp::any().map(move || composer_client.clone())
+/// Writes `genesis_state` and the genesis block to `storage`, unless it already holds them.
+///
+/// # Errors
+/// Returns an error if `genesis_state` is invalid or cannot be committed to `storage`.
+pub async fn init_genesis_state(storage: &Storage, genesis_state: &GenesisAppState) -> Result<()> {
+    let rollup_id = RollupId::from_unhashed_bytes(genesis_state.rollup_name.clone());
+    let mut delta = cnidarium::StateDelta::new(storage.latest_snapshot());
+    // the genesis block was already added
+    if delta.get_block_height().await.is_ok() {
+        // blocks used to be stored without their state roots, so the hashes of the blocks of
+        // such a storage cannot be recomputed and it cannot be migrated
+        eyre::ensure!(
+            !delta.has_legacy_commitment_state().await?,
+            "the storage was written by an older version of the rollup whose block hashes do not \
+             commit to state roots; delete the storage to execute the rollup again from genesis"
+        );
+        return Ok(());
+    }
+    let text = "hello world".to_string();
+    let asset = crate::accounts::state_ext::nria();
+    delta.put_native_asset(asset.clone())?;
+    delta.put_ibc_asset(asset.clone())?;
+    for account in genesis_state.accounts.clone() {
+        let address: Address = Address::from_str(&account.address.unwrap().bech32m).unwrap();
+        address.to_prefix(PREFIX)?;
+        delta.put_account_balance(&address, &asset, account.balance.unwrap().into())?;
+    }
+    for bridge_account in &genesis_state.bridge_accounts {
+        let (bridge_address, bridge_asset) =
+            crate::bridge::parse_genesis_bridge_account(bridge_account)
+                .wrap_err("invalid bridge account in genesis")?;
+        bridge_address.to_prefix(PREFIX)?;
+        if let Some(trace_prefixed) = bridge_asset.as_trace_prefixed() {
+            delta.put_ibc_asset(trace_prefixed.clone())?;
+        }
+        delta.put_bridge_account(&bridge_address).unwrap();
+        delta.put_bridge_config(
+            &bridge_address,
+            &BridgeConfig {
+                asset: bridge_asset.to_ibc_prefixed(),
+                rollup_id,
+            },
+        )?;
+    }
+
+    delta.put_text(text, PREFIX.to_string(), 0).unwrap();
+    if let Some(text_params) = genesis_state.text_params.clone() {
+        let text_params = rollup_core::genesis::v1::TextParams::try_from_raw(text_params)
+            .wrap_err("invalid text params in genesis")?;
+        delta.put_text_params(&text_params)?;
+    }
+    if let Some(block_params) = &genesis_state.block_params {
+        let block_params = rollup_core::genesis::v1::BlockParams::try_from_raw_ref(block_params)
+            .wrap_err("invalid block params in genesis")?;
+        delta.put_block_params(&block_params)?;
+    }
+    if let Some(compute_params) = &genesis_state.compute_params {
+        let compute_params =
+            rollup_core::genesis::v1::ComputeParams::try_from_raw_ref(compute_params)
+                .wrap_err("invalid compute params in genesis")?;
+        delta.put_compute_params(&compute_params)?;
+    }
+    delta.put_last_text_id(1).unwrap();
+    delta
+        .put_commitment_state(0, 0, genesis_state.celestia_genesis_block_height)
+        .unwrap();
+    delta.put_block_height(0).unwrap();
+    let timestamp = pbjson_types::Timestamp {
+        seconds: 0,
+        nanos: 0,
+    };
+    delta.put_block_timestamp(timestamp.clone()).unwrap();
+    let empty_root = merkle::Tree::new().root();
+    delta.put_block_header(&BlockHeader {
+        number: 0,
+        parent_block_hash: Bytes::from_static(&INITIAL_HASH),
+        transactions_root: empty_root,
+        deposits_root: empty_root,
+        timestamp,
+    })?;
+    // the version the genesis state is committed at
+    let storage_version = storage.latest_version().wrapping_add(1);
+    delta.put_storage_version_by_height(0, storage_version)?;
+    execution_service::commit_state_delta(storage, delta)
+        .await
+        .wrap_err("failed to commit genesis state")?;
+    Ok(())
+}
+
+fn with_submitter(
+    submitter: Arc<dyn SubmissionBackend>,
+) -> impl Filter<Extract = (Arc<dyn SubmissionBackend>,), Error = std::convert::Infallible> + Clone
+{
+    warp::any().map(move || submitter.clone())
+}
+
+fn with_mempool(
+    mempool: Mempool,
+) -> impl Filter<Extract = (Mempool,), Error = std::convert::Infallible> + Clone {
+    warp::any().map(move || mempool.clone())
+}
+
+fn with_relayer(
+    relayer: Option<Relayer>,
+) -> impl Filter<Extract = (Option<Relayer>,), Error = std::convert::Infallible> + Clone {
+    warp::any().map(move || relayer.clone())
+}
+
+/// Extracts the IP address of the client, taken from the last entry of the `X-Forwarded-For`
+/// header if `trust_forwarded_for` is set and from the peer address otherwise.
+fn with_client_ip(
+    trust_forwarded_for: bool,
+) -> impl Filter<Extract = (IpAddr,), Error = warp::Rejection> + Clone {
+    warp::addr::remote()
+        .and(warp::header::optional::<String>("x-forwarded-for"))
+        .and_then(
+            move |remote: Option<SocketAddr>, forwarded_for: Option<String>| async move {
+                client_ip(remote, forwarded_for.as_deref(), trust_forwarded_for).ok_or_else(|| {
+                    warp::reject::custom(RestError::InvalidInput(
+                        "client address is unknown".to_string(),
+                    ))
+                })
+            },
+        )
+}
+
+/// Returns the IP address of the client. The last entry of `forwarded_for` is the one added by
+/// the reverse proxy in front of the rollup, while earlier entries are chosen by the client.
+fn client_ip(
+    remote: Option<SocketAddr>,
+    forwarded_for: Option<&str>,
+    trust_forwarded_for: bool,
+) -> Option<IpAddr> {
+    let forwarded = forwarded_for
+        .filter(|_| trust_forwarded_for)
+        .and_then(|forwarded_for| forwarded_for.rsplit(',').next())
+        .and_then(|ip| ip.trim().parse().ok());
+    forwarded.or_else(|| remote.map(|remote| remote.ip()))
+}
+
+fn with_tracker(
+    tracker: TransactionTracker,
+) -> impl Filter<Extract = (TransactionTracker,), Error = std::convert::Infallible> + Clone {
+    warp::any().map(move || tracker.clone())
 }
 
 // Helper function to pass `GameManager` as a filter to endpoints
@@ -288,12 +501,12 @@ fn with_storage(
 }
 
 async fn handle_submit_transaction(
-    mut composer_client: GrpcCollectorServiceClient<tonic::transport::channel::Channel>,
+    submitter: Arc<dyn SubmissionBackend>,
     data: Bytes,
-    rollup_id: RollupId,
+    storage: Storage,
+    mempool: Mempool,
+    tracker: TransactionTracker,
 ) -> Result<impl warp::Reply, warp::Rejection> {
-    // let snapshot = storage.latest_snapshot();
-    // let delta = cnidarium::StateDelta::new(snapshot);
     let raw_transaction = match Transaction::decode(data) {
         Ok(transaction) => transaction,
         Err(_) => {
@@ -302,48 +515,396 @@ async fn handle_submit_transaction(
             )))
         }
     };
+    let transaction = rollup_core::transaction::v1::Transaction::try_from_raw(
+        raw_transaction.clone(),
+    )
+    .map_err(|err| {
+        warp::reject::custom(RestError::InvalidInput(format!(
+            "invalid transaction: {err}"
+        )))
+    })?;
+
+    // reject transactions that would fail execution before they cost sequencer fees
+    let snapshot = storage.latest_snapshot();
+    if let Err(err) = mempool.check_and_insert(&transaction, &snapshot).await {
+        return Err(warp::reject::custom(RestError::InvalidInput(format!(
+            "transaction rejected: {err:#}"
+        ))));
+    }
 
     info!(
         "received transaction submission request: {:?}",
         raw_transaction
     );
 
-    match composer_client
-        .submit_rollup_transaction(SubmitRollupTransactionRequest {
-            rollup_id: Some(rollup_id.into_raw()),
-            data: raw_transaction.encode_to_vec().into(),
+    let data: Bytes = raw_transaction.encode_to_vec().into();
+    let transaction_hash = crate::receipts::transaction_hash(&data);
+    // a queued transaction that is dropped releases its nonce, so that it can be submitted again
+    let on_dropped: OnDropped = {
+        let mempool = mempool.clone();
+        let tracker = tracker.clone();
+        let transaction = transaction.clone();
+        Box::new(move |reason| {
+            warn!(
+                transaction_hash = %hex::encode(transaction_hash),
+                reason,
+                "queued transaction was dropped",
+            );
+            mempool.remove(&transaction);
+            tracker.dropped(transaction_hash);
         })
+    };
+    // tracked before submitting, as a queued transaction may be dropped before `submit` returns
+    tracker.submitted(transaction_hash);
+    match submitter.submit(data, on_dropped).await {
+        Ok(submission) => {
+            // a queued transaction is accepted, but only forwarded once the backend is back
+            let status = match submission {
+                Submission::Forwarded => warp::http::StatusCode::OK,
+                Submission::Queued => warp::http::StatusCode::ACCEPTED,
+            };
+            Ok(warp::reply::with_status(
+                warp::reply::json(&TransactionStatus::Submitted.to_json(&transaction_hash)),
+                status,
+            ))
+        }
+        Err(err) => {
+            mempool.remove(&transaction);
+            tracker.dropped(transaction_hash);
+            Err(warp::reject::custom(RestError::from(err)))
+        }
+    }
+}
+
+impl From<SubmitError> for RestError {
+    fn from(err: SubmitError) -> Self {
+        match err {
+            SubmitError::Unavailable => RestError::ServiceUnavailable(err.to_string()),
+            SubmitError::Rejected(_) => RestError::BadGateway(err.to_string()),
+        }
+    }
+}
+
+/// Reports whether the rollup can forward transactions to the sequencer, responding with `503`
+/// while it cannot.
+async fn handle_health(
+    submitter: Arc<dyn SubmissionBackend>,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    let health = submitter.health();
+    let status = if health.connected {
+        warp::http::StatusCode::OK
+    } else {
+        warp::http::StatusCode::SERVICE_UNAVAILABLE
+    };
+    Ok(warp::reply::with_status(
+        warp::reply::json(&serde_json::json!({
+            "submission": {
+                "backend": submitter.name(),
+                "connected": health.connected,
+                "queued_submissions": health.queued_submissions,
+            },
+        })),
+        status,
+    ))
+}
+
+async fn handle_get_transaction_status(
+    transaction_hash: String,
+    storage: Storage,
+    tracker: TransactionTracker,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    let Ok(transaction_hash) = <[u8; 32]>::from_hex(&transaction_hash) else {
+        return Err(warp::reject::custom(RestError::InvalidInput(
+            transaction_hash,
+        )));
+    };
+    match tracker
+        .status(&transaction_hash, &storage.latest_snapshot())
         .await
     {
-        Ok(_) => Ok(warp::reply::json(&format!(
-            "transaction {:?} submitted to sequencer",
-            raw_transaction
-        ))),
-        Err(_) => Err(warp::reject::reject()),
+        Ok(Some(status)) => Ok(warp::reply::json(&status.to_json(&transaction_hash))),
+        Ok(None) => Err(warp::reject::custom(RestError::NotFound)),
+        Err(_) => Err(warp::reject::custom(RestError::InternalServerError)),
+    }
+}
+
+async fn handle_subscribe_transaction_status(
+    transaction_hash: String,
+    ws: warp::ws::Ws,
+    storage: Storage,
+    tracker: TransactionTracker,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    let Ok(transaction_hash) = <[u8; 32]>::from_hex(&transaction_hash) else {
+        return Err(warp::reject::custom(RestError::InvalidInput(
+            transaction_hash,
+        )));
+    };
+    Ok(ws.on_upgrade(move |socket| {
+        stream_transaction_status(socket, transaction_hash, storage, tracker)
+    }))
+}
+
+/// Sends the current status of the transaction with `transaction_hash` over `socket`, followed
+/// by every transition of its status, and closes the socket once the transaction is firm or was
+/// dropped.
+async fn stream_transaction_status(
+    mut socket: warp::ws::WebSocket,
+    transaction_hash: [u8; 32],
+    storage: Storage,
+    tracker: TransactionTracker,
+) {
+    // subscribe before reading the current status so that no transition is missed
+    let mut updates = tracker.subscribe();
+    let mut last_status = None;
+    let mut status = tracker
+        .status(&transaction_hash, &storage.latest_snapshot())
+        .await
+        .ok()
+        .flatten();
+    loop {
+        if let Some(status) = status.filter(|status| Some(*status) != last_status) {
+            let message = status.to_json(&transaction_hash).to_string();
+            if socket.send(warp::ws::Message::text(message)).await.is_err() {
+                return;
+            }
+            if status.is_final() {
+                break;
+            }
+            last_status = Some(status);
+        }
+        status = tokio::select! {
+            update = updates.recv() => match update {
+                Ok(update) if update.transaction_hash == transaction_hash => Some(update.status),
+                Ok(_) => None,
+                // updates were dropped, so the status is read again
+                Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => tracker
+                    .status(&transaction_hash, &storage.latest_snapshot())
+                    .await
+                    .ok()
+                    .flatten(),
+                Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+            },
+            message = socket.next() => match message {
+                Some(Ok(message)) if !message.is_close() => None,
+                _ => return,
+            },
+        };
     }
+    let _ = socket.close().await;
+}
+
+/// Replies to requests rejected with a [`RestError`] with a JSON body describing the error.
+/// All other rejections are left to warp's default handling.
+async fn handle_rejection(rejection: warp::Rejection) -> Result<impl warp::Reply, warp::Rejection> {
+    let Some(error) = rejection.find::<RestError>() else {
+        return Err(rejection);
+    };
+    let status = match error {
+        RestError::NotFound => warp::http::StatusCode::NOT_FOUND,
+        RestError::InvalidInput(_) => warp::http::StatusCode::BAD_REQUEST,
+        RestError::InternalServerError => warp::http::StatusCode::INTERNAL_SERVER_ERROR,
+        RestError::BadGateway(_) => warp::http::StatusCode::BAD_GATEWAY,
+        RestError::ServiceUnavailable(_) => warp::http::StatusCode::SERVICE_UNAVAILABLE,
+        RestError::TooManyRequests(_) => warp::http::StatusCode::TOO_MANY_REQUESTS,
+    };
+    Ok(warp::reply::with_status(
+        warp::reply::json(&serde_json::json!({
+            "error": error.to_string(),
+        })),
+        status,
+    ))
 }
 
 async fn handle_get_account_balance(
     account: String,
     asset: String,
+    query: ProofQuery,
     storage: Storage,
 ) -> Result<impl warp::Reply, warp::Rejection> {
+    let denom = Denom::from_str(asset.as_str())
+        .map_err(|_| warp::reject::custom(RestError::InvalidInput(asset.clone())))?;
+    let address = Address::from_str(account.as_str())
+        .map_err(|_| warp::reject::custom(RestError::InvalidInput(account.clone())))?;
+    if query.prove {
+        let snapshot = proof_snapshot(&storage, query.height).await?;
+        return match crate::accounts::proof::balance_with_proof(&snapshot, &address, &denom).await {
+            Ok((balance, proof)) => Ok(warp::reply::json(&serde_json::json!({
+                "value": balance.to_string(),
+                "proof": proof,
+            }))),
+            Err(_) => Err(warp::reject::custom(RestError::InternalServerError)),
+        };
+    }
     let snapshot = storage.latest_snapshot();
     let delta = cnidarium::StateDelta::new(snapshot);
-    let denom = Denom::from_str(asset.as_str()).unwrap();
-    match delta
-        .get_account_balance(&Address::from_str(account.as_str()).unwrap(), &denom)
-        .await
-    {
+    match delta.get_account_balance(&address, &denom).await {
         Ok(balance) => Ok(warp::reply::json(&balance.to_string())),
         Err(_) => Err(warp::reject::reject()),
     }
 }
 
+async fn handle_get_account_balances(
+    account: String,
+    storage: Storage,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    let address = Address::from_str(account.as_str())
+        .map_err(|_| warp::reject::custom(RestError::InvalidInput(account.clone())))?;
+    let snapshot = storage.latest_snapshot();
+    let Ok(mut balances) =
+        crate::accounts::query::get_trace_prefixed_account_balances(&snapshot, &address).await
+    else {
+        return Err(warp::reject::custom(RestError::InternalServerError));
+    };
+    balances.sort_unstable_by(|a, b| a.denom.cmp(&b.denom));
+    let balances: Vec<_> = balances
+        .into_iter()
+        .map(|balance| {
+            serde_json::json!({
+                "denom": balance.denom.to_string(),
+                "balance": balance.balance.to_string(),
+            })
+        })
+        .collect();
+    Ok(warp::reply::json(&balances))
+}
+
+async fn handle_get_assets(storage: Storage) -> Result<impl warp::Reply, warp::Rejection> {
+    let snapshot = storage.latest_snapshot();
+    let Ok(mut assets) = snapshot.get_ibc_assets().await else {
+        return Err(warp::reject::custom(RestError::InternalServerError));
+    };
+    assets.sort_unstable_by_key(ToString::to_string);
+    let assets: Vec<_> = assets.iter().map(asset_json).collect();
+    Ok(warp::reply::json(&assets))
+}
+
+async fn handle_get_asset(
+    ibc_id: String,
+    storage: Storage,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    // the id is given as the hex part of `ibc/<hex>`
+    let ibc_prefixed = asset::IbcPrefixed::from_str(&format!("ibc/{ibc_id}"))
+        .map_err(|_| warp::reject::custom(RestError::InvalidInput(ibc_id.clone())))?;
+    let snapshot = storage.latest_snapshot();
+    match snapshot
+        .map_ibc_to_trace_prefixed_asset(&ibc_prefixed)
+        .await
+    {
+        Ok(Some(denom)) => Ok(warp::reply::json(&asset_json(&denom))),
+        Ok(None) => Err(warp::reject::custom(RestError::NotFound)),
+        Err(_) => Err(warp::reject::custom(RestError::InternalServerError)),
+    }
+}
+
+fn asset_json(asset: &asset::TracePrefixed) -> serde_json::Value {
+    serde_json::json!({
+        "id": asset.to_ibc_prefixed().to_string(),
+        "denom": asset.to_string(),
+    })
+}
+
+async fn handle_get_deposit_status(
+    source_transaction_id: String,
+    source_action_index: u64,
+    storage: Storage,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    let Ok(source_transaction_id) = <[u8; 32]>::from_hex(&source_transaction_id) else {
+        return Err(warp::reject::custom(RestError::InvalidInput(
+            source_transaction_id,
+        )));
+    };
+    let snapshot = storage.latest_snapshot();
+    match snapshot
+        .get_deposit_status(&source_transaction_id, source_action_index)
+        .await
+    {
+        Ok(Some(DepositStatus::Credited)) => Ok(warp::reply::json(&serde_json::json!({
+            "status": "credited",
+        }))),
+        Ok(Some(DepositStatus::Failed { reason })) => Ok(warp::reply::json(&serde_json::json!({
+            "status": "failed",
+            "reason": reason,
+        }))),
+        Ok(None) => Err(warp::reject::custom(RestError::NotFound)),
+        Err(_) => Err(warp::reject::custom(RestError::InternalServerError)),
+    }
+}
+
+async fn handle_get_refunds(
+    height: u32,
+    storage: Storage,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    let snapshot = storage.latest_snapshot();
+    let Ok(refunds) = snapshot.get_refunds(height).await else {
+        return Err(warp::reject::custom(RestError::InternalServerError));
+    };
+    let refunds: Vec<_> = refunds
+        .iter()
+        .map(|refund| refund_json(height, refund))
+        .collect();
+    Ok(warp::reply::json(&refunds))
+}
+
+/// Returns `refund`, emitted at `height`, in the shape a bridge withdrawer turns into a
+/// `BridgeUnlock` action. A `null` recipient is the signer of the source transaction.
+fn refund_json(height: u32, refund: &Refund) -> serde_json::Value {
+    serde_json::json!({
+        "rollup_block_number": height,
+        "rollup_withdrawal_event_id": refund.withdrawal_event_id(),
+        "source_transaction_id": hex::encode(refund.source_transaction_id),
+        "source_action_index": refund.source_action_index,
+        "bridge_address": refund.bridge_address.to_string(),
+        "recipient": refund.recipient.map(|recipient| recipient.to_string()),
+        "asset": refund.asset.to_string(),
+        "amount": refund.amount.to_string(),
+        "reason": refund.reason,
+    })
+}
+
+async fn handle_abci_query(
+    query: AbciQuery,
+    storage: Storage,
+    router: crate::abci_query_router::Router,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    use base64::{engine::general_purpose::STANDARD, Engine as _};
+
+    // the CometBFT RPC accepts the path as a quoted string
+    let request = tendermint::abci::request::Query {
+        data: Bytes::new(),
+        path: query.path.trim_matches('"').to_string(),
+        height: query.height.into(),
+        prove: false,
+    };
+    let response = router.handle(storage, request).await;
+    Ok(warp::reply::json(&serde_json::json!({
+        "response": {
+            "code": response.code.value(),
+            "log": response.log,
+            "info": response.info,
+            "index": response.index.to_string(),
+            "key": STANDARD.encode(&response.key),
+            "value": STANDARD.encode(&response.value),
+            "height": response.height.to_string(),
+            "codespace": response.codespace,
+        }
+    })))
+}
+
 async fn handle_get_text_from_id(
     id: u64,
+    query: ProofQuery,
     storage: Storage,
 ) -> Result<impl warp::Reply, warp::Rejection> {
+    if query.prove {
+        let snapshot = proof_snapshot(&storage, query.height).await?;
+        return match crate::text::proof::text_with_proof(&snapshot, id).await {
+            Ok((text, proof)) => Ok(warp::reply::json(&serde_json::json!({
+                "value": text,
+                "proof": proof,
+            }))),
+            Err(_) => Err(warp::reject::custom(RestError::InternalServerError)),
+        };
+    }
     let snapshot = storage.latest_snapshot();
     let delta = cnidarium::StateDelta::new(snapshot);
     match delta.get_text(id).await {
@@ -352,48 +913,73 @@ async fn handle_get_text_from_id(
     }
 }
 
-#[allow(dead_code)]
-async fn handle_submit_unsigned_text(
-    req: SendMessageRequest,
-    mut composer_client: GrpcCollectorServiceClient<tonic::transport::channel::Channel>,
+/// Returns the snapshot committed to by the block at `height` (or the latest block) to read
+/// proofs from.
+async fn proof_snapshot(
+    storage: &Storage,
+    height: Option<u64>,
+) -> Result<cnidarium::Snapshot, warp::Rejection> {
+    crate::proof::snapshot_at_height(storage, height)
+        .await
+        .map_err(|_| warp::reject::custom(RestError::NotFound))
+}
+
+async fn handle_get_block(
+    height: u32,
     storage: Storage,
-    rollup_id: RollupId,
 ) -> Result<impl warp::Reply, warp::Rejection> {
     let snapshot = storage.latest_snapshot();
-    let delta = cnidarium::StateDelta::new(snapshot);
-    let sequencer_key = signing_key_from_private_key(SEQUENCER_PRIVATE_KEY).unwrap();
-    let fee_asset = asset::denom::Denom::from_str(FEE_ASSET).unwrap();
-    let from_address = address_from_signing_key(&sequencer_key, "astria").unwrap();
-    println!("sending tx from address: {from_address}");
-    let nonce = match delta.get_account_nonce(&from_address).await {
-        Ok(nonce) => Ok(nonce),
-        Err(_) => Err(warp::reject::reject()),
+    let (Ok((block, state_root)), Ok(header)) = (
+        block::get_block(&snapshot, height).await,
+        snapshot.get_block_header(height).await,
+    ) else {
+        return Err(warp::reject::custom(RestError::NotFound));
+    };
+    let timestamp = block.timestamp.unwrap_or_default();
+    Ok(warp::reply::json(&serde_json::json!({
+        "number": block.number,
+        "hash": hex::encode(&block.hash),
+        "parent_block_hash": hex::encode(&block.parent_block_hash),
+        "transactions_root": hex::encode(header.transactions_root),
+        "deposits_root": hex::encode(header.deposits_root),
+        "timestamp": {
+            "seconds": timestamp.seconds,
+            "nanos": timestamp.nanos,
+        },
+        "state_root": hex::encode(state_root),
+    })))
+}
+
+async fn handle_submit_unsigned_text(
+    client: IpAddr,
+    req: SendMessageRequest,
+    relayer: Option<Relayer>,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    let Some(relayer) = relayer else {
+        return Err(warp::reject::custom(RestError::ServiceUnavailable(
+            "no relayer key is configured".to_string(),
+        )));
+    };
+    match relayer.relay_text(client, &req.sender, req.message).await {
+        Ok(transaction_hash) => Ok(warp::reply::json(
+            &TransactionStatus::Submitted.to_json(&transaction_hash),
+        )),
+        Err(err) => Err(warp::reject::custom(RestError::from(err))),
+    }
+}
+
+impl From<RelayError> for RestError {
+    fn from(err: RelayError) -> Self {
+        match err {
+            RelayError::LimitExceeded(LimitExceeded::SpendCap) | RelayError::Rejected(_) => {
+                RestError::ServiceUnavailable(err.to_string())
+            }
+            RelayError::LimitExceeded(_) => RestError::TooManyRequests(err.to_string()),
+            RelayError::InvalidMessage(_) => RestError::InvalidInput(err.to_string()),
+            RelayError::State(_) => RestError::InternalServerError,
+            RelayError::Submit(err) => RestError::from(err),
+        }
     }
-    .unwrap();
-    let tx = TransactionBody::builder()
-        .nonce(nonce)
-        .chain_id(CHAIN_ID)
-        .actions(vec![Action::Text(SendText {
-            text: req.message.clone(),
-            from: req.sender.to_string(),
-            fee_asset,
-        })])
-        .try_build()
-        .wrap_err("failed to construct a transaction")
-        .unwrap()
-        .sign(&sequencer_key);
-    let raw_encoded: prost::bytes::Bytes = tx.into_raw().encode_to_vec().into();
-    composer_client
-        .submit_rollup_transaction(SubmitRollupTransactionRequest {
-            rollup_id: Some(rollup_id.into_raw()),
-            data: raw_encoded,
-        })
-        .await
-        .unwrap();
-    Ok(warp::reply::json(&format!(
-        "text {:?} submitted to sequencer",
-        req.message
-    )))
 }
 
 #[allow(dead_code)]
@@ -445,15 +1031,216 @@ pub(crate) fn address_from_signing_key(
 
 async fn handle_get_account_nonce(
     account: String,
+    query: ProofQuery,
     storage: Storage,
 ) -> Result<impl warp::Reply, warp::Rejection> {
+    let address = Address::from_str(account.as_str())
+        .map_err(|_| warp::reject::custom(RestError::InvalidInput(account.clone())))?;
+    if query.prove {
+        let snapshot = proof_snapshot(&storage, query.height).await?;
+        return match crate::accounts::proof::nonce_with_proof(&snapshot, &address).await {
+            Ok((nonce, proof)) => Ok(warp::reply::json(&serde_json::json!({
+                "value": nonce.to_string(),
+                "proof": proof,
+            }))),
+            Err(_) => Err(warp::reject::custom(RestError::InternalServerError)),
+        };
+    }
     let snapshot = storage.latest_snapshot();
     let delta = cnidarium::StateDelta::new(snapshot);
-    match delta
-        .get_account_nonce(&Address::from_str(account.as_str()).unwrap())
-        .await
-    {
+    match delta.get_account_nonce(&address).await {
         Ok(nonce) => Ok(warp::reply::json(&nonce.to_string())),
         Err(_) => Err(warp::reject::reject()),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use astria_core::primitive::v1::asset;
+    use cnidarium::{StateDelta, StateWrite as _};
+    use rollup_core::transaction::v1::{action::SendText, Action, TransactionBody};
+    use warp::Reply as _;
+
+    use super::*;
+    use crate::submission::MockBackend;
+
+    const CHAIN_ID: &str = "astria-chat";
+
+    fn encoded_transaction(nonce: u32) -> Bytes {
+        TransactionBody::builder()
+            .nonce(nonce)
+            .chain_id(CHAIN_ID)
+            .actions(vec![Action::Text(SendText {
+                text: "gm".to_string(),
+                from: "alice".to_string(),
+                fee_asset: "nria".parse().unwrap(),
+            })])
+            .try_build()
+            .unwrap()
+            .sign(&SigningKey::from([1; 32]))
+            .into_raw()
+            .encode_to_vec()
+            .into()
+    }
+
+    async fn funded_storage() -> cnidarium::TempStorage {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        let fee_asset: asset::Denom = "nria".parse().unwrap();
+        state
+            .put_account_balance(&SigningKey::from([1; 32]).address_bytes(), &fee_asset, 10)
+            .unwrap();
+        execution_service::commit_state_delta(&storage, state)
+            .await
+            .unwrap();
+        storage
+    }
+
+    #[tokio::test]
+    async fn submitted_transaction_is_forwarded_to_backend() {
+        let storage = funded_storage().await;
+        let backend = Arc::new(MockBackend::default());
+        let tracker = TransactionTracker::new();
+
+        let reply = handle_submit_transaction(
+            backend.clone(),
+            encoded_transaction(0),
+            (*storage).clone(),
+            Mempool::new(CHAIN_ID.to_string()),
+            tracker.clone(),
+        )
+        .await
+        .unwrap()
+        .into_response();
+
+        assert_eq!(reply.status(), warp::http::StatusCode::OK);
+        let submitted = backend.submitted.lock().unwrap().clone();
+        assert_eq!(submitted, vec![encoded_transaction(0)]);
+        let status = tracker
+            .status(
+                &crate::receipts::transaction_hash(&submitted[0]),
+                &storage.latest_snapshot(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(status, Some(TransactionStatus::Submitted));
+    }
+
+    #[tokio::test]
+    async fn unavailable_backend_is_reported() {
+        let storage = funded_storage().await;
+        let backend = Arc::new(MockBackend::default());
+        backend
+            .unavailable
+            .store(true, std::sync::atomic::Ordering::Relaxed);
+
+        let Err(rejection) = handle_submit_transaction(
+            backend,
+            encoded_transaction(0),
+            (*storage).clone(),
+            Mempool::new(CHAIN_ID.to_string()),
+            TransactionTracker::new(),
+        )
+        .await
+        else {
+            panic!("submission to an unavailable backend must be rejected");
+        };
+        assert!(matches!(
+            rejection.find::<RestError>(),
+            Some(RestError::ServiceUnavailable(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn dropped_submission_releases_its_nonce() {
+        let storage = funded_storage().await;
+        let backend = Arc::new(MockBackend::default());
+        backend
+            .queue
+            .store(true, std::sync::atomic::Ordering::Relaxed);
+        let mempool = Mempool::new(CHAIN_ID.to_string());
+        let tracker = TransactionTracker::new();
+
+        let reply = handle_submit_transaction(
+            backend.clone(),
+            encoded_transaction(0),
+            (*storage).clone(),
+            mempool.clone(),
+            tracker.clone(),
+        )
+        .await
+        .unwrap()
+        .into_response();
+        assert_eq!(reply.status(), warp::http::StatusCode::ACCEPTED);
+
+        let on_dropped = backend.queued.lock().unwrap().pop().unwrap();
+        on_dropped("composer rejected submission".to_string());
+        let status = tracker
+            .status(
+                &crate::receipts::transaction_hash(&encoded_transaction(0)),
+                &storage.latest_snapshot(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(status, Some(TransactionStatus::Dropped));
+
+        // the nonce of the dropped transaction can be used again
+        handle_submit_transaction(
+            backend,
+            encoded_transaction(0),
+            (*storage).clone(),
+            mempool,
+            tracker,
+        )
+        .await
+        .unwrap();
+    }
+
+    #[test]
+    fn client_ip_is_only_forwarded_if_trusted() {
+        let remote = Some(SocketAddr::from(([10, 0, 0, 1], 4000)));
+        let forwarded_for = Some("1.1.1.1, 10.0.0.2");
+
+        assert_eq!(
+            client_ip(remote, forwarded_for, false),
+            Some(IpAddr::from([10, 0, 0, 1])),
+        );
+        // earlier entries are set by the client, so only the last one is used
+        assert_eq!(
+            client_ip(remote, forwarded_for, true),
+            Some(IpAddr::from([10, 0, 0, 2])),
+        );
+        assert_eq!(
+            client_ip(remote, None, true),
+            Some(IpAddr::from([10, 0, 0, 1])),
+        );
+    }
+
+    #[test]
+    fn rejected_submission_is_a_bad_gateway() {
+        assert!(matches!(
+            RestError::from(SubmitError::Rejected("invalid".to_string())),
+            RestError::BadGateway(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn storage_of_blocks_without_state_roots_is_rejected() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let genesis = GenesisAppState {
+            rollup_name: CHAIN_ID.to_string(),
+            ..GenesisAppState::default()
+        };
+        init_genesis_state(&storage, &genesis).await.unwrap();
+        // initializing again leaves the storage as it is
+        init_genesis_state(&storage, &genesis).await.unwrap();
+
+        // older versions kept the commitment state in the verifiable state
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_raw(storage::keys::COMMITMENT_STATE.to_string(), vec![0]);
+        execution_service::commit_state_delta(&storage, state)
+            .await
+            .unwrap();
+        init_genesis_state(&storage, &genesis).await.unwrap_err();
+    }
+}
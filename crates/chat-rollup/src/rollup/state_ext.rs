@@ -1,15 +1,18 @@
-use astria_core::execution::v1::Block;
 use astria_eyre::{
     anyhow_to_eyre,
     eyre::{bail, Result, WrapErr as _},
 };
 use async_trait::async_trait;
 use cnidarium::{StateRead, StateWrite};
+use rollup_core::genesis::v1::{BlockParams, ComputeParams};
 use tracing::{info, instrument};
 
 use self::storage::CommitmentStateHeight;
 
-use super::storage::{self, keys};
+use super::{
+    block::BlockHeader,
+    storage::{self, keys},
+};
 use crate::storage::StoredValue;
 
 #[async_trait]
@@ -29,6 +32,58 @@ pub(crate) trait StateReadExt: StateRead {
             .context("invalid block height bytes")
     }
 
+    /// Returns the timestamp of the block being executed, or of the last executed block outside
+    /// of execution.
+    #[instrument(skip_all)]
+    async fn get_block_timestamp(&self) -> Result<pbjson_types::Timestamp> {
+        let Some(bytes) = self
+            .get_raw(keys::BLOCK_TIMESTAMP)
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed to read raw block_timestamp from state")?
+        else {
+            bail!("block timestamp not found state");
+        };
+        StoredValue::deserialize(&bytes)
+            .and_then(|value| {
+                storage::BlockTimestamp::try_from(value).map(pbjson_types::Timestamp::from)
+            })
+            .context("invalid block timestamp bytes")
+    }
+
+    /// Returns the limits on the resources a block can use. No limits apply if none were set.
+    #[instrument(skip_all)]
+    async fn get_block_params(&self) -> Result<BlockParams> {
+        let Some(bytes) = self
+            .get_raw(keys::BLOCK_PARAMS)
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed to read raw block params from state")?
+        else {
+            return Ok(BlockParams::default());
+        };
+        StoredValue::deserialize(&bytes)
+            .and_then(|value| storage::BlockParams::try_from(value).map(BlockParams::from))
+            .context("invalid block params bytes")
+    }
+
+    /// Returns the metering of compute units used by actions. Actions are not metered if none was
+    /// set.
+    #[instrument(skip_all)]
+    async fn get_compute_params(&self) -> Result<ComputeParams> {
+        let Some(bytes) = self
+            .get_raw(keys::COMPUTE_PARAMS)
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed to read raw compute params from state")?
+        else {
+            return Ok(ComputeParams::default());
+        };
+        StoredValue::deserialize(&bytes)
+            .and_then(|value| storage::ComputeParams::try_from(value).map(ComputeParams::from))
+            .context("invalid compute params bytes")
+    }
+
     #[instrument(skip_all)]
     async fn get_block_hash(&self) -> Result<String> {
         let Some(bytes) = self
@@ -46,23 +101,27 @@ pub(crate) trait StateReadExt: StateRead {
     }
 
     #[instrument(skip_all)]
-    async fn get_block(&self, height: u32) -> Result<Block> {
+    async fn get_block_header(&self, height: u32) -> Result<BlockHeader> {
         let Some(bytes) = self
             .get_raw(&keys::block(height))
             .await
             .map_err(anyhow_to_eyre)
-            .wrap_err("failed to read raw block_height from state")?
+            .wrap_err("failed to read raw block header from state")?
         else {
-            bail!("block height not found state");
+            bail!("block header not found state");
         };
         StoredValue::deserialize(&bytes)
-            .and_then(|value| storage::Block::try_from(value).map(Block::from))
-            .context("invalid block height bytes")
+            .and_then(|value| storage::BlockHeader::try_from(value).map(BlockHeader::from))
+            .context("invalid block header bytes")
     }
 
+    /// Returns the heights of the soft and firm blocks and the base celestia height.
+    ///
+    /// The commitment state is updated outside of block execution, so it is kept out of the
+    /// verifiable state to leave its root at the state root of the latest block.
     async fn get_commitment_state(&self) -> Result<CommitmentStateHeight> {
         let Some(bytes) = self
-            .get_raw(keys::COMMITMENT_STATE)
+            .nonverifiable_get_raw(keys::COMMITMENT_STATE.as_bytes())
             .await
             .map_err(anyhow_to_eyre)
             .wrap_err("failed to read raw commitment state from state")?
@@ -75,6 +134,16 @@ pub(crate) trait StateReadExt: StateRead {
             .context("invalid commitment state bytes")
     }
 
+    /// Returns whether the state holds a commitment state in its verifiable part, where it was
+    /// kept before blocks committed to their state roots.
+    async fn has_legacy_commitment_state(&self) -> Result<bool> {
+        self.get_raw(keys::COMMITMENT_STATE)
+            .await
+            .map(|bytes| bytes.is_some())
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed to read raw legacy commitment state from state")
+    }
+
     async fn get_storage_version_by_height(&self, height: u64) -> Result<u64> {
         let Some(bytes) = self
             .get_raw(&keys::storage_version_by_height(height))
@@ -88,6 +157,21 @@ pub(crate) trait StateReadExt: StateRead {
             .and_then(|value| storage::StorageVersion::try_from(value).map(u64::from))
             .context("invalid storage version bytes")
     }
+
+    #[instrument(skip_all)]
+    async fn get_block_state_root(&self, height: u32) -> Result<[u8; 32]> {
+        let Some(bytes) = self
+            .get_raw(&keys::state_root(height))
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed to read raw block state root from state")?
+        else {
+            bail!("block state root not found state");
+        };
+        StoredValue::deserialize(&bytes)
+            .and_then(|value| storage::StateRoot::try_from(value).map(<[u8; 32]>::from))
+            .context("invalid block state root bytes")
+    }
 }
 
 impl<T: StateRead> StateReadExt for T {}
@@ -103,6 +187,33 @@ pub(crate) trait StateWriteExt: StateWrite {
         Ok(())
     }
 
+    #[instrument(skip_all)]
+    fn put_block_timestamp(&mut self, timestamp: pbjson_types::Timestamp) -> Result<()> {
+        let bytes = StoredValue::from(storage::BlockTimestamp::from(timestamp))
+            .serialize()
+            .context("failed to serialize block timestamp")?;
+        self.put_raw(keys::BLOCK_TIMESTAMP.to_string(), bytes);
+        Ok(())
+    }
+
+    #[instrument(skip_all)]
+    fn put_block_params(&mut self, params: &BlockParams) -> Result<()> {
+        let bytes = StoredValue::from(storage::BlockParams::from(params))
+            .serialize()
+            .context("failed to serialize block params")?;
+        self.put_raw(keys::BLOCK_PARAMS.to_string(), bytes);
+        Ok(())
+    }
+
+    #[instrument(skip_all)]
+    fn put_compute_params(&mut self, params: &ComputeParams) -> Result<()> {
+        let bytes = StoredValue::from(storage::ComputeParams::from(params))
+            .serialize()
+            .context("failed to serialize compute params")?;
+        self.put_raw(keys::COMPUTE_PARAMS.to_string(), bytes);
+        Ok(())
+    }
+
     #[instrument(skip_all)]
     fn put_commitment_state(&mut self, soft: u32, firm: u32, celestia: u32) -> Result<()> {
         let bytes = StoredValue::from(storage::CommitmentState::from(CommitmentStateHeight {
@@ -113,16 +224,37 @@ pub(crate) trait StateWriteExt: StateWrite {
         .serialize()
         .context("failed to serialize commitment state")?;
         info!("bytes: {:?}", bytes);
-        self.put_raw(keys::COMMITMENT_STATE.to_string(), bytes);
+        self.nonverifiable_put_raw(keys::COMMITMENT_STATE.as_bytes().to_vec(), bytes);
+        Ok(())
+    }
+
+    #[instrument(skip_all)]
+    fn put_block_header(&mut self, header: &BlockHeader) -> Result<()> {
+        let bytes = StoredValue::from(storage::BlockHeader::from(header))
+            .serialize()
+            .context("failed to serialize block header")?;
+        self.put_raw(keys::block(header.number), bytes);
         Ok(())
     }
 
+    /// Writes the root hash of the application state that resulted from executing the block at
+    /// `height`. It is written together with the block after it, as it is only known once the
+    /// state of the block at `height` is committed.
     #[instrument(skip_all)]
-    fn put_block(&mut self, block: Block, height: u32) -> Result<()> {
-        let bytes = StoredValue::from(storage::Block::from(block))
+    fn put_block_state_root(&mut self, height: u32, state_root: [u8; 32]) -> Result<()> {
+        let bytes = StoredValue::from(storage::StateRoot::from(state_root))
             .serialize()
-            .context("failed to serialize block")?;
-        self.put_raw(keys::block(height), bytes);
+            .context("failed to serialize block state root")?;
+        self.put_raw(keys::state_root(height), bytes);
+        Ok(())
+    }
+
+    #[instrument(skip_all)]
+    fn put_storage_version_by_height(&mut self, height: u64, version: u64) -> Result<()> {
+        let bytes = StoredValue::from(storage::StorageVersion::from(version))
+            .serialize()
+            .context("failed to serialize storage version")?;
+        self.put_raw(keys::storage_version_by_height(height), bytes);
         Ok(())
     }
 }
@@ -184,4 +316,84 @@ mod tests {
         assert_eq!(0, commit.firm);
         assert_eq!(2, commit.celestia);
     }
+
+    #[tokio::test]
+    async fn put_and_get_block_state_root() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        // doesn't exist at first
+        let _ = state
+            .get_block_state_root(1)
+            .await
+            .expect_err("no state root should exist at first");
+
+        state.put_block_state_root(1, [1; 32]).unwrap();
+        state.put_block_state_root(2, [2; 32]).unwrap();
+        assert_eq!([1; 32], state.get_block_state_root(1).await.unwrap());
+        assert_eq!([2; 32], state.get_block_state_root(2).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn put_and_get_block_timestamp() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let timestamp = pbjson_types::Timestamp {
+            seconds: 10,
+            nanos: 5,
+        };
+        state.put_block_timestamp(timestamp.clone()).unwrap();
+        assert_eq!(timestamp, state.get_block_timestamp().await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn block_params_default_to_no_limits() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        assert_eq!(
+            BlockParams::default(),
+            state.get_block_params().await.unwrap()
+        );
+        let params = BlockParams {
+            max_transactions: 1,
+            max_transaction_bytes: 2,
+            max_text_bytes: 3,
+            max_deposits: 4,
+        };
+        state.put_block_params(&params).unwrap();
+        assert_eq!(params, state.get_block_params().await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn compute_params_default_to_no_metering() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        assert_eq!(
+            ComputeParams::default(),
+            state.get_compute_params().await.unwrap()
+        );
+        let params = ComputeParams {
+            max_units_per_transaction: 1,
+            unit_price: 2,
+        };
+        state.put_compute_params(&params).unwrap();
+        assert_eq!(params, state.get_compute_params().await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn put_and_get_storage_version_by_height() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        state.put_storage_version_by_height(3, 7).unwrap();
+        assert_eq!(7, state.get_storage_version_by_height(3).await.unwrap());
+    }
 }
@@ -3,8 +3,10 @@
 pub(in crate::rollup) const BLOCK: &str = "app/block";
 pub(in crate::rollup) const BLOCK_HASH: &str = "app/block_hash";
 pub(in crate::rollup) const BLOCK_HEIGHT: &str = "app/block_height";
+pub(in crate::rollup) const BLOCK_PARAMS: &str = "app/block_params";
 pub(in crate::rollup) const BLOCK_TIMESTAMP: &str = "app/block_timestamp";
 pub(in crate::rollup) const COMMITMENT_STATE: &str = "app/commitment_state";
+pub(in crate::rollup) const COMPUTE_PARAMS: &str = "app/compute_params";
 
 pub(in crate::rollup) fn storage_version_by_height(height: u64) -> String {
     format!("app/storage_version/{height}")
@@ -13,3 +15,7 @@ pub(in crate::rollup) fn storage_version_by_height(height: u64) -> String {
 pub(in crate::rollup) fn block(height: u32) -> String {
     format!("app/block/{height}")
 }
+
+pub(in crate::rollup) fn state_root(height: u32) -> String {
+    format!("app/state_root/{height}")
+}
@@ -1,12 +1,14 @@
 pub(super) mod keys;
 mod values;
 
-pub(super) use values::Block;
 pub(super) use values::BlockHash;
+pub(super) use values::BlockHeader;
 pub(super) use values::BlockHeight;
-#[allow(unused_imports)] // for BlockTimestamp
+pub(super) use values::BlockParams;
 pub(super) use values::BlockTimestamp;
 pub(super) use values::CommitmentState;
 pub(super) use values::CommitmentStateHeight;
+pub(super) use values::ComputeParams;
+pub(super) use values::StateRoot;
 pub(super) use values::StorageVersion;
 pub(crate) use values::Value;
@@ -0,0 +1,89 @@
+use super::{BlockTimestamp, Value, ValueImpl};
+use crate::rollup::block::BlockHeader as DomainBlockHeader;
+
+use astria_eyre::eyre::bail;
+use borsh::io::{Read, Write};
+use borsh::{BorshDeserialize, BorshSerialize};
+use bytes::Bytes;
+
+#[derive(Debug)]
+pub(in crate::rollup) struct BlockHeader(DomainBlockHeader);
+
+impl From<&DomainBlockHeader> for BlockHeader {
+    fn from(header: &DomainBlockHeader) -> Self {
+        BlockHeader(header.clone())
+    }
+}
+
+impl From<BlockHeader> for DomainBlockHeader {
+    fn from(header: BlockHeader) -> Self {
+        header.0
+    }
+}
+
+impl BorshSerialize for BlockHeader {
+    fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        BorshSerialize::serialize(&self.0.number, writer)?;
+        BorshSerialize::serialize(&self.0.parent_block_hash.to_vec(), writer)?;
+        BorshSerialize::serialize(&self.0.transactions_root, writer)?;
+        BorshSerialize::serialize(&self.0.deposits_root, writer)?;
+        BorshSerialize::serialize(&BlockTimestamp::from(self.0.timestamp.clone()), writer)
+    }
+}
+
+impl BorshDeserialize for BlockHeader {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> std::io::Result<Self> {
+        let number = u32::deserialize_reader(reader)?;
+        let parent_block_hash = Vec::<u8>::deserialize_reader(reader)?;
+        let transactions_root = <[u8; 32]>::deserialize_reader(reader)?;
+        let deposits_root = <[u8; 32]>::deserialize_reader(reader)?;
+        let timestamp = BlockTimestamp::deserialize_reader(reader)?;
+        Ok(BlockHeader(DomainBlockHeader {
+            number,
+            parent_block_hash: Bytes::from(parent_block_hash),
+            transactions_root,
+            deposits_root,
+            timestamp: timestamp.into(),
+        }))
+    }
+}
+
+impl From<BlockHeader> for crate::storage::StoredValue<'_> {
+    fn from(header: BlockHeader) -> Self {
+        crate::storage::StoredValue::Rollup(Value(ValueImpl::BlockHeader(header)))
+    }
+}
+
+impl TryFrom<crate::storage::StoredValue<'_>> for BlockHeader {
+    type Error = astria_eyre::eyre::Error;
+
+    fn try_from(value: crate::storage::StoredValue) -> Result<Self, Self::Error> {
+        let crate::storage::StoredValue::Rollup(Value(ValueImpl::BlockHeader(header))) = value
+        else {
+            bail!("app stored value type mismatch: expected block header, found {value:?}");
+        };
+        Ok(header)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization_round_trip() {
+        let header = BlockHeader(DomainBlockHeader {
+            number: 7,
+            parent_block_hash: Bytes::from_static(&[1; 32]),
+            transactions_root: [2; 32],
+            deposits_root: [3; 32],
+            timestamp: pbjson_types::Timestamp {
+                seconds: 1_000_000_000,
+                nanos: 56,
+            },
+        });
+        let serialized = borsh::to_vec(&header).unwrap();
+        let deserialized: BlockHeader = borsh::from_slice(&serialized).unwrap();
+        assert_eq!(header.0, deserialized.0);
+    }
+}
@@ -0,0 +1,53 @@
+use astria_eyre::eyre::bail;
+use borsh::{BorshDeserialize, BorshSerialize};
+use rollup_core::genesis::v1::BlockParams as DomainBlockParams;
+
+use super::{Value, ValueImpl};
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(in crate::rollup) struct BlockParams {
+    max_transactions: u32,
+    max_transaction_bytes: u64,
+    max_text_bytes: u64,
+    max_deposits: u32,
+}
+
+impl From<&DomainBlockParams> for BlockParams {
+    fn from(params: &DomainBlockParams) -> Self {
+        Self {
+            max_transactions: params.max_transactions,
+            max_transaction_bytes: params.max_transaction_bytes,
+            max_text_bytes: params.max_text_bytes,
+            max_deposits: params.max_deposits,
+        }
+    }
+}
+
+impl From<BlockParams> for DomainBlockParams {
+    fn from(params: BlockParams) -> Self {
+        Self {
+            max_transactions: params.max_transactions,
+            max_transaction_bytes: params.max_transaction_bytes,
+            max_text_bytes: params.max_text_bytes,
+            max_deposits: params.max_deposits,
+        }
+    }
+}
+
+impl From<BlockParams> for crate::storage::StoredValue<'_> {
+    fn from(params: BlockParams) -> Self {
+        crate::storage::StoredValue::Rollup(Value(ValueImpl::BlockParams(params)))
+    }
+}
+
+impl TryFrom<crate::storage::StoredValue<'_>> for BlockParams {
+    type Error = astria_eyre::eyre::Error;
+
+    fn try_from(value: crate::storage::StoredValue) -> Result<Self, Self::Error> {
+        let crate::storage::StoredValue::Rollup(Value(ValueImpl::BlockParams(params))) = value
+        else {
+            bail!("app stored value type mismatch: expected block params, found {value:?}");
+        };
+        Ok(params)
+    }
+}
@@ -0,0 +1,47 @@
+use astria_eyre::eyre::bail;
+use borsh::{BorshDeserialize, BorshSerialize};
+use rollup_core::genesis::v1::ComputeParams as DomainComputeParams;
+
+use super::{Value, ValueImpl};
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(in crate::rollup) struct ComputeParams {
+    max_units_per_transaction: u64,
+    unit_price: u64,
+}
+
+impl From<&DomainComputeParams> for ComputeParams {
+    fn from(params: &DomainComputeParams) -> Self {
+        Self {
+            max_units_per_transaction: params.max_units_per_transaction,
+            unit_price: params.unit_price,
+        }
+    }
+}
+
+impl From<ComputeParams> for DomainComputeParams {
+    fn from(params: ComputeParams) -> Self {
+        Self {
+            max_units_per_transaction: params.max_units_per_transaction,
+            unit_price: params.unit_price,
+        }
+    }
+}
+
+impl From<ComputeParams> for crate::storage::StoredValue<'_> {
+    fn from(params: ComputeParams) -> Self {
+        crate::storage::StoredValue::Rollup(Value(ValueImpl::ComputeParams(params)))
+    }
+}
+
+impl TryFrom<crate::storage::StoredValue<'_>> for ComputeParams {
+    type Error = astria_eyre::eyre::Error;
+
+    fn try_from(value: crate::storage::StoredValue) -> Result<Self, Self::Error> {
+        let crate::storage::StoredValue::Rollup(Value(ValueImpl::ComputeParams(params))) = value
+        else {
+            bail!("app stored value type mismatch: expected compute params, found {value:?}");
+        };
+        Ok(params)
+    }
+}
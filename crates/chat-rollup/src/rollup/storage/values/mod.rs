@@ -1,17 +1,23 @@
-mod block;
 mod block_hash;
+mod block_header;
 mod block_height;
+mod block_params;
 mod block_timestamp;
 mod commitment_state;
+mod compute_params;
+mod state_root;
 mod storage_version;
 use borsh::{BorshDeserialize, BorshSerialize};
 
-pub(in crate::rollup) use self::block::Block;
 pub(in crate::rollup) use self::block_hash::BlockHash;
+pub(in crate::rollup) use self::block_header::BlockHeader;
 pub(in crate::rollup) use self::block_height::BlockHeight;
+pub(in crate::rollup) use self::block_params::BlockParams;
 pub(in crate::rollup) use self::block_timestamp::BlockTimestamp;
 pub(in crate::rollup) use self::commitment_state::CommitmentState;
 pub(in crate::rollup) use self::commitment_state::CommitmentStateHeight;
+pub(in crate::rollup) use self::compute_params::ComputeParams;
+pub(in crate::rollup) use self::state_root::StateRoot;
 pub(in crate::rollup) use self::storage_version::StorageVersion;
 
 #[derive(Debug, BorshSerialize, BorshDeserialize)]
@@ -19,11 +25,14 @@ pub(crate) struct Value(ValueImpl);
 
 #[derive(Debug, BorshSerialize, BorshDeserialize)]
 enum ValueImpl {
-    Block(Block),
+    BlockHeader(BlockHeader),
     BlockHeight(BlockHeight),
     BlockHash(BlockHash),
     CommitmentState(CommitmentState),
     CommitmentStateHeight(CommitmentStateHeight),
     BlockTimestamp(BlockTimestamp),
     StorageVersion(StorageVersion),
+    StateRoot(StateRoot),
+    BlockParams(BlockParams),
+    ComputeParams(ComputeParams),
 }
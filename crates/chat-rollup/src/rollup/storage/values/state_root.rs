@@ -0,0 +1,37 @@
+use astria_eyre::eyre::bail;
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use super::{Value, ValueImpl};
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(in crate::rollup) struct StateRoot([u8; 32]);
+
+impl From<[u8; 32]> for StateRoot {
+    fn from(state_root: [u8; 32]) -> Self {
+        StateRoot(state_root)
+    }
+}
+
+impl From<StateRoot> for [u8; 32] {
+    fn from(state_root: StateRoot) -> Self {
+        state_root.0
+    }
+}
+
+impl From<StateRoot> for crate::storage::StoredValue<'_> {
+    fn from(state_root: StateRoot) -> Self {
+        crate::storage::StoredValue::Rollup(Value(ValueImpl::StateRoot(state_root)))
+    }
+}
+
+impl TryFrom<crate::storage::StoredValue<'_>> for StateRoot {
+    type Error = astria_eyre::eyre::Error;
+
+    fn try_from(value: crate::storage::StoredValue) -> Result<Self, Self::Error> {
+        let crate::storage::StoredValue::Rollup(Value(ValueImpl::StateRoot(state_root))) = value
+        else {
+            bail!("app stored value type mismatch: expected state root, found {value:?}");
+        };
+        Ok(state_root)
+    }
+}
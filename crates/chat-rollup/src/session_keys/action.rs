@@ -0,0 +1,49 @@
+use astria_eyre::eyre::{ensure, Result, WrapErr as _};
+use cnidarium::StateWrite;
+use rollup_core::transaction::v1::action::{ActionKind, GrantSessionKey, RevokeSessionKey};
+
+use crate::{
+    accounts::AddressBytes,
+    session_keys::{SessionKey, StateWriteExt as _},
+};
+
+/// Grants the session key of `action` to `from`, replacing any earlier grant of the same key.
+pub(crate) fn execute_grant_session_key<S, TAddress>(
+    action: &GrantSessionKey,
+    from: &TAddress,
+    mut state: S,
+) -> Result<()>
+where
+    S: StateWrite,
+    TAddress: AddressBytes,
+{
+    ensure!(
+        !action.actions.iter().any(|kind| matches!(
+            kind,
+            ActionKind::GrantSessionKey | ActionKind::RevokeSessionKey
+        )),
+        "session keys cannot be permitted to grant or revoke session keys",
+    );
+    state
+        .put_session_key(
+            from,
+            action.verification_key.address_bytes(),
+            &SessionKey {
+                actions: action.actions.clone(),
+                expires_at_height: action.expires_at_height,
+            },
+        )
+        .wrap_err("failed to put session key")
+}
+
+/// Revokes the session key of `action` granted by `from`.
+pub(crate) fn execute_revoke_session_key<S, TAddress>(
+    action: &RevokeSessionKey,
+    from: &TAddress,
+    mut state: S,
+) where
+    S: StateWrite,
+    TAddress: AddressBytes,
+{
+    state.delete_session_key(from, action.verification_key.address_bytes());
+}
@@ -0,0 +1,22 @@
+//! Session keys: short-lived keys signing transactions on behalf of another account.
+//!
+//! An account grants a key the right to sign transactions containing only certain kinds of
+//! actions until an expiry height with a `GrantSessionKey` action, and revokes it with a
+//! `RevokeSessionKey` action. Transactions signed by a session key name the granting account,
+//! and are executed as if the granter had signed them.
+
+pub(crate) mod action;
+pub(crate) mod state_ext;
+pub(crate) mod storage;
+
+use rollup_core::transaction::v1::action::ActionKind;
+pub(crate) use state_ext::{StateReadExt, StateWriteExt};
+
+/// The rights an account granted to a session key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct SessionKey {
+    /// The kinds of actions the session key may sign.
+    pub(crate) actions: Vec<ActionKind>,
+    /// The last rollup height at which the session key can sign.
+    pub(crate) expires_at_height: u32,
+}
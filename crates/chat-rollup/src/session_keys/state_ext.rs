@@ -0,0 +1,131 @@
+use astria_eyre::{
+    anyhow_to_eyre,
+    eyre::{Result, WrapErr as _},
+};
+use async_trait::async_trait;
+use cnidarium::{StateRead, StateWrite};
+use tracing::instrument;
+
+use super::{
+    storage::{self, keys},
+    SessionKey,
+};
+use crate::{accounts::AddressBytes, storage::StoredValue};
+
+#[async_trait]
+pub(crate) trait StateReadExt: StateRead {
+    /// Returns the rights `granter` granted to the key with address `session_key`, or `None` if
+    /// it granted none.
+    ///
+    /// Expired session keys are returned as well.
+    #[instrument(skip_all, fields(
+        granter = %granter.display_address(),
+        session_key = %session_key.display_address(),
+    ), err)]
+    async fn get_session_key<TGranter, TSessionKey>(
+        &self,
+        granter: &TGranter,
+        session_key: &TSessionKey,
+    ) -> Result<Option<SessionKey>>
+    where
+        TGranter: AddressBytes,
+        TSessionKey: AddressBytes,
+    {
+        let Some(bytes) = self
+            .get_raw(&keys::session_key(granter, session_key))
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading raw session key from state")?
+        else {
+            return Ok(None);
+        };
+        StoredValue::deserialize(&bytes)
+            .and_then(storage::SessionKey::try_from)
+            .and_then(SessionKey::try_from)
+            .map(Some)
+            .wrap_err("invalid session key bytes")
+    }
+}
+
+impl<T: StateRead + ?Sized> StateReadExt for T {}
+
+#[async_trait]
+pub(crate) trait StateWriteExt: StateWrite {
+    #[instrument(skip_all, fields(
+        granter = %granter.display_address(),
+        session_key = %session_key_address.display_address(),
+    ), err)]
+    fn put_session_key<TGranter, TSessionKey>(
+        &mut self,
+        granter: &TGranter,
+        session_key_address: &TSessionKey,
+        session_key: &SessionKey,
+    ) -> Result<()>
+    where
+        TGranter: AddressBytes,
+        TSessionKey: AddressBytes,
+    {
+        let bytes = StoredValue::from(storage::SessionKey::from(session_key))
+            .serialize()
+            .wrap_err("failed to serialize session key")?;
+        self.put_raw(keys::session_key(granter, session_key_address), bytes);
+        Ok(())
+    }
+
+    #[instrument(skip_all, fields(
+        granter = %granter.display_address(),
+        session_key = %session_key.display_address(),
+    ))]
+    fn delete_session_key<TGranter, TSessionKey>(
+        &mut self,
+        granter: &TGranter,
+        session_key: &TSessionKey,
+    ) where
+        TGranter: AddressBytes,
+        TSessionKey: AddressBytes,
+    {
+        self.delete(keys::session_key(granter, session_key));
+    }
+}
+
+impl<T: StateWrite> StateWriteExt for T {}
+
+#[cfg(test)]
+mod tests {
+    use cnidarium::StateDelta;
+    use rollup_core::transaction::v1::action::ActionKind;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn put_get_and_delete_session_key() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        let session_key = SessionKey {
+            actions: vec![ActionKind::SendText],
+            expires_at_height: 10,
+        };
+
+        assert_eq!(
+            state.get_session_key(&[1u8; 20], &[2u8; 20]).await.unwrap(),
+            None
+        );
+        state
+            .put_session_key(&[1u8; 20], &[2u8; 20], &session_key)
+            .unwrap();
+        assert_eq!(
+            state.get_session_key(&[1u8; 20], &[2u8; 20]).await.unwrap(),
+            Some(session_key)
+        );
+        assert_eq!(
+            state.get_session_key(&[2u8; 20], &[1u8; 20]).await.unwrap(),
+            None
+        );
+
+        state.delete_session_key(&[1u8; 20], &[2u8; 20]);
+        assert_eq!(
+            state.get_session_key(&[1u8; 20], &[2u8; 20]).await.unwrap(),
+            None
+        );
+    }
+}
@@ -0,0 +1,38 @@
+use crate::{accounts::AddressBytes, storage::keys::AccountPrefixer};
+
+const COMPONENT_PREFIX: &str = "session_keys/";
+
+/// Example: `session_keys/gGhH....zZ4=/aAbB....yY8=`.
+///                       |base64 chars||base64 chars|
+pub(in crate::session_keys) fn session_key<TGranter, TSessionKey>(
+    granter: &TGranter,
+    session_key: &TSessionKey,
+) -> String
+where
+    TGranter: AddressBytes,
+    TSessionKey: AddressBytes,
+{
+    format!(
+        "{}/{}",
+        AccountPrefixer::new(COMPONENT_PREFIX, granter),
+        AccountPrefixer::new("", session_key),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keys_have_component_prefix() {
+        assert!(session_key(&[1u8; 20], &[2u8; 20]).starts_with(COMPONENT_PREFIX));
+    }
+
+    #[test]
+    fn keys_differ_between_granter_and_session_key() {
+        assert_ne!(
+            session_key(&[1u8; 20], &[2u8; 20]),
+            session_key(&[2u8; 20], &[1u8; 20]),
+        );
+    }
+}
@@ -0,0 +1,66 @@
+use astria_eyre::eyre::{bail, Result, WrapErr as _};
+use borsh::{BorshDeserialize, BorshSerialize};
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(crate) struct Value(ValueImpl);
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+enum ValueImpl {
+    SessionKey(SessionKey),
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(in crate::session_keys) struct SessionKey {
+    /// The names of the permitted actions.
+    actions: Vec<String>,
+    expires_at_height: u32,
+}
+
+impl From<&crate::session_keys::SessionKey> for SessionKey {
+    fn from(session_key: &crate::session_keys::SessionKey) -> Self {
+        Self {
+            actions: session_key
+                .actions
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            expires_at_height: session_key.expires_at_height,
+        }
+    }
+}
+
+impl TryFrom<SessionKey> for crate::session_keys::SessionKey {
+    type Error = astria_eyre::eyre::Error;
+
+    fn try_from(session_key: SessionKey) -> Result<Self> {
+        let actions = session_key
+            .actions
+            .iter()
+            .map(|action| action.parse())
+            .collect::<Result<_, _>>()
+            .wrap_err("invalid action kind")?;
+        Ok(Self {
+            actions,
+            expires_at_height: session_key.expires_at_height,
+        })
+    }
+}
+
+impl From<SessionKey> for crate::storage::StoredValue<'_> {
+    fn from(session_key: SessionKey) -> Self {
+        crate::storage::StoredValue::SessionKey(Value(ValueImpl::SessionKey(session_key)))
+    }
+}
+
+impl TryFrom<crate::storage::StoredValue<'_>> for SessionKey {
+    type Error = astria_eyre::eyre::Error;
+
+    fn try_from(value: crate::storage::StoredValue) -> Result<Self, Self::Error> {
+        let crate::storage::StoredValue::SessionKey(Value(ValueImpl::SessionKey(session_key))) =
+            value
+        else {
+            bail!("session keys stored value type mismatch: expected session key, found {value:?}");
+        };
+        Ok(session_key)
+    }
+}
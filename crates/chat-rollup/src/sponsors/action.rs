@@ -0,0 +1,20 @@
+use astria_eyre::eyre::{Result, WrapErr as _};
+use cnidarium::StateWrite;
+use rollup_core::transaction::v1::action::SetSponsorLimit;
+
+use crate::{accounts::AddressBytes, sponsors::StateWriteExt as _};
+
+/// Sets the limit `from` spends on fees of transactions sent by `action.sponsored`.
+pub(crate) fn execute_set_sponsor_limit<S, TAddress>(
+    action: &SetSponsorLimit,
+    from: &TAddress,
+    mut state: S,
+) -> Result<()>
+where
+    S: StateWrite,
+    TAddress: AddressBytes,
+{
+    state
+        .put_sponsor_limit(from, &action.sponsored, &action.asset, action.limit)
+        .wrap_err("failed to put sponsor limit")
+}
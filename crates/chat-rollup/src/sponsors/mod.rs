@@ -0,0 +1,11 @@
+//! Fee sponsorship: accounts paying the fees of transactions sent by other accounts.
+//!
+//! A sponsor opts into paying for an account by setting a limit per fee asset with a
+//! `SetSponsorLimit` action. Every sponsored transaction spends its fees from that limit, and is
+//! rejected once the limit is used up.
+
+pub(crate) mod action;
+pub(crate) mod state_ext;
+pub(crate) mod storage;
+
+pub(crate) use state_ext::{StateReadExt, StateWriteExt};
@@ -0,0 +1,190 @@
+use std::{borrow::Cow, fmt::Display};
+
+use astria_core::primitive::v1::asset::IbcPrefixed;
+use astria_eyre::{
+    anyhow_to_eyre,
+    eyre::{OptionExt as _, Result, WrapErr as _},
+};
+use async_trait::async_trait;
+use cnidarium::{StateRead, StateWrite};
+use tracing::instrument;
+
+use super::storage::{self, keys};
+use crate::{accounts::AddressBytes, storage::StoredValue};
+
+#[async_trait]
+pub(crate) trait StateReadExt: StateRead {
+    /// Returns how much of `asset` `sponsor` still spends on fees of transactions sent by
+    /// `sponsored`. Zero if `sponsor` does not sponsor `sponsored`.
+    #[instrument(skip_all, fields(
+        sponsor = %sponsor.display_address(),
+        sponsored = %sponsored.display_address(),
+        %asset,
+    ), err)]
+    async fn get_sponsor_limit<'a, TSponsor, TSponsored, TAsset>(
+        &self,
+        sponsor: &TSponsor,
+        sponsored: &TSponsored,
+        asset: &'a TAsset,
+    ) -> Result<u128>
+    where
+        TSponsor: AddressBytes,
+        TSponsored: AddressBytes,
+        TAsset: Sync + Display,
+        &'a TAsset: Into<Cow<'a, IbcPrefixed>>,
+    {
+        let Some(bytes) = self
+            .get_raw(&keys::sponsor_limit(sponsor, sponsored, asset))
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading raw sponsor limit from state")?
+        else {
+            return Ok(0);
+        };
+        StoredValue::deserialize(&bytes)
+            .and_then(|value| storage::SponsorLimit::try_from(value).map(u128::from))
+            .wrap_err("invalid sponsor limit bytes")
+    }
+}
+
+impl<T: StateRead + ?Sized> StateReadExt for T {}
+
+#[async_trait]
+pub(crate) trait StateWriteExt: StateWrite {
+    #[instrument(skip_all, fields(
+        sponsor = %sponsor.display_address(),
+        sponsored = %sponsored.display_address(),
+        %asset,
+        limit,
+    ), err)]
+    fn put_sponsor_limit<'a, TSponsor, TSponsored, TAsset>(
+        &mut self,
+        sponsor: &TSponsor,
+        sponsored: &TSponsored,
+        asset: &'a TAsset,
+        limit: u128,
+    ) -> Result<()>
+    where
+        TSponsor: AddressBytes,
+        TSponsored: AddressBytes,
+        TAsset: Display,
+        &'a TAsset: Into<Cow<'a, IbcPrefixed>>,
+    {
+        let key = keys::sponsor_limit(sponsor, sponsored, asset);
+        if limit == 0 {
+            self.delete(key);
+            return Ok(());
+        }
+        let bytes = StoredValue::from(storage::SponsorLimit::from(limit))
+            .serialize()
+            .wrap_err("failed to serialize sponsor limit")?;
+        self.put_raw(key, bytes);
+        Ok(())
+    }
+
+    /// Spends `amount` of the limit `sponsor` set for `sponsored` in `asset`.
+    #[instrument(skip_all, fields(
+        sponsor = %sponsor.display_address(),
+        sponsored = %sponsored.display_address(),
+        %asset,
+        amount,
+    ))]
+    async fn decrease_sponsor_limit<'a, TSponsor, TSponsored, TAsset>(
+        &mut self,
+        sponsor: &TSponsor,
+        sponsored: &TSponsored,
+        asset: &'a TAsset,
+        amount: u128,
+    ) -> Result<()>
+    where
+        TSponsor: AddressBytes,
+        TSponsored: AddressBytes,
+        TAsset: Sync + Display,
+        &'a TAsset: Into<Cow<'a, IbcPrefixed>>,
+    {
+        let limit = self
+            .get_sponsor_limit(sponsor, sponsored, asset)
+            .await
+            .wrap_err("failed to get sponsor limit")?;
+        self.put_sponsor_limit(
+            sponsor,
+            sponsored,
+            asset,
+            limit
+                .checked_sub(amount)
+                .ok_or_eyre("fees exceed the limit set by the sponsor")?,
+        )
+        .wrap_err("failed to store updated sponsor limit in database")?;
+        Ok(())
+    }
+}
+
+impl<T: StateWrite> StateWriteExt for T {}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::primitive::v1::asset::Denom;
+    use cnidarium::StateDelta;
+
+    use super::*;
+
+    fn asset() -> Denom {
+        "nria".parse().unwrap()
+    }
+
+    #[tokio::test]
+    async fn sponsor_limit_uninitialized_is_zero() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let state = StateDelta::new(storage.latest_snapshot());
+
+        assert_eq!(
+            state
+                .get_sponsor_limit(&[1u8; 20], &[2u8; 20], &asset())
+                .await
+                .unwrap(),
+            0
+        );
+    }
+
+    #[tokio::test]
+    async fn sponsor_limit_is_per_sponsored_account() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+
+        state
+            .put_sponsor_limit(&[1u8; 20], &[2u8; 20], &asset(), 10)
+            .unwrap();
+        assert_eq!(
+            state
+                .get_sponsor_limit(&[1u8; 20], &[2u8; 20], &asset())
+                .await
+                .unwrap(),
+            10
+        );
+        assert_eq!(
+            state
+                .get_sponsor_limit(&[1u8; 20], &[3u8; 20], &asset())
+                .await
+                .unwrap(),
+            0
+        );
+    }
+
+    #[tokio::test]
+    async fn decrease_sponsor_limit_beyond_limit_fails() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+
+        state
+            .put_sponsor_limit(&[1u8; 20], &[2u8; 20], &asset(), 2)
+            .unwrap();
+        state
+            .decrease_sponsor_limit(&[1u8; 20], &[2u8; 20], &asset(), 2)
+            .await
+            .unwrap();
+        assert!(state
+            .decrease_sponsor_limit(&[1u8; 20], &[2u8; 20], &asset(), 1)
+            .await
+            .is_err());
+    }
+}
@@ -0,0 +1,52 @@
+use std::borrow::Cow;
+
+use astria_core::primitive::v1::asset::IbcPrefixed;
+
+use crate::{
+    accounts::AddressBytes,
+    storage::keys::{AccountPrefixer, Asset},
+};
+
+const COMPONENT_PREFIX: &str = "sponsors/";
+
+/// Example: `sponsors/gGhH....zZ4=/aAbB....yY8=/0202....0202`.
+///                   |base64 chars||base64 chars||64 hex chars|
+pub(in crate::sponsors) fn sponsor_limit<'a, TSponsor, TSponsored, TAsset>(
+    sponsor: &TSponsor,
+    sponsored: &TSponsored,
+    asset: &'a TAsset,
+) -> String
+where
+    TSponsor: AddressBytes,
+    TSponsored: AddressBytes,
+    &'a TAsset: Into<Cow<'a, IbcPrefixed>>,
+{
+    format!(
+        "{}/{}/{}",
+        AccountPrefixer::new(COMPONENT_PREFIX, sponsor),
+        AccountPrefixer::new("", sponsored),
+        Asset::from(asset)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::primitive::v1::asset::Denom;
+
+    use super::*;
+
+    #[test]
+    fn keys_have_component_prefix() {
+        let asset: Denom = "nria".parse().unwrap();
+        assert!(sponsor_limit(&[1u8; 20], &[2u8; 20], &asset).starts_with(COMPONENT_PREFIX));
+    }
+
+    #[test]
+    fn keys_differ_between_sponsor_and_sponsored() {
+        let asset: Denom = "nria".parse().unwrap();
+        assert_ne!(
+            sponsor_limit(&[1u8; 20], &[2u8; 20], &asset),
+            sponsor_limit(&[2u8; 20], &[1u8; 20], &asset),
+        );
+    }
+}
@@ -0,0 +1,5 @@
+pub(crate) mod keys;
+mod values;
+
+pub(super) use values::SponsorLimit;
+pub(crate) use values::Value;
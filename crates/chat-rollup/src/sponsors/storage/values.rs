@@ -0,0 +1,43 @@
+use astria_eyre::eyre::bail;
+use borsh::{BorshDeserialize, BorshSerialize};
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(crate) struct Value(ValueImpl);
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+enum ValueImpl {
+    SponsorLimit(SponsorLimit),
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(in crate::sponsors) struct SponsorLimit(u128);
+
+impl From<u128> for SponsorLimit {
+    fn from(limit: u128) -> Self {
+        SponsorLimit(limit)
+    }
+}
+
+impl From<SponsorLimit> for u128 {
+    fn from(limit: SponsorLimit) -> Self {
+        limit.0
+    }
+}
+
+impl From<SponsorLimit> for crate::storage::StoredValue<'_> {
+    fn from(limit: SponsorLimit) -> Self {
+        crate::storage::StoredValue::Sponsor(Value(ValueImpl::SponsorLimit(limit)))
+    }
+}
+
+impl TryFrom<crate::storage::StoredValue<'_>> for SponsorLimit {
+    type Error = astria_eyre::eyre::Error;
+
+    fn try_from(value: crate::storage::StoredValue) -> Result<Self, Self::Error> {
+        let crate::storage::StoredValue::Sponsor(Value(ValueImpl::SponsorLimit(limit))) = value
+        else {
+            bail!("sponsors stored value type mismatch: expected sponsor limit, found {value:?}");
+        };
+        Ok(limit)
+    }
+}
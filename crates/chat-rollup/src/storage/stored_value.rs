@@ -10,6 +10,10 @@ pub(crate) enum StoredValue<'a> {
     Address(crate::address::storage::Value<'a>),
     Text(crate::text::storage::Value),
     Bridge(crate::bridge::storage::Value<'a>),
+    Receipt(crate::receipts::storage::Value),
+    Sponsor(crate::sponsors::storage::Value),
+    SessionKey(crate::session_keys::storage::Value),
+    Multisig(crate::multisig::storage::Value),
 }
 
 impl StoredValue<'_> {
@@ -0,0 +1,300 @@
+//! A client for the composer which keeps the rollup usable while the composer is down.
+//!
+//! The client connects lazily, so the rollup starts without a reachable composer. Submissions
+//! that cannot be forwarded because the composer is unavailable are put into a bounded retry
+//! queue, which a background task drains in order with exponential backoff once the composer is
+//! reachable again. Only when the queue is full are submissions refused. A queued submission the
+//! composer rejects, or which cannot be forwarded after [`MAX_RETRY_ATTEMPTS`] attempts, is
+//! dropped and its submitter is told through the [`OnDropped`] callback.
+
+use std::{
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use astria_core::{
+    generated::astria::composer::v1::{
+        grpc_collector_service_client::GrpcCollectorServiceClient, SubmitRollupTransactionRequest,
+    },
+    primitive::v1::RollupId,
+    Protobuf as _,
+};
+use astria_eyre::eyre::{Result, WrapErr as _};
+use bytes::Bytes;
+use tokio::sync::mpsc;
+use tonic::{
+    transport::{Channel, Endpoint},
+    Code,
+};
+use tracing::{error, info, warn};
+
+use super::{OnDropped, Submission, SubmissionBackend, SubmissionHealth, SubmitError};
+
+/// How many submissions are held while the composer is unavailable.
+const RETRY_QUEUE_CAPACITY: usize = 256;
+
+/// How often a queued submission is attempted before it is dropped.
+const MAX_RETRY_ATTEMPTS: u32 = 20;
+
+const INITIAL_RETRY_DELAY: Duration = Duration::from_millis(100);
+
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(10);
+
+#[derive(Clone)]
+pub(crate) struct ComposerClient {
+    rollup_id: RollupId,
+    client: GrpcCollectorServiceClient<Channel>,
+    connected: Arc<AtomicBool>,
+    queued_submissions: Arc<AtomicUsize>,
+    retry_queue_capacity: usize,
+    retry_queue: mpsc::UnboundedSender<QueuedSubmission>,
+}
+
+struct QueuedSubmission {
+    data: Bytes,
+    on_dropped: OnDropped,
+}
+
+impl ComposerClient {
+    /// Returns a client submitting transactions of `rollup_id` to the composer at `addr`, and
+    /// spawns the task retrying queued submissions.
+    ///
+    /// The composer does not need to be reachable yet.
+    ///
+    /// # Errors
+    /// Returns an error if `addr` is not a valid endpoint.
+    pub(crate) fn spawn(addr: String, rollup_id: RollupId) -> Result<Self> {
+        Self::spawn_with_capacity(addr, rollup_id, RETRY_QUEUE_CAPACITY)
+    }
+
+    fn spawn_with_capacity(
+        addr: String,
+        rollup_id: RollupId,
+        retry_queue_capacity: usize,
+    ) -> Result<Self> {
+        let endpoint = Endpoint::from_shared(addr).wrap_err("invalid composer address")?;
+        let client = GrpcCollectorServiceClient::new(endpoint.connect_lazy());
+        let (retry_queue, queued) = mpsc::unbounded_channel();
+        let composer = Self {
+            rollup_id,
+            client,
+            connected: Arc::new(AtomicBool::new(false)),
+            queued_submissions: Arc::new(AtomicUsize::new(0)),
+            retry_queue_capacity,
+            retry_queue,
+        };
+        tokio::spawn(composer.clone().run_retry_queue(endpoint, queued));
+        Ok(composer)
+    }
+
+    fn enqueue(&self, data: Bytes, on_dropped: OnDropped) -> Result<Submission, SubmitError> {
+        self.queued_submissions
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |queued| {
+                (queued < self.retry_queue_capacity).then_some(queued + 1)
+            })
+            .map_err(|_| SubmitError::Unavailable)?;
+        if self
+            .retry_queue
+            .send(QueuedSubmission { data, on_dropped })
+            .is_err()
+        {
+            self.queued_submissions.fetch_sub(1, Ordering::AcqRel);
+            return Err(SubmitError::Unavailable);
+        }
+        Ok(Submission::Queued)
+    }
+
+    async fn forward(&self, data: Bytes) -> Result<(), tonic::Status> {
+        self.client
+            .clone()
+            .submit_rollup_transaction(SubmitRollupTransactionRequest {
+                rollup_id: Some(self.rollup_id.into_raw()),
+                data,
+            })
+            .await
+            .map(|_| ())
+    }
+
+    /// Forwards queued submissions in order. While the queue is empty and the composer is
+    /// unavailable, its endpoint is probed so that the health reported is current.
+    async fn run_retry_queue(
+        self,
+        endpoint: Endpoint,
+        mut queued: mpsc::UnboundedReceiver<QueuedSubmission>,
+    ) {
+        loop {
+            let submission = if self.connected.load(Ordering::Relaxed) {
+                queued.recv().await
+            } else {
+                tokio::select! {
+                    submission = queued.recv() => submission,
+                    () = probe(&endpoint) => {
+                        info!("connected to composer");
+                        self.connected.store(true, Ordering::Relaxed);
+                        continue;
+                    }
+                }
+            };
+            let Some(QueuedSubmission { data, on_dropped }) = submission else {
+                break;
+            };
+            if let Err(reason) = self.forward_with_backoff(data).await {
+                on_dropped(reason);
+            }
+            self.queued_submissions.fetch_sub(1, Ordering::AcqRel);
+        }
+    }
+
+    /// Forwards `data`, retrying while the composer is unavailable. Returns the reason the
+    /// submission was dropped if it could not be forwarded.
+    async fn forward_with_backoff(&self, data: Bytes) -> Result<(), String> {
+        for attempt in 0..MAX_RETRY_ATTEMPTS {
+            match self.forward(data.clone()).await {
+                Ok(()) => {
+                    self.connected.store(true, Ordering::Relaxed);
+                    return Ok(());
+                }
+                Err(status) if is_retryable(&status) => {
+                    self.connected.store(false, Ordering::Relaxed);
+                    let delay = retry_delay(attempt);
+                    warn!(
+                        error = %status,
+                        attempt,
+                        delay_ms = delay.as_millis(),
+                        "failed to forward queued submission to composer, retrying",
+                    );
+                    tokio::time::sleep(delay).await;
+                }
+                Err(status) => {
+                    error!(error = %status, "composer rejected queued submission, dropping it");
+                    return Err(format!(
+                        "composer rejected submission: {}",
+                        status.message()
+                    ));
+                }
+            }
+        }
+        error!(
+            attempts = MAX_RETRY_ATTEMPTS,
+            "failed to forward queued submission to composer, dropping it"
+        );
+        Err(format!(
+            "composer unavailable after {MAX_RETRY_ATTEMPTS} attempts"
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl SubmissionBackend for ComposerClient {
+    fn name(&self) -> &'static str {
+        "composer"
+    }
+
+    fn health(&self) -> SubmissionHealth {
+        SubmissionHealth {
+            connected: self.connected.load(Ordering::Relaxed),
+            queued_submissions: self.queued_submissions.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Forwards `data` to the composer.
+    ///
+    /// If the composer is unavailable, or earlier submissions are still queued, `data` is queued
+    /// so that submissions reach the composer in order. Submissions are only refused with
+    /// [`SubmitError::Unavailable`] once the retry queue is full. `on_dropped` is called if a
+    /// queued submission is dropped later on.
+    async fn submit(&self, data: Bytes, on_dropped: OnDropped) -> Result<Submission, SubmitError> {
+        let health = self.health();
+        if health.connected && health.queued_submissions == 0 {
+            match self.forward(data.clone()).await {
+                Ok(()) => return Ok(Submission::Forwarded),
+                Err(status) if is_retryable(&status) => {
+                    warn!(error = %status, "composer unavailable, queueing submission");
+                    self.connected.store(false, Ordering::Relaxed);
+                }
+                Err(status) => return Err(SubmitError::Rejected(status.message().to_string())),
+            }
+        }
+        self.enqueue(data, on_dropped)
+    }
+}
+
+/// Returns once a connection to `endpoint` could be established, retrying with backoff.
+async fn probe(endpoint: &Endpoint) {
+    let mut attempt = 0;
+    while endpoint.connect().await.is_err() {
+        tokio::time::sleep(retry_delay(attempt)).await;
+        attempt = attempt.saturating_add(1);
+    }
+}
+
+/// Returns the delay before retrying after `attempt` failed attempts, doubling from
+/// [`INITIAL_RETRY_DELAY`] up to [`MAX_RETRY_DELAY`].
+fn retry_delay(attempt: u32) -> Duration {
+    INITIAL_RETRY_DELAY
+        .saturating_mul(2u32.saturating_pow(attempt))
+        .min(MAX_RETRY_DELAY)
+}
+
+/// Returns whether a submission failing with `status` may succeed when retried, because the
+/// composer could not be reached rather than rejecting the submission.
+fn is_retryable(status: &tonic::Status) -> bool {
+    matches!(
+        status.code(),
+        Code::Unavailable
+            | Code::Unknown
+            | Code::DeadlineExceeded
+            | Code::ResourceExhausted
+            | Code::Aborted
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // nothing listens on this port, so the composer is never reachable
+    const UNREACHABLE_COMPOSER: &str = "http://127.0.0.1:1";
+
+    #[test]
+    fn retry_delay_doubles_up_to_max() {
+        assert_eq!(retry_delay(0), INITIAL_RETRY_DELAY);
+        assert_eq!(retry_delay(1), INITIAL_RETRY_DELAY * 2);
+        assert_eq!(retry_delay(3), INITIAL_RETRY_DELAY * 8);
+        assert_eq!(retry_delay(u32::MAX), MAX_RETRY_DELAY);
+    }
+
+    #[tokio::test]
+    async fn submissions_are_queued_until_queue_is_full() {
+        let composer = ComposerClient::spawn_with_capacity(
+            UNREACHABLE_COMPOSER.to_string(),
+            RollupId::from_unhashed_bytes("astria-chat"),
+            1,
+        )
+        .unwrap();
+
+        assert_eq!(
+            composer
+                .submit(Bytes::from_static(b"tx-0"), Box::new(|_| {}))
+                .await
+                .unwrap(),
+            Submission::Queued,
+        );
+        assert!(matches!(
+            composer
+                .submit(Bytes::from_static(b"tx-1"), Box::new(|_| {}))
+                .await,
+            Err(SubmitError::Unavailable),
+        ));
+        assert_eq!(
+            composer.health(),
+            SubmissionHealth {
+                connected: false,
+                queued_submissions: 1,
+            }
+        );
+    }
+}
@@ -0,0 +1,130 @@
+//! Forwards transactions submitted to the rollup to the sequencer.
+//!
+//! Transactions are either handed to a composer, which bundles them into sequencer transactions,
+//! or wrapped into sequencer transactions signed by the rollup itself. Which backend is used is
+//! selected by [`Config::submission_backend`].
+
+pub(crate) mod composer;
+pub(crate) mod sequencer;
+
+use std::sync::Arc;
+
+use astria_core::primitive::v1::RollupId;
+use astria_eyre::eyre::{Result, WrapErr as _};
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::config::Config;
+
+/// Selects where submitted transactions are sent.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SubmissionBackendKind {
+    /// Submit to a composer at [`Config::composer_addr`].
+    #[default]
+    Composer,
+    /// Submit directly to a sequencer at [`Config::sequencer_rpc_addr`].
+    Sequencer,
+}
+
+/// How a submission accepted by [`SubmissionBackend::submit`] was handled.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Submission {
+    /// The submission was forwarded.
+    Forwarded,
+    /// The backend is unavailable, the submission will be forwarded once it is back.
+    Queued,
+}
+
+/// Called with the reason if a submission accepted as [`Submission::Queued`] is dropped before it
+/// could be forwarded, so that the caller can release what it reserved for it.
+pub(crate) type OnDropped = Box<dyn FnOnce(String) + Send>;
+
+#[derive(Clone, Debug, Error)]
+pub(crate) enum SubmitError {
+    #[error("submissions cannot be forwarded to the sequencer right now")]
+    Unavailable,
+
+    #[error("submission was rejected: {0}")]
+    Rejected(String),
+}
+
+/// The state of a submission backend, as reported by the health endpoint.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct SubmissionHealth {
+    pub(crate) connected: bool,
+    pub(crate) queued_submissions: usize,
+}
+
+#[async_trait::async_trait]
+pub(crate) trait SubmissionBackend: Send + Sync {
+    /// Returns the name of the backend, as reported by the health endpoint.
+    fn name(&self) -> &'static str;
+
+    fn health(&self) -> SubmissionHealth;
+
+    /// Forwards the encoded rollup transaction `data` to the sequencer.
+    ///
+    /// `on_dropped` is only called if the submission was queued and is dropped later on.
+    async fn submit(&self, data: Bytes, on_dropped: OnDropped) -> Result<Submission, SubmitError>;
+}
+
+/// Returns the submission backend selected by `config`, forwarding transactions of `rollup_id`.
+///
+/// # Errors
+/// Returns an error if the configuration of the selected backend is invalid.
+pub(crate) fn from_config(
+    config: &Config,
+    rollup_id: RollupId,
+) -> Result<Arc<dyn SubmissionBackend>> {
+    let backend: Arc<dyn SubmissionBackend> = match config.submission_backend {
+        SubmissionBackendKind::Composer => Arc::new(
+            composer::ComposerClient::spawn(config.composer_addr.clone(), rollup_id)
+                .wrap_err("failed to construct composer client")?,
+        ),
+        SubmissionBackendKind::Sequencer => Arc::new(
+            sequencer::SequencerSubmitter::from_config(config, rollup_id)
+                .wrap_err("failed to construct sequencer submitter")?,
+        ),
+    };
+    Ok(backend)
+}
+
+/// A backend recording submissions instead of forwarding them.
+#[cfg(test)]
+#[derive(Default)]
+pub(crate) struct MockBackend {
+    pub(crate) submitted: std::sync::Mutex<Vec<Bytes>>,
+    pub(crate) unavailable: std::sync::atomic::AtomicBool,
+    /// If set, submissions are queued and their callbacks kept in `queued`.
+    pub(crate) queue: std::sync::atomic::AtomicBool,
+    pub(crate) queued: std::sync::Mutex<Vec<OnDropped>>,
+}
+
+#[cfg(test)]
+#[async_trait::async_trait]
+impl SubmissionBackend for MockBackend {
+    fn name(&self) -> &'static str {
+        "mock"
+    }
+
+    fn health(&self) -> SubmissionHealth {
+        SubmissionHealth {
+            connected: !self.unavailable.load(std::sync::atomic::Ordering::Relaxed),
+            queued_submissions: 0,
+        }
+    }
+
+    async fn submit(&self, data: Bytes, on_dropped: OnDropped) -> Result<Submission, SubmitError> {
+        if self.unavailable.load(std::sync::atomic::Ordering::Relaxed) {
+            return Err(SubmitError::Unavailable);
+        }
+        if self.queue.load(std::sync::atomic::Ordering::Relaxed) {
+            self.queued.lock().unwrap().push(on_dropped);
+            return Ok(Submission::Queued);
+        }
+        self.submitted.lock().unwrap().push(data);
+        Ok(Submission::Forwarded)
+    }
+}
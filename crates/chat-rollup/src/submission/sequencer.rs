@@ -0,0 +1,227 @@
+//! Submits rollup transactions directly to a sequencer, without a composer.
+//!
+//! Every rollup transaction is wrapped into a sequencer transaction with a single
+//! `RollupDataSubmission` action, signed by the rollup's sequencer key and broadcast to the
+//! sequencer's CometBFT RPC.
+
+use std::{
+    path::Path,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use astria_core::{
+    crypto::SigningKey,
+    primitive::v1::{asset, Address, RollupId},
+    protocol::{
+        abci::AbciErrorCode,
+        transaction::v1::{action::RollupDataSubmission, Transaction, TransactionBody},
+    },
+};
+use astria_eyre::eyre::{eyre, Result, WrapErr as _};
+use astria_sequencer_client::{HttpClient, SequencerClientExt as _};
+use bytes::Bytes;
+use hex::FromHex as _;
+use tendermint::abci::Code;
+use tokio::sync::Mutex;
+use tracing::warn;
+
+use super::{OnDropped, Submission, SubmissionBackend, SubmissionHealth, SubmitError};
+use crate::config::Config;
+
+const SEQUENCER_ADDRESS_PREFIX: &str = "astria";
+
+pub(crate) struct SequencerSubmitter {
+    client: HttpClient,
+    chain_id: String,
+    rollup_id: RollupId,
+    fee_asset: asset::Denom,
+    signing_key: SigningKey,
+    address: Address,
+    /// The nonce of the next sequencer transaction, or `None` if it must be fetched from the
+    /// sequencer first. Holding the lock for a whole submission keeps nonces in order.
+    next_nonce: Mutex<Option<u32>>,
+    connected: AtomicBool,
+}
+
+impl SequencerSubmitter {
+    /// Returns a submitter configured by the `sequencer_*` fields of `config`.
+    ///
+    /// # Errors
+    /// Returns an error if the sequencer key cannot be read, or if any of the fields is invalid.
+    pub(crate) fn from_config(config: &Config, rollup_id: RollupId) -> Result<Self> {
+        let signing_key = signing_key_from_file(&config.sequencer_private_key_file)?;
+        let fee_asset = config
+            .sequencer_fee_asset
+            .parse()
+            .wrap_err("invalid sequencer fee asset")?;
+        Self::new(
+            &config.sequencer_rpc_addr,
+            config.sequencer_chain_id.clone(),
+            rollup_id,
+            fee_asset,
+            signing_key,
+        )
+    }
+
+    fn new(
+        rpc_addr: &str,
+        chain_id: String,
+        rollup_id: RollupId,
+        fee_asset: asset::Denom,
+        signing_key: SigningKey,
+    ) -> Result<Self> {
+        let client = HttpClient::new(rpc_addr).wrap_err("invalid sequencer rpc address")?;
+        let address = Address::builder()
+            .array(*signing_key.verification_key().address_bytes())
+            .prefix(SEQUENCER_ADDRESS_PREFIX)
+            .try_build()
+            .wrap_err("failed to construct address of the sequencer key")?;
+        Ok(Self {
+            client,
+            chain_id,
+            rollup_id,
+            fee_asset,
+            signing_key,
+            address,
+            next_nonce: Mutex::new(None),
+            connected: AtomicBool::new(false),
+        })
+    }
+
+    /// Wraps `data` into a sequencer transaction with `nonce`, signed by the sequencer key.
+    fn sign(&self, data: Bytes, nonce: u32) -> Result<Transaction> {
+        let body = TransactionBody::builder()
+            .actions(vec![RollupDataSubmission {
+                rollup_id: self.rollup_id,
+                data,
+                fee_asset: self.fee_asset.clone(),
+            }
+            .into()])
+            .chain_id(self.chain_id.clone())
+            .nonce(nonce)
+            .try_build()
+            .wrap_err("failed to construct sequencer transaction")?;
+        Ok(body.sign(&self.signing_key))
+    }
+
+    async fn fetch_nonce(&self) -> Result<u32, SubmitError> {
+        match self.client.get_latest_nonce(self.address).await {
+            Ok(response) => Ok(response.nonce),
+            Err(err) => {
+                warn!(error = %err, "failed to fetch nonce of sequencer key");
+                self.connected.store(false, Ordering::Relaxed);
+                Err(SubmitError::Unavailable)
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl SubmissionBackend for SequencerSubmitter {
+    fn name(&self) -> &'static str {
+        "sequencer"
+    }
+
+    fn health(&self) -> SubmissionHealth {
+        SubmissionHealth {
+            connected: self.connected.load(Ordering::Relaxed),
+            queued_submissions: 0,
+        }
+    }
+
+    /// Wraps `data` into a sequencer transaction and broadcasts it.
+    ///
+    /// If the sequencer rejects the nonce, for example because the key was also used elsewhere,
+    /// the nonce is fetched again and the submission is retried once. Submissions are never
+    /// queued, so `on_dropped` is not called.
+    async fn submit(&self, data: Bytes, _on_dropped: OnDropped) -> Result<Submission, SubmitError> {
+        let mut next_nonce = self.next_nonce.lock().await;
+        for _ in 0..2 {
+            let nonce = match *next_nonce {
+                Some(nonce) => nonce,
+                None => self.fetch_nonce().await?,
+            };
+            let transaction = self
+                .sign(data.clone(), nonce)
+                .map_err(|err| SubmitError::Rejected(format!("{err:#}")))?;
+            let response = match self.client.submit_transaction_sync(transaction).await {
+                Ok(response) => response,
+                Err(err) => {
+                    warn!(error = %err, "failed to broadcast sequencer transaction");
+                    self.connected.store(false, Ordering::Relaxed);
+                    // the transaction may or may not have been received
+                    *next_nonce = None;
+                    return Err(SubmitError::Unavailable);
+                }
+            };
+            self.connected.store(true, Ordering::Relaxed);
+            if response.code.is_ok() {
+                *next_nonce = Some(nonce.saturating_add(1));
+                return Ok(Submission::Forwarded);
+            }
+            if response.code != Code::Err(AbciErrorCode::INVALID_NONCE.value()) {
+                return Err(SubmitError::Rejected(response.log));
+            }
+            *next_nonce = None;
+        }
+        Err(SubmitError::Rejected(
+            "sequencer repeatedly rejected the nonce of the sequencer key".to_string(),
+        ))
+    }
+}
+
+/// Reads the hex encoded private key in the file at `path`.
+fn signing_key_from_file(path: &Path) -> Result<SigningKey> {
+    let contents = std::fs::read_to_string(path)
+        .wrap_err_with(|| format!("failed to read sequencer key from `{}`", path.display()))?;
+    let bytes = <[u8; 32]>::from_hex(contents.trim())
+        .map_err(|_| eyre!("sequencer key must be 32 hex encoded bytes"))?;
+    Ok(SigningKey::from(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::protocol::transaction::v1::Action;
+
+    use super::*;
+
+    // nothing listens on this port, so the sequencer is never reachable
+    const UNREACHABLE_SEQUENCER: &str = "http://127.0.0.1:1";
+
+    fn submitter() -> SequencerSubmitter {
+        SequencerSubmitter::new(
+            UNREACHABLE_SEQUENCER,
+            "astria-dev-1".to_string(),
+            RollupId::from_unhashed_bytes("astria-chat"),
+            "nria".parse().unwrap(),
+            SigningKey::from([1; 32]),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn signed_transaction_wraps_rollup_data() {
+        let submitter = submitter();
+        let transaction = submitter.sign(Bytes::from_static(b"tx"), 3).unwrap();
+
+        assert_eq!(transaction.nonce(), 3);
+        assert_eq!(transaction.chain_id(), "astria-dev-1");
+        let [Action::RollupDataSubmission(submission)] = transaction.actions() else {
+            panic!("expected a single rollup data submission");
+        };
+        assert_eq!(submission.rollup_id, submitter.rollup_id);
+        assert_eq!(submission.data, Bytes::from_static(b"tx"));
+    }
+
+    #[tokio::test]
+    async fn unreachable_sequencer_is_unavailable() {
+        let submitter = submitter();
+        assert!(matches!(
+            submitter
+                .submit(Bytes::from_static(b"tx"), Box::new(|_| {}))
+                .await,
+            Err(SubmitError::Unavailable),
+        ));
+        assert!(!submitter.health().connected);
+    }
+}
@@ -1,15 +1,25 @@
 #![allow(unused_imports)]
+use astria_core::primitive::v1::ADDRESS_LEN;
 use astria_eyre::eyre::{ensure, Result, WrapErr as _};
 use cnidarium::{StateRead, StateWrite};
-use rollup_core::transaction::v1::action::Transfer;
+use rollup_core::{
+    genesis::v1::TextParams,
+    transaction::v1::{
+        action::{SendText, Transfer},
+        Action, Transaction,
+    },
+};
 
-use super::AddressBytes;
-use crate::text::{StateReadExt as _, StateWriteExt as _};
+use super::{AddressBytes, TextsPosted};
+use crate::{
+    rollup::state_ext::StateReadExt as _,
+    text::{StateReadExt as _, StateWriteExt as _},
+};
 
 #[allow(unused_imports)]
 use crate::{
     accounts::{StateReadExt as _, StateWriteExt as _},
-    address::{StateReadExt as _, StateReadExt as _},
+    address::StateReadExt as _,
 };
 
 // #[async_trait::async_trait]
@@ -40,37 +50,241 @@ use crate::{
 //     }
 // }
 
+/// The flat fee charged for every `SendText` action.
+pub(crate) const SEND_TEXT_FEE: u128 = 1;
+
+/// Checks `transaction` against the limits on posting texts in `state`: its number of actions,
+/// the length of its texts, and the number of texts its account may still post.
+///
+/// Texts are counted in the window of the block following the last block in `state`: the block
+/// being executed, or the next block for the mempool.
+pub(crate) async fn check_text_limits<S: StateRead>(
+    transaction: &Transaction,
+    state: &S,
+) -> Result<()> {
+    let params = state
+        .get_text_params()
+        .await
+        .wrap_err("failed to get text params")?;
+    let actions = transaction.actions().len();
+    ensure!(
+        params.max_actions_per_transaction == 0
+            || u32::try_from(actions)
+                .is_ok_and(|actions| actions <= params.max_actions_per_transaction),
+        "transaction has `{actions}` actions, at most `{}` are allowed",
+        params.max_actions_per_transaction,
+    );
+
+    let mut texts: u32 = 0;
+    for action in transaction.actions() {
+        if let Action::Text(send_text) = action {
+            check_text_length(&send_text.text, &params)?;
+            texts = texts.saturating_add(1);
+        }
+    }
+    if params.max_texts_per_window > 0 && texts > 0 {
+        let posted =
+            texts_posted_in_current_window(state, transaction.address_bytes(), &params).await?;
+        ensure!(
+            posted.count.saturating_add(texts) <= params.max_texts_per_window,
+            "account can post `{}` texts every `{}` blocks and already posted `{}` in the current \
+             window, cannot post `{texts}` more",
+            params.max_texts_per_window,
+            params.window_blocks,
+            posted.count,
+        );
+    }
+    Ok(())
+}
+
+/// Checks that `text` is no longer than the text length limit of `params`.
+pub(crate) fn check_text_length(text: &str, params: &TextParams) -> Result<()> {
+    let length = text.len();
+    ensure!(
+        params.max_text_length == 0
+            || u32::try_from(length).is_ok_and(|length| length <= params.max_text_length),
+        "text is `{length}` bytes long, at most `{}` are allowed",
+        params.max_text_length,
+    );
+    Ok(())
+}
+
+/// Returns the texts `address` posted in the window of the block following the last block in
+/// `state`.
+pub(crate) async fn texts_posted_in_current_window<S: StateRead>(
+    state: &S,
+    address: &[u8; ADDRESS_LEN],
+    params: &TextParams,
+) -> Result<TextsPosted> {
+    let height = state
+        .get_block_height()
+        .await
+        .wrap_err("failed to get block height")?
+        .saturating_add(1);
+    let window = height
+        .checked_div(u64::from(params.window_blocks))
+        .unwrap_or_default();
+    let posted = state
+        .get_texts_posted(address)
+        .await
+        .wrap_err("failed to get texts posted")?;
+    if posted.window == window {
+        Ok(posted)
+    } else {
+        Ok(TextsPosted { window, count: 0 })
+    }
+}
+
+/// Posts the text of `action` for `sender`, charging its fee to `fee_payer`: the sponsor of the
+/// transaction, if it has one, or its sender.
+///
+/// The text is counted against the texts `sender` may post in the current window, if that is
+/// limited.
 pub(crate) async fn execute_send_text<S, TAddress>(
-    action: &rollup_core::transaction::v1::action::SendText,
-    from: &TAddress,
+    action: &SendText,
+    sender: &TAddress,
+    fee_payer: &TAddress,
     mut state: S,
 ) -> Result<()>
 where
     S: StateWrite,
     TAddress: AddressBytes,
 {
-    let from = from.address_bytes();
+    let sender = sender.address_bytes();
+    let fee_payer = fee_payer.address_bytes();
 
-    let last_id = state.get_last_text_id().await.unwrap();
-    let last_id: u64 = last_id.into();
-    state
-        .put_text(action.text.clone(), action.from.clone(), last_id)
-        .unwrap();
-    state.put_last_text_id(last_id + 1).unwrap();
+    post_text(&mut state, action.text.clone(), action.from.clone()).await?;
+
+    let params = state
+        .get_text_params()
+        .await
+        .wrap_err("failed to get text params")?;
+    if params.max_texts_per_window > 0 {
+        let mut posted = texts_posted_in_current_window(&state, sender, &params).await?;
+        posted.count = posted.count.saturating_add(1);
+        state
+            .put_texts_posted(sender, posted)
+            .wrap_err("failed to put texts posted")?;
+    }
 
     // TODO: Implement configurable fees and fee recipients
     state
-        .decrease_balance(from, &action.fee_asset, 1)
+        .decrease_balance(fee_payer, &action.fee_asset, SEND_TEXT_FEE)
         .await
-        .wrap_err("failed decreasing `from` account balance")?;
+        .wrap_err("failed decreasing fee payer account balance")?;
 
-    let nonce = state
-        .get_account_nonce(from)
+    Ok(())
+}
+
+/// Stores `text` attributed to `from` under the next text id, returning that id.
+pub(crate) async fn post_text<S: StateWrite>(
+    state: &mut S,
+    text: String,
+    from: String,
+) -> Result<u64> {
+    let id: u64 = state
+        .get_last_text_id()
         .await
-        .wrap_err("failed to get account nonce")?;
-    let nonce = nonce + 1;
+        .wrap_err("failed to get last text id")?
+        .into();
     state
-        .put_account_nonce(from, nonce)
-        .wrap_err("failed to put account nonce")?;
-    Ok(())
+        .put_text(text, from, id)
+        .wrap_err("failed to put text")?;
+    state
+        .put_last_text_id(id + 1)
+        .wrap_err("failed to put last text id")?;
+    Ok(id)
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{crypto::SigningKey, primitive::v1::asset::Denom};
+    use cnidarium::StateDelta;
+    use rollup_core::transaction::v1::TransactionBody;
+
+    use super::*;
+    use crate::{accounts::StateWriteExt as _, rollup::state_ext::StateWriteExt as _};
+
+    fn params() -> TextParams {
+        TextParams {
+            max_texts_per_window: 3,
+            window_blocks: 10,
+            max_text_length: 5,
+            max_actions_per_transaction: 2,
+        }
+    }
+
+    fn send_text(text: &str) -> SendText {
+        SendText {
+            text: text.to_string(),
+            from: "alice".to_string(),
+            fee_asset: "nria".parse().unwrap(),
+        }
+    }
+
+    fn transaction(texts: &[&str]) -> Transaction {
+        TransactionBody::builder()
+            .nonce(0)
+            .chain_id("astria-chat")
+            .actions(texts.iter().map(|text| send_text(text).into()).collect())
+            .try_build()
+            .unwrap()
+            .sign(&SigningKey::from([1; 32]))
+    }
+
+    #[tokio::test]
+    async fn texts_are_unlimited_without_params() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let state = StateDelta::new(storage.latest_snapshot());
+
+        check_text_limits(&transaction(&["a long text"; 10]), &state)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn texts_and_actions_are_limited_by_params() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_block_height(1).unwrap();
+        state.put_text_params(&params()).unwrap();
+
+        check_text_limits(&transaction(&["gm", "hello"]), &state)
+            .await
+            .unwrap();
+        check_text_limits(&transaction(&["hello!"]), &state)
+            .await
+            .unwrap_err();
+        check_text_limits(&transaction(&["gm", "gm", "gm"]), &state)
+            .await
+            .unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn texts_posted_are_limited_per_window() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_last_text_id(0).unwrap();
+        state.put_text_params(&params()).unwrap();
+        state.put_block_height(10).unwrap();
+        let transaction = transaction(&["gm", "gm"]);
+        let sender = *transaction.address_bytes();
+
+        state
+            .put_account_balance(&sender, &"nria".parse::<Denom>().unwrap(), 10)
+            .unwrap();
+
+        check_text_limits(&transaction, &state).await.unwrap();
+        for _ in 0..2 {
+            execute_send_text(&send_text("gm"), &sender, &sender, &mut state)
+                .await
+                .unwrap();
+        }
+        assert_eq!(state.get_texts_posted(&sender).await.unwrap().count, 2);
+        check_text_limits(&transaction, &state).await.unwrap_err();
+
+        // the count starts over in the next window
+        state.put_block_height(19).unwrap();
+        check_text_limits(&transaction, &state).await.unwrap();
+    }
 }
@@ -1,4 +1,5 @@
 pub(crate) mod action;
+pub mod proof;
 pub(crate) mod query;
 mod state_ext;
 pub(crate) mod storage;
@@ -46,3 +47,11 @@ impl AddressBytes for VerificationKey {
         self.address_bytes()
     }
 }
+
+/// The number of texts an account posted within a window of blocks.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct TextsPosted {
+    /// The index of the window: the height of its first block divided by its length.
+    pub(crate) window: u64,
+    pub(crate) count: u32,
+}
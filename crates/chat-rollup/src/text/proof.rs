@@ -0,0 +1,34 @@
+use astria_eyre::eyre::{OptionExt as _, Result, WrapErr as _};
+use cnidarium::Snapshot;
+use rollup_core::proof::StateProof;
+
+use super::storage::{keys, values};
+use crate::storage::StoredValue;
+
+/// Returns the text stored under `id` together with a proof against the state root of
+/// `snapshot`.
+///
+/// If no text is stored under `id` the returned value is `None` and the proof is a proof of
+/// its absence.
+pub(crate) async fn text_with_proof(
+    snapshot: &Snapshot,
+    id: u64,
+) -> Result<(Option<String>, StateProof)> {
+    let proof = crate::proof::get_with_proof(snapshot, keys::text(id)).await?;
+    let text = match proof.value.as_deref() {
+        Some(bytes) => Some(decode_text(Some(bytes))?),
+        None => None,
+    };
+    Ok((text, proof))
+}
+
+/// Decodes the raw value of a text proof.
+///
+/// # Errors
+/// Returns an error if the value is absent or the bytes are not a stored text.
+pub fn decode_text(value: Option<&[u8]>) -> Result<String> {
+    let bytes = value.ok_or_eyre("text not found in state")?;
+    StoredValue::deserialize(bytes)
+        .and_then(|value| values::Text::try_from(value).map(String::from))
+        .wrap_err("invalid text bytes")
+}
@@ -9,6 +9,7 @@ use astria_eyre::{
 };
 use async_trait::async_trait;
 use cnidarium::{StateRead, StateWrite};
+use rollup_core::genesis::v1::TextParams;
 use tracing::instrument;
 
 use self::{
@@ -20,7 +21,7 @@ use super::storage::{
     self,
     keys::{self},
 };
-use crate::storage::StoredValue;
+use crate::{accounts::AddressBytes, storage::StoredValue, text::TextsPosted};
 
 #[async_trait]
 pub(crate) trait StateReadExt: StateRead {
@@ -52,6 +53,39 @@ pub(crate) trait StateReadExt: StateRead {
             .and_then(|value| storage::values::LastText::try_from(value).map(LastText::from))
             .context("invalid block height bytes")
     }
+
+    /// Returns the limits on posting texts. No limits apply if none were set.
+    #[instrument(skip_all)]
+    async fn get_text_params(&self) -> Result<TextParams> {
+        let Some(bytes) = self
+            .get_raw(keys::PARAMS)
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed to read raw text params from state")?
+        else {
+            return Ok(TextParams::default());
+        };
+        StoredValue::deserialize(&bytes)
+            .and_then(|value| storage::values::TextParams::try_from(value).map(TextParams::from))
+            .wrap_err("invalid text params bytes")
+    }
+
+    /// Returns the number of texts the account with `address` posted in the last window it
+    /// posted in.
+    #[instrument(skip_all)]
+    async fn get_texts_posted<T: AddressBytes>(&self, address: &T) -> Result<TextsPosted> {
+        let Some(bytes) = self
+            .get_raw(&keys::texts_posted(address))
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed to read raw texts posted from state")?
+        else {
+            return Ok(TextsPosted::default());
+        };
+        StoredValue::deserialize(&bytes)
+            .and_then(|value| storage::values::TextsPosted::try_from(value).map(TextsPosted::from))
+            .wrap_err("invalid texts posted bytes")
+    }
 }
 
 impl<T: ?Sized + StateRead> StateReadExt for T {}
@@ -75,6 +109,28 @@ pub(crate) trait StateWriteExt: StateWrite {
         self.put_raw(keys::LAST_TX.to_string(), bytes);
         Ok(())
     }
+
+    #[instrument(skip_all)]
+    fn put_text_params(&mut self, params: &TextParams) -> Result<()> {
+        let bytes = StoredValue::from(storage::values::TextParams::from(params))
+            .serialize()
+            .wrap_err("failed to serialize text params")?;
+        self.put_raw(keys::PARAMS.to_string(), bytes);
+        Ok(())
+    }
+
+    #[instrument(skip_all)]
+    fn put_texts_posted<T: AddressBytes>(
+        &mut self,
+        address: &T,
+        posted: TextsPosted,
+    ) -> Result<()> {
+        let bytes = StoredValue::from(storage::values::TextsPosted::from(posted))
+            .serialize()
+            .wrap_err("failed to serialize texts posted")?;
+        self.put_raw(keys::texts_posted(address), bytes);
+        Ok(())
+    }
 }
 
 impl<T: StateWrite> StateWriteExt for T {}
@@ -1,5 +1,14 @@
+use crate::{accounts::AddressBytes, storage::keys::AccountPrefixer};
+
 pub(in crate::text) const LAST_TX: &str = "app/text/last";
+pub(in crate::text) const PARAMS: &str = "app/text/params";
 
 pub(in crate::text) fn text(id: u64) -> String {
     format!("app/text/{id}")
 }
+
+/// Example: `app/text/posted/gGhH....zZ4=`.
+///                          |base64 chars|
+pub(in crate::text) fn texts_posted<T: AddressBytes>(address: &T) -> String {
+    AccountPrefixer::new("app/text/posted/", address).to_string()
+}
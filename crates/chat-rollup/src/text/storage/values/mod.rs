@@ -1,9 +1,13 @@
 mod last_text;
+mod params;
 mod text;
+mod texts_posted;
 use borsh::{BorshDeserialize, BorshSerialize};
 
 pub(in crate::text) use self::last_text::LastText;
+pub(in crate::text) use self::params::TextParams;
 pub(in crate::text) use self::text::Text;
+pub(in crate::text) use self::texts_posted::TextsPosted;
 
 #[derive(Debug, BorshSerialize, BorshDeserialize)]
 pub(crate) struct Value(ValueImpl);
@@ -12,4 +16,6 @@ pub(crate) struct Value(ValueImpl);
 enum ValueImpl {
     Text(Text),
     LastText(LastText),
+    TextParams(TextParams),
+    TextsPosted(TextsPosted),
 }
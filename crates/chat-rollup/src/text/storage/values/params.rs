@@ -0,0 +1,51 @@
+use super::{Value, ValueImpl};
+use astria_eyre::eyre::bail;
+use borsh::{BorshDeserialize, BorshSerialize};
+use rollup_core::genesis::v1::TextParams as DomainTextParams;
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(crate) struct TextParams {
+    max_texts_per_window: u32,
+    window_blocks: u32,
+    max_text_length: u32,
+    max_actions_per_transaction: u32,
+}
+
+impl From<&DomainTextParams> for TextParams {
+    fn from(params: &DomainTextParams) -> Self {
+        Self {
+            max_texts_per_window: params.max_texts_per_window,
+            window_blocks: params.window_blocks,
+            max_text_length: params.max_text_length,
+            max_actions_per_transaction: params.max_actions_per_transaction,
+        }
+    }
+}
+
+impl From<TextParams> for DomainTextParams {
+    fn from(params: TextParams) -> Self {
+        Self {
+            max_texts_per_window: params.max_texts_per_window,
+            window_blocks: params.window_blocks,
+            max_text_length: params.max_text_length,
+            max_actions_per_transaction: params.max_actions_per_transaction,
+        }
+    }
+}
+
+impl From<TextParams> for crate::storage::StoredValue<'_> {
+    fn from(params: TextParams) -> Self {
+        crate::storage::StoredValue::Text(Value(ValueImpl::TextParams(params)))
+    }
+}
+
+impl TryFrom<crate::storage::StoredValue<'_>> for TextParams {
+    type Error = astria_eyre::eyre::Error;
+
+    fn try_from(value: crate::storage::StoredValue) -> Result<Self, Self::Error> {
+        let crate::storage::StoredValue::Text(Value(ValueImpl::TextParams(params))) = value else {
+            bail!("app stored value type mismatch: expected text params, found {value:?}");
+        };
+        Ok(params)
+    }
+}
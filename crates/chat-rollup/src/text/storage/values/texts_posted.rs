@@ -0,0 +1,44 @@
+use super::{Value, ValueImpl};
+use astria_eyre::eyre::bail;
+use borsh::{BorshDeserialize, BorshSerialize};
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(crate) struct TextsPosted {
+    window: u64,
+    count: u32,
+}
+
+impl From<crate::text::TextsPosted> for TextsPosted {
+    fn from(posted: crate::text::TextsPosted) -> Self {
+        Self {
+            window: posted.window,
+            count: posted.count,
+        }
+    }
+}
+
+impl From<TextsPosted> for crate::text::TextsPosted {
+    fn from(posted: TextsPosted) -> Self {
+        Self {
+            window: posted.window,
+            count: posted.count,
+        }
+    }
+}
+
+impl From<TextsPosted> for crate::storage::StoredValue<'_> {
+    fn from(posted: TextsPosted) -> Self {
+        crate::storage::StoredValue::Text(Value(ValueImpl::TextsPosted(posted)))
+    }
+}
+
+impl TryFrom<crate::storage::StoredValue<'_>> for TextsPosted {
+    type Error = astria_eyre::eyre::Error;
+
+    fn try_from(value: crate::storage::StoredValue) -> Result<Self, Self::Error> {
+        let crate::storage::StoredValue::Text(Value(ValueImpl::TextsPosted(posted))) = value else {
+            bail!("app stored value type mismatch: expected texts posted, found {value:?}");
+        };
+        Ok(posted)
+    }
+}
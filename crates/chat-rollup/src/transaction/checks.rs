@@ -0,0 +1,728 @@
+use std::collections::HashMap;
+
+use astria_core::primitive::v1::asset;
+use astria_eyre::eyre::{ensure, OptionExt as _, Result, WrapErr as _};
+use cnidarium::StateRead;
+use rollup_core::{
+    genesis::v1::ComputeParams,
+    transaction::v1::{Action, Transaction},
+};
+
+use super::metering;
+use crate::{
+    accounts::StateReadExt as _, multisig::StateReadExt as _, rollup::state_ext::StateReadExt as _,
+    session_keys::StateReadExt as _, sponsors::StateReadExt as _, text::action::SEND_TEXT_FEE,
+};
+
+/// Checks that `transaction` was signed for the rollup with `chain_id`.
+pub(crate) fn check_chain_id(transaction: &Transaction, chain_id: &str) -> Result<()> {
+    ensure!(
+        transaction.chain_id() == chain_id,
+        "invalid chain id: expected `{chain_id}`, got `{}`",
+        transaction.chain_id(),
+    );
+    Ok(())
+}
+
+/// Checks that `transaction` has not expired.
+///
+/// Like session keys, the expiry height is checked against the height of the block following the
+/// last block in `state`. The expiry timestamp is checked against the timestamp in `state`: that
+/// of the block being executed, or of the last executed block for the mempool.
+pub(crate) async fn check_expiry<S: StateRead>(transaction: &Transaction, state: &S) -> Result<()> {
+    if let Some(valid_until_height) = transaction.valid_until_height() {
+        let height = state
+            .get_block_height()
+            .await
+            .wrap_err("failed to get block height")?
+            .saturating_add(1);
+        ensure!(
+            height <= u64::from(valid_until_height),
+            "transaction expired after height `{valid_until_height}`",
+        );
+    }
+    if let Some(valid_until) = transaction.valid_until_timestamp() {
+        let timestamp = state
+            .get_block_timestamp()
+            .await
+            .wrap_err("failed to get block timestamp")?;
+        ensure!(
+            (timestamp.seconds, timestamp.nanos) <= (valid_until.seconds, valid_until.nanos),
+            "transaction expired after timestamp `{}s`",
+            valid_until.seconds,
+        );
+    }
+    Ok(())
+}
+
+/// Checks that the signer of `transaction` holds an unexpired session key of its granter that
+/// permits all actions of the transaction, if it acts for a granter.
+///
+/// Session keys are checked against the height of the block following the last block in
+/// `state`: the block being executed, or the next block for the mempool.
+pub(crate) async fn check_session_key<S: StateRead>(
+    transaction: &Transaction,
+    state: &S,
+) -> Result<()> {
+    let Some(granter) = transaction.granter() else {
+        return Ok(());
+    };
+    let session_key = state
+        .get_session_key(granter, transaction.signer_address_bytes())
+        .await
+        .wrap_err("failed to get session key")?
+        .ok_or_eyre("signer does not hold a session key of the granter")?;
+    let height = state
+        .get_block_height()
+        .await
+        .wrap_err("failed to get block height")?
+        .saturating_add(1);
+    ensure!(
+        height <= u64::from(session_key.expires_at_height),
+        "session key expired after height `{}`",
+        session_key.expires_at_height,
+    );
+    for action in transaction.actions() {
+        ensure!(
+            session_key.actions.contains(&action.kind()),
+            "session key does not permit `{}` actions",
+            action.kind(),
+        );
+    }
+    Ok(())
+}
+
+/// Checks that `transaction` is signed by at least the threshold of members of the multisig
+/// account it acts for, and by no one else, if it acts for a multisig account.
+pub(crate) async fn check_multisig<S: StateRead>(
+    transaction: &Transaction,
+    state: &S,
+) -> Result<()> {
+    let Some(address) = transaction.multisig() else {
+        return Ok(());
+    };
+    let multisig = state
+        .get_multisig(address)
+        .await
+        .wrap_err("failed to get multisig account")?
+        .ok_or_eyre("multisig account is not registered")?;
+    let mut signatures: u32 = 0;
+    for signer in transaction.signers() {
+        ensure!(
+            multisig.members.contains(signer),
+            "signer is not a member of the multisig account",
+        );
+        signatures = signatures.saturating_add(1);
+    }
+    ensure!(
+        signatures >= multisig.threshold,
+        "multisig account requires `{}` signatures, got `{signatures}`",
+        multisig.threshold,
+    );
+    Ok(())
+}
+
+/// Checks that the nonce of `transaction` is the current nonce of its signer.
+pub(crate) async fn check_nonce<S: StateRead>(transaction: &Transaction, state: &S) -> Result<()> {
+    let current_nonce = state
+        .get_account_nonce(transaction.address_bytes())
+        .await
+        .wrap_err("failed to get account nonce")?;
+    ensure!(
+        transaction.nonce() == current_nonce,
+        "invalid nonce: expected `{current_nonce}`, got `{}`",
+        transaction.nonce(),
+    );
+    Ok(())
+}
+
+/// Checks that the actions of `transaction` are not estimated to use more compute units than a
+/// transaction may. The units they actually use are checked again once they are executed.
+pub(crate) async fn check_compute_units<S: StateRead>(
+    transaction: &Transaction,
+    state: &S,
+) -> Result<()> {
+    let params = state
+        .get_compute_params()
+        .await
+        .wrap_err("failed to get compute params")?;
+    let units = metering::estimated_transaction_units(transaction);
+    ensure!(
+        params.max_units_per_transaction == 0 || units <= params.max_units_per_transaction,
+        "transaction uses `{units}` compute units, at most `{}` are allowed",
+        params.max_units_per_transaction,
+    );
+    Ok(())
+}
+
+/// Checks that the signer of `transaction` holds enough of every asset to pay for its transfers,
+/// and that the fee payer of the transaction (its sponsor, if it has one, or its signer) holds
+/// enough to pay for its fees.
+pub(crate) async fn check_balance_for_total_fees_and_transfers<S: StateRead>(
+    transaction: &Transaction,
+    state: &S,
+) -> Result<()> {
+    let signer = *transaction.address_bytes();
+    let fee_payer = *transaction.fee_payer_address_bytes();
+    let params = state
+        .get_compute_params()
+        .await
+        .wrap_err("failed to get compute params")?;
+    let fees = total_fees(transaction, &params)?;
+    let mut required_by_account = vec![(signer, total_transfers(transaction)?)];
+    if fee_payer == signer {
+        for (asset, fee) in fees {
+            add_amount(&mut required_by_account[0].1, asset, fee)?;
+        }
+    } else {
+        required_by_account.push((fee_payer, fees));
+    }
+    for (address, totals) in required_by_account {
+        for (asset, required) in totals {
+            let balance = state
+                .get_account_balance(&address, &asset)
+                .await
+                .wrap_err("failed to get account balance")?;
+            ensure!(
+                balance >= required,
+                "insufficient balance of asset `{asset}`: required `{required}`, available \
+                 `{balance}`",
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Checks that the sponsor of `transaction`, if it has one, has set a limit for the signer that
+/// covers the fees of the transaction.
+pub(crate) async fn check_sponsor_limit<S: StateRead>(
+    transaction: &Transaction,
+    state: &S,
+) -> Result<()> {
+    if transaction.sponsor_signature().is_none() {
+        return Ok(());
+    }
+    let params = state
+        .get_compute_params()
+        .await
+        .wrap_err("failed to get compute params")?;
+    for (asset, fee) in total_fees(transaction, &params)? {
+        let limit = state
+            .get_sponsor_limit(
+                transaction.fee_payer_address_bytes(),
+                transaction.address_bytes(),
+                &asset,
+            )
+            .await
+            .wrap_err("failed to get sponsor limit")?;
+        ensure!(
+            limit >= fee,
+            "fees exceed the limit set by the sponsor for asset `{asset}`: required `{fee}`, \
+             remaining `{limit}`",
+        );
+    }
+    Ok(())
+}
+
+/// Returns the amount of each asset spent by the fees of `transaction`: the flat fees of its
+/// actions and the estimated fees for the compute units they use, priced by `params`.
+pub(crate) fn total_fees(
+    transaction: &Transaction,
+    params: &ComputeParams,
+) -> Result<HashMap<asset::IbcPrefixed, u128>> {
+    let mut totals = metering::estimated_compute_fees(transaction, params)?;
+    for (asset, fee) in flat_fees(transaction)? {
+        add_amount(&mut totals, asset, fee)?;
+    }
+    Ok(totals)
+}
+
+/// Returns the amount of each asset spent by the flat fees of the actions of `transaction`.
+pub(crate) fn flat_fees(transaction: &Transaction) -> Result<HashMap<asset::IbcPrefixed, u128>> {
+    let mut totals = HashMap::new();
+    for action in transaction.actions() {
+        match action {
+            Action::Text(send_text) => add_amount(
+                &mut totals,
+                send_text.fee_asset.to_ibc_prefixed(),
+                SEND_TEXT_FEE,
+            )?,
+            Action::Transfer(_)
+            | Action::SetSponsorLimit(_)
+            | Action::GrantSessionKey(_)
+            | Action::RevokeSessionKey(_)
+            | Action::RegisterMultisig(_) => {}
+        }
+    }
+    Ok(totals)
+}
+
+/// Returns the amount of each asset transferred by `transaction`.
+fn total_transfers(transaction: &Transaction) -> Result<HashMap<asset::IbcPrefixed, u128>> {
+    let mut totals = HashMap::new();
+    for action in transaction.actions() {
+        if let Action::Transfer(transfer) = action {
+            add_amount(
+                &mut totals,
+                transfer.asset.to_ibc_prefixed(),
+                transfer.amount,
+            )?;
+        }
+    }
+    Ok(totals)
+}
+
+fn add_amount(
+    totals: &mut HashMap<asset::IbcPrefixed, u128>,
+    asset: asset::IbcPrefixed,
+    amount: u128,
+) -> Result<()> {
+    let total = totals.entry(asset).or_default();
+    *total = total
+        .checked_add(amount)
+        .ok_or_eyre("total amount spent by transaction overflowed")?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::crypto::SigningKey;
+    use cnidarium::StateDelta;
+    use rollup_core::transaction::v1::{
+        action::{ActionKind, SendText, Transfer},
+        TransactionBody,
+    };
+
+    use super::*;
+    use crate::{
+        accounts::{state_ext::astria_address, StateWriteExt as _},
+        multisig::{Multisig, StateWriteExt as _},
+        rollup::state_ext::StateWriteExt as _,
+        session_keys::{SessionKey, StateWriteExt as _},
+        sponsors::StateWriteExt as _,
+    };
+
+    fn transaction(nonce: u32, chain_id: &str, texts: usize) -> Transaction {
+        let action = Action::Text(SendText {
+            text: "gm".to_string(),
+            from: "alice".to_string(),
+            fee_asset: "nria".parse().unwrap(),
+        });
+        TransactionBody::builder()
+            .nonce(nonce)
+            .chain_id(chain_id)
+            .actions(vec![action; texts])
+            .try_build()
+            .unwrap()
+            .sign(&SigningKey::from([1; 32]))
+    }
+
+    fn sponsored_transaction(texts: usize) -> Transaction {
+        let sponsor = SigningKey::from([2; 32]);
+        let action = Action::Text(SendText {
+            text: "gm".to_string(),
+            from: "alice".to_string(),
+            fee_asset: "nria".parse().unwrap(),
+        });
+        TransactionBody::builder()
+            .nonce(0)
+            .chain_id("astria-chat")
+            .actions(vec![action; texts])
+            .sponsor(astria_address(sponsor.verification_key().address_bytes()))
+            .try_build()
+            .unwrap()
+            .sign(&SigningKey::from([1; 32]))
+            .sign_as_sponsor(&sponsor)
+            .unwrap()
+    }
+
+    #[test]
+    fn chain_id_must_match() {
+        check_chain_id(&transaction(0, "astria-chat", 1), "astria-chat").unwrap();
+        check_chain_id(&transaction(0, "other-chain", 1), "astria-chat").unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn nonce_must_be_current() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        let transaction = transaction(1, "astria-chat", 1);
+
+        check_nonce(&transaction, &state).await.unwrap_err();
+        state
+            .put_account_nonce(transaction.address_bytes(), 1)
+            .unwrap();
+        check_nonce(&transaction, &state).await.unwrap();
+        state
+            .put_account_nonce(transaction.address_bytes(), 2)
+            .unwrap();
+        check_nonce(&transaction, &state).await.unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn balance_must_cover_fees_of_all_actions() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        let transaction = transaction(0, "astria-chat", 3);
+        let fee_asset: asset::Denom = "nria".parse().unwrap();
+
+        state
+            .put_account_balance(transaction.address_bytes(), &fee_asset, 2)
+            .unwrap();
+        check_balance_for_total_fees_and_transfers(&transaction, &state)
+            .await
+            .unwrap_err();
+        state
+            .put_account_balance(transaction.address_bytes(), &fee_asset, 3)
+            .unwrap();
+        check_balance_for_total_fees_and_transfers(&transaction, &state)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn fees_of_sponsored_transaction_are_paid_by_sponsor() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        let transaction = sponsored_transaction(3);
+        let fee_asset: asset::Denom = "nria".parse().unwrap();
+
+        state
+            .put_account_balance(transaction.address_bytes(), &fee_asset, 3)
+            .unwrap();
+        check_balance_for_total_fees_and_transfers(&transaction, &state)
+            .await
+            .unwrap_err();
+        state
+            .put_account_balance(transaction.fee_payer_address_bytes(), &fee_asset, 3)
+            .unwrap();
+        state
+            .put_account_balance(transaction.address_bytes(), &fee_asset, 0)
+            .unwrap();
+        check_balance_for_total_fees_and_transfers(&transaction, &state)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn sponsor_limit_must_cover_fees() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        let transaction = sponsored_transaction(3);
+        let fee_asset: asset::Denom = "nria".parse().unwrap();
+
+        check_sponsor_limit(&transaction, &state).await.unwrap_err();
+        state
+            .put_sponsor_limit(
+                transaction.fee_payer_address_bytes(),
+                transaction.address_bytes(),
+                &fee_asset,
+                2,
+            )
+            .unwrap();
+        check_sponsor_limit(&transaction, &state).await.unwrap_err();
+        state
+            .put_sponsor_limit(
+                transaction.fee_payer_address_bytes(),
+                transaction.address_bytes(),
+                &fee_asset,
+                3,
+            )
+            .unwrap();
+        check_sponsor_limit(&transaction, &state).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn unsponsored_transaction_needs_no_sponsor_limit() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let state = StateDelta::new(storage.latest_snapshot());
+
+        check_sponsor_limit(&transaction(0, "astria-chat", 1), &state)
+            .await
+            .unwrap();
+    }
+
+    fn session_key_transaction(action: Action) -> Transaction {
+        TransactionBody::builder()
+            .nonce(0)
+            .chain_id("astria-chat")
+            .actions(vec![action])
+            .granter(astria_address(&[9; 20]))
+            .try_build()
+            .unwrap()
+            .sign(&SigningKey::from([1; 32]))
+    }
+
+    fn send_text() -> Action {
+        Action::Text(SendText {
+            text: "gm".to_string(),
+            from: "alice".to_string(),
+            fee_asset: "nria".parse().unwrap(),
+        })
+    }
+
+    fn expiring_transaction(
+        valid_until_height: Option<u32>,
+        valid_until_seconds: Option<i64>,
+    ) -> Transaction {
+        TransactionBody::builder()
+            .nonce(0)
+            .chain_id("astria-chat")
+            .actions(vec![send_text()])
+            .valid_until_height(valid_until_height)
+            .valid_until_timestamp(
+                valid_until_seconds.map(|seconds| pbjson_types::Timestamp { seconds, nanos: 0 }),
+            )
+            .try_build()
+            .unwrap()
+            .sign(&SigningKey::from([1; 32]))
+    }
+
+    #[tokio::test]
+    async fn transaction_must_not_be_expired_by_height() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        let transaction = expiring_transaction(Some(10), None);
+
+        state.put_block_height(9).unwrap();
+        check_expiry(&transaction, &state).await.unwrap();
+        state.put_block_height(10).unwrap();
+        check_expiry(&transaction, &state).await.unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn transaction_must_not_be_expired_by_timestamp() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        let transaction = expiring_transaction(None, Some(100));
+
+        state
+            .put_block_timestamp(pbjson_types::Timestamp {
+                seconds: 100,
+                nanos: 0,
+            })
+            .unwrap();
+        check_expiry(&transaction, &state).await.unwrap();
+        state
+            .put_block_timestamp(pbjson_types::Timestamp {
+                seconds: 100,
+                nanos: 1,
+            })
+            .unwrap();
+        check_expiry(&transaction, &state).await.unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn transaction_without_expiry_never_expires() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let state = StateDelta::new(storage.latest_snapshot());
+
+        check_expiry(&expiring_transaction(None, None), &state)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn session_key_must_be_granted_by_granter() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_block_height(1).unwrap();
+        let transaction = session_key_transaction(send_text());
+
+        check_session_key(&transaction, &state).await.unwrap_err();
+        state
+            .put_session_key(
+                &[9; 20],
+                transaction.signer_address_bytes(),
+                &SessionKey {
+                    actions: vec![ActionKind::SendText],
+                    expires_at_height: 10,
+                },
+            )
+            .unwrap();
+        check_session_key(&transaction, &state).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn session_key_must_not_be_expired() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        let transaction = session_key_transaction(send_text());
+        state
+            .put_session_key(
+                &[9; 20],
+                transaction.signer_address_bytes(),
+                &SessionKey {
+                    actions: vec![ActionKind::SendText],
+                    expires_at_height: 10,
+                },
+            )
+            .unwrap();
+
+        // like `valid_until_height`, the expiry height is the last height the key can sign at
+        state.put_block_height(9).unwrap();
+        check_session_key(&transaction, &state).await.unwrap();
+        state.put_block_height(10).unwrap();
+        check_session_key(&transaction, &state).await.unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn session_key_must_permit_all_actions() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_block_height(1).unwrap();
+        let transaction = session_key_transaction(Action::Transfer(Transfer {
+            to: astria_address(&[3; 20]),
+            amount: 1,
+            asset: "nria".parse().unwrap(),
+            fee_asset: "nria".parse().unwrap(),
+        }));
+        state
+            .put_session_key(
+                &[9; 20],
+                transaction.signer_address_bytes(),
+                &SessionKey {
+                    actions: vec![ActionKind::SendText],
+                    expires_at_height: 10,
+                },
+            )
+            .unwrap();
+
+        check_session_key(&transaction, &state).await.unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn transaction_without_granter_needs_no_session_key() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let state = StateDelta::new(storage.latest_snapshot());
+
+        check_session_key(&transaction(0, "astria-chat", 1), &state)
+            .await
+            .unwrap();
+    }
+
+    fn multisig_transaction(signers: &[u8]) -> Transaction {
+        let (first, rest) = signers.split_first().unwrap();
+        let mut transaction = TransactionBody::builder()
+            .nonce(0)
+            .chain_id("astria-chat")
+            .actions(vec![send_text()])
+            .multisig(astria_address(&[7; 20]))
+            .try_build()
+            .unwrap()
+            .sign(&SigningKey::from([*first; 32]));
+        for signer in rest {
+            transaction = transaction
+                .cosign(&SigningKey::from([*signer; 32]))
+                .unwrap();
+        }
+        transaction
+    }
+
+    fn put_multisig(state: &mut StateDelta<cnidarium::Snapshot>) {
+        let members = (1..=3)
+            .map(|i| *SigningKey::from([i; 32]).verification_key().address_bytes())
+            .collect();
+        state
+            .put_multisig(
+                &[7; 20],
+                &Multisig {
+                    members,
+                    threshold: 2,
+                },
+            )
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn multisig_must_be_registered() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        let transaction = multisig_transaction(&[1, 2]);
+
+        check_multisig(&transaction, &state).await.unwrap_err();
+        put_multisig(&mut state);
+        check_multisig(&transaction, &state).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn multisig_requires_threshold_of_signatures() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        put_multisig(&mut state);
+
+        check_multisig(&multisig_transaction(&[1]), &state)
+            .await
+            .unwrap_err();
+        check_multisig(&multisig_transaction(&[3, 1]), &state)
+            .await
+            .unwrap();
+        check_multisig(&multisig_transaction(&[1, 2, 3]), &state)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn multisig_rejects_signatures_of_non_members() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        put_multisig(&mut state);
+
+        check_multisig(&multisig_transaction(&[1, 2, 4]), &state)
+            .await
+            .unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn compute_units_must_stay_within_limit() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        let transaction = transaction(0, "astria-chat", 3);
+        let units = metering::estimated_transaction_units(&transaction);
+
+        // no limit unless one is set
+        check_compute_units(&transaction, &state).await.unwrap();
+        state
+            .put_compute_params(&ComputeParams {
+                max_units_per_transaction: units - 1,
+                unit_price: 0,
+            })
+            .unwrap();
+        check_compute_units(&transaction, &state).await.unwrap_err();
+        state
+            .put_compute_params(&ComputeParams {
+                max_units_per_transaction: units,
+                unit_price: 0,
+            })
+            .unwrap();
+        check_compute_units(&transaction, &state).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn balance_must_cover_compute_fees() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        let transaction = transaction(0, "astria-chat", 3);
+        let fee_asset: asset::Denom = "nria".parse().unwrap();
+        state
+            .put_compute_params(&ComputeParams {
+                max_units_per_transaction: 1_000_000,
+                unit_price: 2,
+            })
+            .unwrap();
+        let fees = 3 + 2 * u128::from(metering::estimated_transaction_units(&transaction));
+
+        state
+            .put_account_balance(transaction.address_bytes(), &fee_asset, fees - 1)
+            .unwrap();
+        check_balance_for_total_fees_and_transfers(&transaction, &state)
+            .await
+            .unwrap_err();
+        state
+            .put_account_balance(transaction.address_bytes(), &fee_asset, fees)
+            .unwrap();
+        check_balance_for_total_fees_and_transfers(&transaction, &state)
+            .await
+            .unwrap();
+    }
+}
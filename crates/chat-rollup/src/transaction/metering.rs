@@ -0,0 +1,479 @@
+//! Prices actions in compute units, so that heavy actions pay proportionally.
+//!
+//! An action uses compute units for the state entries it reads and writes and for the bytes it
+//! writes. They are counted while the action is executed, by running it against a
+//! [`MeteredState`], and the fee payer is charged for them once all actions of a transaction
+//! succeeded. The units of a transaction are limited by
+//! [`ComputeParams::max_units_per_transaction`] and priced at [`ComputeParams::unit_price`].
+//!
+//! The units are only known once an action is executed, but the mempool and the relayer must
+//! check a transaction's fees before that. They use the estimate of [`estimated_action_units`],
+//! which charges every kind of action for the state it is expected to touch. A transaction whose
+//! counted units exceed the limit, or whose fee payer cannot pay for them, fails at execution.
+
+use std::{
+    any::{Any, TypeId},
+    collections::{BTreeMap, HashMap},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+
+use astria_core::primitive::v1::asset;
+use astria_eyre::eyre::{OptionExt as _, Result};
+use cnidarium::{StateRead, StateWrite};
+use futures::{stream::BoxStream, Stream, StreamExt as _};
+use rollup_core::{
+    genesis::v1::ComputeParams,
+    transaction::v1::{Action, Transaction},
+};
+
+/// The units used to read a state entry.
+pub(crate) const UNITS_PER_READ: u64 = 10;
+/// The units used to write a state entry.
+pub(crate) const UNITS_PER_WRITE: u64 = 10;
+/// The units used per byte written to state.
+pub(crate) const UNITS_PER_BYTE_WRITTEN: u64 = 1;
+
+/// The size of a stored balance or amount.
+const AMOUNT_BYTES: u64 = 16;
+/// The size of a stored public key.
+const PUBLIC_KEY_BYTES: u64 = 32;
+/// The size of a stored text id or count of texts posted.
+const COUNTER_BYTES: u64 = 8;
+
+/// The state an action reads and writes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct Usage {
+    pub(crate) reads: u64,
+    pub(crate) writes: u64,
+    pub(crate) bytes_written: u64,
+}
+
+impl Usage {
+    /// Returns the compute units used for this state access.
+    pub(crate) fn units(self) -> u64 {
+        let Self {
+            reads,
+            writes,
+            bytes_written,
+        } = self;
+        reads
+            .saturating_mul(UNITS_PER_READ)
+            .saturating_add(writes.saturating_mul(UNITS_PER_WRITE))
+            .saturating_add(bytes_written.saturating_mul(UNITS_PER_BYTE_WRITTEN))
+    }
+}
+
+fn len(bytes: usize) -> u64 {
+    u64::try_from(bytes).unwrap_or(u64::MAX)
+}
+
+/// The state accessed through a [`MeteredState`], shared with the streams it returns.
+#[derive(Debug, Default)]
+struct Counters {
+    reads: AtomicU64,
+    writes: AtomicU64,
+    bytes_written: AtomicU64,
+}
+
+impl Counters {
+    fn read(&self) {
+        self.reads.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn write(&self, bytes: usize) {
+        self.writes.fetch_add(1, Ordering::Relaxed);
+        self.bytes_written.fetch_add(len(bytes), Ordering::Relaxed);
+    }
+}
+
+/// Counts the state entries read and written through it, and the bytes written.
+///
+/// Every entry returned by a prefix or range read counts as a read, and a deletion as a write of
+/// no bytes. Ephemeral objects and events are not part of the state and are not counted.
+pub(crate) struct MeteredState<S> {
+    state: S,
+    counters: Arc<Counters>,
+}
+
+impl<S> MeteredState<S> {
+    pub(crate) fn new(state: S) -> Self {
+        Self {
+            state,
+            counters: Arc::default(),
+        }
+    }
+
+    /// Returns the state read and written so far.
+    pub(crate) fn usage(&self) -> Usage {
+        Usage {
+            reads: self.counters.reads.load(Ordering::Relaxed),
+            writes: self.counters.writes.load(Ordering::Relaxed),
+            bytes_written: self.counters.bytes_written.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Counts every item of `stream` as a read.
+fn counted<T>(stream: T, counters: &Arc<Counters>) -> BoxStream<'static, T::Item>
+where
+    T: Stream + Send + 'static,
+{
+    let counters = Arc::clone(counters);
+    stream.inspect(move |_| counters.read()).boxed()
+}
+
+impl<S: StateRead> StateRead for MeteredState<S> {
+    type GetRawFut = S::GetRawFut;
+    type NonconsensusPrefixRawStream =
+        BoxStream<'static, <S::NonconsensusPrefixRawStream as Stream>::Item>;
+    type NonconsensusRangeRawStream =
+        BoxStream<'static, <S::NonconsensusRangeRawStream as Stream>::Item>;
+    type PrefixKeysStream = BoxStream<'static, <S::PrefixKeysStream as Stream>::Item>;
+    type PrefixRawStream = BoxStream<'static, <S::PrefixRawStream as Stream>::Item>;
+
+    fn get_raw(&self, key: &str) -> Self::GetRawFut {
+        self.counters.read();
+        self.state.get_raw(key)
+    }
+
+    fn nonverifiable_get_raw(&self, key: &[u8]) -> Self::GetRawFut {
+        self.counters.read();
+        self.state.nonverifiable_get_raw(key)
+    }
+
+    fn object_get<T: Any + Send + Sync + Clone>(&self, key: &'static str) -> Option<T> {
+        self.state.object_get(key)
+    }
+
+    fn object_type(&self, key: &'static str) -> Option<TypeId> {
+        self.state.object_type(key)
+    }
+
+    fn prefix_raw(&self, prefix: &str) -> Self::PrefixRawStream {
+        counted(self.state.prefix_raw(prefix), &self.counters)
+    }
+
+    fn prefix_keys(&self, prefix: &str) -> Self::PrefixKeysStream {
+        counted(self.state.prefix_keys(prefix), &self.counters)
+    }
+
+    fn nonverifiable_prefix_raw(&self, prefix: &[u8]) -> Self::NonconsensusPrefixRawStream {
+        counted(self.state.nonverifiable_prefix_raw(prefix), &self.counters)
+    }
+
+    fn nonverifiable_range_raw(
+        &self,
+        prefix: Option<&[u8]>,
+        range: impl std::ops::RangeBounds<Vec<u8>>,
+    ) -> anyhow::Result<Self::NonconsensusRangeRawStream> {
+        self.state
+            .nonverifiable_range_raw(prefix, range)
+            .map(|stream| counted(stream, &self.counters))
+    }
+}
+
+impl<S: StateWrite> StateWrite for MeteredState<S> {
+    fn put_raw(&mut self, key: String, value: Vec<u8>) {
+        self.counters.write(value.len());
+        self.state.put_raw(key, value);
+    }
+
+    fn delete(&mut self, key: String) {
+        self.counters.write(0);
+        self.state.delete(key);
+    }
+
+    fn nonverifiable_put_raw(&mut self, key: Vec<u8>, value: Vec<u8>) {
+        self.counters.write(value.len());
+        self.state.nonverifiable_put_raw(key, value);
+    }
+
+    fn nonverifiable_delete(&mut self, key: Vec<u8>) {
+        self.counters.write(0);
+        self.state.nonverifiable_delete(key);
+    }
+
+    fn object_put<T: Clone + Any + Send + Sync>(&mut self, key: &'static str, value: T) {
+        self.state.object_put(key, value);
+    }
+
+    fn object_delete(&mut self, key: &'static str) {
+        self.state.object_delete(key);
+    }
+
+    fn object_merge(
+        &mut self,
+        objects: BTreeMap<&'static str, Option<Box<dyn Any + Send + Sync>>>,
+    ) {
+        self.state.object_merge(objects);
+    }
+
+    fn record(&mut self, event: tendermint::abci::Event) {
+        self.state.record(event);
+    }
+}
+
+/// Returns the state an action of this kind is expected to read and write.
+fn estimated_usage(action: &Action) -> Usage {
+    match action {
+        // the text params, the text, the last text id, the texts posted by the sender and the
+        // fee payer's balance
+        Action::Text(send_text) => Usage {
+            reads: 5,
+            writes: 4,
+            bytes_written: len(send_text.text.len())
+                .saturating_add(len(send_text.from.len()))
+                .saturating_add(2 * COUNTER_BYTES)
+                .saturating_add(AMOUNT_BYTES),
+        },
+        // the balances of sender and recipient
+        Action::Transfer(_) => Usage {
+            reads: 2,
+            writes: 2,
+            bytes_written: 2 * AMOUNT_BYTES,
+        },
+        Action::SetSponsorLimit(_) => Usage {
+            reads: 0,
+            writes: 1,
+            bytes_written: AMOUNT_BYTES,
+        },
+        // the session key with the actions it may sign and its expiry height
+        Action::GrantSessionKey(grant_session_key) => Usage {
+            reads: 0,
+            writes: 1,
+            bytes_written: PUBLIC_KEY_BYTES
+                .saturating_add(len(grant_session_key.actions.len()))
+                .saturating_add(4),
+        },
+        Action::RevokeSessionKey(_) => Usage {
+            reads: 0,
+            writes: 1,
+            bytes_written: 0,
+        },
+        // the member keys and the threshold, after checking the account does not exist yet
+        Action::RegisterMultisig(register_multisig) => Usage {
+            reads: 1,
+            writes: 1,
+            bytes_written: PUBLIC_KEY_BYTES
+                .saturating_mul(len(register_multisig.public_keys().len()))
+                .saturating_add(4),
+        },
+    }
+}
+
+/// Returns an estimate of the compute units `action` uses, for checking its fees before it is
+/// executed.
+pub(crate) fn estimated_action_units(action: &Action) -> u64 {
+    estimated_usage(action).units()
+}
+
+/// Returns an estimate of the compute units all actions of `transaction` use.
+pub(crate) fn estimated_transaction_units(transaction: &Transaction) -> u64 {
+    transaction
+        .actions()
+        .iter()
+        .map(estimated_action_units)
+        .fold(0, u64::saturating_add)
+}
+
+/// Returns the asset the compute units of `action` are paid in: its fee asset, or the native
+/// asset for actions without one.
+fn fee_asset(action: &Action) -> asset::IbcPrefixed {
+    match action {
+        Action::Text(send_text) => send_text.fee_asset.to_ibc_prefixed(),
+        Action::Transfer(transfer) => transfer.fee_asset.to_ibc_prefixed(),
+        Action::SetSponsorLimit(_)
+        | Action::GrantSessionKey(_)
+        | Action::RevokeSessionKey(_)
+        | Action::RegisterMultisig(_) => crate::accounts::state_ext::nria().to_ibc_prefixed(),
+    }
+}
+
+/// Returns the amount of each asset spent on `units` compute units used by each of `actions`.
+fn compute_fees<'a>(
+    actions: impl IntoIterator<Item = (&'a Action, u64)>,
+    params: &ComputeParams,
+) -> Result<HashMap<asset::IbcPrefixed, u128>> {
+    let mut totals = HashMap::new();
+    if params.unit_price == 0 {
+        return Ok(totals);
+    }
+    for (action, units) in actions {
+        let fee = u128::from(units) * u128::from(params.unit_price);
+        let total: &mut u128 = totals.entry(fee_asset(action)).or_default();
+        *total = total
+            .checked_add(fee)
+            .ok_or_eyre("compute fees of transaction overflowed")?;
+    }
+    Ok(totals)
+}
+
+/// Returns an estimate of the amount of each asset spent on the compute units of
+/// `transaction`.
+pub(crate) fn estimated_compute_fees(
+    transaction: &Transaction,
+    params: &ComputeParams,
+) -> Result<HashMap<asset::IbcPrefixed, u128>> {
+    compute_fees(
+        transaction
+            .actions()
+            .iter()
+            .map(|action| (action, estimated_action_units(action))),
+        params,
+    )
+}
+
+/// Returns the amount of each asset spent on the compute units the actions of `transaction`
+/// used, given the `usages` counted while executing each of them.
+pub(crate) fn used_compute_fees(
+    transaction: &Transaction,
+    usages: &[Usage],
+    params: &ComputeParams,
+) -> Result<HashMap<asset::IbcPrefixed, u128>> {
+    compute_fees(
+        transaction
+            .actions()
+            .iter()
+            .zip(usages)
+            .map(|(action, usage)| (action, usage.units())),
+        params,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::crypto::SigningKey;
+    use cnidarium::StateDelta;
+    use futures::TryStreamExt as _;
+    use rollup_core::transaction::v1::{
+        action::{SendText, SetSponsorLimit},
+        TransactionBody,
+    };
+
+    use super::*;
+    use crate::accounts::state_ext::astria_address;
+
+    fn send_text(text: &str) -> Action {
+        Action::Text(SendText {
+            text: text.to_string(),
+            from: "alice".to_string(),
+            fee_asset: "ntia".parse().unwrap(),
+        })
+    }
+
+    fn transaction(actions: Vec<Action>) -> Transaction {
+        TransactionBody::builder()
+            .nonce(0)
+            .chain_id("astria-chat")
+            .actions(actions)
+            .try_build()
+            .unwrap()
+            .sign(&SigningKey::from([1; 32]))
+    }
+
+    #[tokio::test]
+    async fn metered_state_counts_reads_and_writes() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_raw("a/1".to_string(), vec![1]);
+        state.put_raw("a/2".to_string(), vec![2, 2]);
+
+        let mut metered = MeteredState::new(&mut state);
+        metered.get_raw("a/1").await.unwrap();
+        let entries: Vec<_> = metered.prefix_raw("a/").try_collect().await.unwrap();
+        assert_eq!(entries.len(), 2);
+        metered.put_raw("b".to_string(), vec![0; 5]);
+        metered.delete("a/1".to_string());
+        let usage = metered.usage();
+        assert_eq!(
+            usage,
+            Usage {
+                reads: 3,
+                writes: 2,
+                bytes_written: 5,
+            }
+        );
+        assert_eq!(
+            usage.units(),
+            3 * UNITS_PER_READ + 2 * UNITS_PER_WRITE + 5 * UNITS_PER_BYTE_WRITTEN
+        );
+        // the writes went through to the wrapped state
+        assert_eq!(state.get_raw("b").await.unwrap(), Some(vec![0; 5]));
+        assert_eq!(state.get_raw("a/1").await.unwrap(), None);
+    }
+
+    #[test]
+    fn longer_texts_are_estimated_to_use_more_units() {
+        let short = estimated_action_units(&send_text("gm"));
+        let long = estimated_action_units(&send_text(&"gm".repeat(500)));
+        assert_eq!(long - short, 998 * UNITS_PER_BYTE_WRITTEN);
+        assert_eq!(
+            short,
+            5 * UNITS_PER_READ + 4 * UNITS_PER_WRITE + 39 * UNITS_PER_BYTE_WRITTEN
+        );
+    }
+
+    #[test]
+    fn estimated_transaction_units_sum_its_actions() {
+        let actions = vec![send_text("gm"), send_text("gn")];
+        let expected = estimated_action_units(&actions[0]) + estimated_action_units(&actions[1]);
+        assert_eq!(estimated_transaction_units(&transaction(actions)), expected);
+    }
+
+    #[test]
+    fn compute_fees_are_priced_per_unit_in_the_fee_asset() {
+        let set_sponsor_limit = Action::SetSponsorLimit(SetSponsorLimit {
+            sponsored: astria_address(&[2; 20]),
+            asset: "ntia".parse().unwrap(),
+            limit: 1,
+        });
+        let transaction = transaction(vec![send_text("gm"), set_sponsor_limit.clone()]);
+
+        let unpriced = ComputeParams::default();
+        assert!(estimated_compute_fees(&transaction, &unpriced)
+            .unwrap()
+            .is_empty());
+
+        let params = ComputeParams {
+            max_units_per_transaction: 1_000,
+            unit_price: 3,
+        };
+        let fees = estimated_compute_fees(&transaction, &params).unwrap();
+        let ntia: asset::Denom = "ntia".parse().unwrap();
+        assert_eq!(
+            fees[&ntia.to_ibc_prefixed()],
+            u128::from(estimated_action_units(&send_text("gm"))) * 3
+        );
+        // actions without a fee asset pay in the native asset
+        assert_eq!(
+            fees[&crate::accounts::state_ext::nria().to_ibc_prefixed()],
+            u128::from(estimated_action_units(&set_sponsor_limit)) * 3
+        );
+
+        // once executed, the actions pay for the state they were counted to access
+        let usages = [
+            Usage {
+                reads: 1,
+                writes: 0,
+                bytes_written: 0,
+            },
+            Usage {
+                reads: 0,
+                writes: 1,
+                bytes_written: 2,
+            },
+        ];
+        let fees = used_compute_fees(&transaction, &usages, &params).unwrap();
+        assert_eq!(
+            fees[&ntia.to_ibc_prefixed()],
+            u128::from(UNITS_PER_READ) * 3
+        );
+        assert_eq!(
+            fees[&crate::accounts::state_ext::nria().to_ibc_prefixed()],
+            u128::from(UNITS_PER_WRITE + 2 * UNITS_PER_BYTE_WRITTEN) * 3
+        );
+    }
+}
@@ -0,0 +1,204 @@
+pub(crate) mod checks;
+pub(crate) mod metering;
+pub(crate) mod ordering;
+pub(crate) mod tracker;
+
+use std::collections::HashMap;
+
+use astria_core::primitive::v1::asset;
+use astria_eyre::eyre::{ensure, Result, WrapErr as _};
+use cnidarium::{StateRead, StateWrite};
+use rollup_core::transaction::v1::Transaction;
+
+use crate::{
+    accounts::StateWriteExt as _, rollup::state_ext::StateReadExt as _,
+    sponsors::StateWriteExt as _,
+};
+
+/// How [`check_transaction`] checks the nonce of a transaction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum NonceCheck {
+    /// The nonce must be the nonce of the signer in state, as when executing the transaction.
+    State,
+    /// The nonce is checked by the caller, like the mempool which also accepts the nonces
+    /// following the transactions it has pending.
+    Caller,
+}
+
+/// Runs the checks a transaction must pass to be executed against `state`.
+///
+/// The mempool runs the same checks before forwarding a transaction, with [`NonceCheck::Caller`]
+/// so it can check the nonce against its pending transactions.
+pub(crate) async fn check_transaction<S: StateRead>(
+    transaction: &Transaction,
+    chain_id: &str,
+    state: &S,
+    nonce_check: NonceCheck,
+) -> Result<()> {
+    checks::check_chain_id(transaction, chain_id)?;
+    checks::check_expiry(transaction, state).await?;
+    checks::check_session_key(transaction, state).await?;
+    checks::check_multisig(transaction, state).await?;
+    crate::text::action::check_text_limits(transaction, state).await?;
+    checks::check_compute_units(transaction, state).await?;
+    if nonce_check == NonceCheck::State {
+        checks::check_nonce(transaction, state).await?;
+    }
+    checks::check_balance_for_total_fees_and_transfers(transaction, state).await?;
+    checks::check_sponsor_limit(transaction, state).await?;
+    Ok(())
+}
+
+/// Consumes the nonce of `transaction`, so that it cannot be executed again.
+///
+/// The nonce is consumed once per transaction, no matter how many actions it contains.
+pub(crate) fn consume_nonce<S: StateWrite>(transaction: &Transaction, mut state: S) -> Result<()> {
+    let next_nonce = transaction
+        .nonce()
+        .checked_add(1)
+        .ok_or_else(|| astria_eyre::eyre::eyre!("nonce overflowed"))?;
+    state
+        .put_account_nonce(transaction.address_bytes(), next_nonce)
+        .wrap_err("failed to put account nonce")
+}
+
+/// Spends `fees` of `transaction` from the limit its sponsor set for its signer.
+///
+/// Does nothing if `transaction` is not sponsored.
+async fn spend_sponsor_limit<S: StateWrite>(
+    transaction: &Transaction,
+    fees: HashMap<asset::IbcPrefixed, u128>,
+    state: &mut S,
+) -> Result<()> {
+    if transaction.sponsor_signature().is_none() {
+        return Ok(());
+    }
+    for (asset, fee) in fees {
+        state
+            .decrease_sponsor_limit(
+                transaction.fee_payer_address_bytes(),
+                transaction.address_bytes(),
+                &asset,
+                fee,
+            )
+            .await
+            .wrap_err("failed to spend sponsor limit")?;
+    }
+    Ok(())
+}
+
+/// Spends the flat fees of `transaction` from the limit its sponsor set for its signer.
+///
+/// Does nothing if `transaction` is not sponsored. The fees themselves are paid by its actions.
+pub(crate) async fn spend_sponsor_limit_for_flat_fees<S: StateWrite>(
+    transaction: &Transaction,
+    mut state: S,
+) -> Result<()> {
+    spend_sponsor_limit(transaction, checks::flat_fees(transaction)?, &mut state).await
+}
+
+/// Charges the fee payer of `transaction` for the compute units its actions used, given the
+/// `usages` counted while executing each of them, and spends the fees from the sponsor's limit
+/// if it is sponsored.
+///
+/// # Errors
+/// Returns an error if the actions used more compute units than a transaction may, or if the fee
+/// payer cannot pay for them.
+pub(crate) async fn pay_compute_fees<S: StateWrite>(
+    transaction: &Transaction,
+    usages: &[metering::Usage],
+    mut state: S,
+) -> Result<()> {
+    let params = state
+        .get_compute_params()
+        .await
+        .wrap_err("failed to get compute params")?;
+    let units = usages
+        .iter()
+        .map(|usage| usage.units())
+        .fold(0, u64::saturating_add);
+    ensure!(
+        params.max_units_per_transaction == 0 || units <= params.max_units_per_transaction,
+        "transaction used `{units}` compute units, at most `{}` are allowed",
+        params.max_units_per_transaction,
+    );
+    let fees = metering::used_compute_fees(transaction, usages, &params)?;
+    for (asset, fee) in &fees {
+        state
+            .decrease_balance(transaction.fee_payer_address_bytes(), asset, *fee)
+            .await
+            .wrap_err("failed to charge compute fees")?;
+    }
+    spend_sponsor_limit(transaction, fees, &mut state).await
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::crypto::SigningKey;
+    use cnidarium::StateDelta;
+    use rollup_core::{
+        genesis::v1::ComputeParams,
+        transaction::v1::{action::SendText, Action, TransactionBody},
+    };
+
+    use super::*;
+    use crate::{
+        accounts::{StateReadExt as _, StateWriteExt as _},
+        rollup::state_ext::StateWriteExt as _,
+    };
+
+    fn transaction() -> Transaction {
+        TransactionBody::builder()
+            .nonce(0)
+            .chain_id("astria-chat")
+            .actions(vec![Action::Text(SendText {
+                text: "gm".to_string(),
+                from: "alice".to_string(),
+                fee_asset: "nria".parse().unwrap(),
+            })])
+            .try_build()
+            .unwrap()
+            .sign(&SigningKey::from([1; 32]))
+    }
+
+    #[tokio::test]
+    async fn compute_fees_are_paid_for_the_units_used() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        let transaction = transaction();
+        let nria = crate::accounts::state_ext::nria();
+        state
+            .put_account_balance(transaction.address_bytes(), &nria, 100)
+            .unwrap();
+        state
+            .put_compute_params(&ComputeParams {
+                max_units_per_transaction: 20,
+                unit_price: 2,
+            })
+            .unwrap();
+        let read = metering::Usage {
+            reads: 1,
+            ..metering::Usage::default()
+        };
+
+        pay_compute_fees(&transaction, &[read], &mut state)
+            .await
+            .unwrap();
+        assert_eq!(
+            state
+                .get_account_balance(transaction.address_bytes(), &nria)
+                .await
+                .unwrap(),
+            100 - 2 * u128::from(metering::UNITS_PER_READ)
+        );
+
+        // three reads use more units than a transaction may
+        let reads = metering::Usage {
+            reads: 3,
+            ..metering::Usage::default()
+        };
+        pay_compute_fees(&transaction, &[reads], &mut state)
+            .await
+            .unwrap_err();
+    }
+}
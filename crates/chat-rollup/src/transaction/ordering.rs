@@ -0,0 +1,178 @@
+//! Orders the transactions of a block for execution.
+//!
+//! Transactions are executed by the [`Group`] of their actions: sudo transactions before general
+//! ones, and within each, unbundleable transactions before bundleable ones. An unbundleable
+//! transaction carries a single action, so its effect is in place before any transaction of a
+//! lower priority group runs. Within a group, transactions keep the order they were sequenced
+//! in, and transactions that could not be decoded run last. The order depends on nothing but
+//! the block, so every node executes the same order.
+//!
+//! A transaction never runs ahead of a transaction of the same account with a lower nonce. It is
+//! moved down to the lowest priority group of those transactions, as otherwise a sudo transaction
+//! with nonce `n + 1` would run before the general transaction with nonce `n` and fail. Within a
+//! group, the transactions of an account take the places of its transactions in the sequenced
+//! order, but run in the order of their nonces.
+
+use std::{collections::HashMap, hash::Hash};
+
+use rollup_core::transaction::v1::action::group::Group;
+
+/// Sorts the `transactions` of a block, given in sequenced order, into the order they are
+/// executed. `key` returns the group of a transaction together with the account whose nonce it
+/// uses and that nonce, or `None` if the transaction could not be decoded.
+pub(crate) fn sort_for_execution<T, A: Eq + Hash>(
+    transactions: Vec<T>,
+    key: impl Fn(&T) -> Option<(Group, A, u32)>,
+) -> Vec<T> {
+    let keys: Vec<_> = transactions.iter().map(key).collect();
+
+    // the groups of each account's transactions by nonce, where every entry is lowered to the
+    // lowest priority group of the transactions with the same or a lower nonce
+    let mut groups_by_nonce: HashMap<&A, Vec<(u32, Group)>> = HashMap::new();
+    for (group, account, nonce) in keys.iter().flatten() {
+        groups_by_nonce
+            .entry(account)
+            .or_default()
+            .push((*nonce, *group));
+    }
+    for groups in groups_by_nonce.values_mut() {
+        groups.sort_unstable();
+        let mut lowest = Group::UnbundleableSudo;
+        for (_, group) in groups.iter_mut() {
+            lowest = lowest.max(*group);
+            *group = lowest;
+        }
+    }
+    let groups: Vec<_> = keys
+        .iter()
+        .map(|key| {
+            let (_, account, nonce) = key.as_ref()?;
+            let groups = &groups_by_nonce[account];
+            let last = groups.partition_point(|(other, _)| other <= nonce) - 1;
+            Some(groups[last].1)
+        })
+        .collect();
+
+    let mut order: Vec<_> = (0..transactions.len()).collect();
+    // the sort is stable, so transactions of the same group keep their sequenced order
+    order.sort_by_key(|&index| (groups[index].is_none(), groups[index]));
+
+    // the transactions of each account are put into the places of its transactions in order of
+    // their nonces. As the groups of an account never rise with its nonces, every transaction
+    // stays within its group
+    let mut places_by_account: HashMap<&A, Vec<usize>> = HashMap::new();
+    for (place, &index) in order.iter().enumerate() {
+        if let Some((_, account, _)) = &keys[index] {
+            places_by_account.entry(account).or_default().push(place);
+        }
+    }
+    for places in places_by_account.values() {
+        let mut indices: Vec<_> = places.iter().map(|&place| order[place]).collect();
+        indices.sort_by_key(|&index| keys[index].as_ref().map(|(_, _, nonce)| *nonce));
+        for (&place, index) in places.iter().zip(indices) {
+            order[place] = index;
+        }
+    }
+
+    let mut places = vec![0; order.len()];
+    for (place, index) in order.into_iter().enumerate() {
+        places[index] = place;
+    }
+    let mut transactions: Vec<_> = places.into_iter().zip(transactions).collect();
+    transactions.sort_unstable_by_key(|(place, _)| *place);
+    transactions
+        .into_iter()
+        .map(|(_, transaction)| transaction)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the sequenced indices of transactions of `groups` in execution order, where every
+    /// transaction is sent by its own account.
+    fn execution_order<const N: usize>(groups: [Option<Group>; N]) -> Vec<usize> {
+        let transactions: Vec<_> = groups
+            .into_iter()
+            .enumerate()
+            .map(|(index, group)| (index, group.map(|group| (group, index, 0))))
+            .collect();
+        sort_for_execution(transactions, |(_, key)| *key)
+            .into_iter()
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    #[test]
+    fn sudo_groups_run_before_general_ones() {
+        let groups = [
+            Some(Group::BundleableGeneral),
+            Some(Group::UnbundleableGeneral),
+            Some(Group::BundleableSudo),
+            Some(Group::UnbundleableSudo),
+        ];
+        assert_eq!(execution_order(groups), vec![3, 2, 1, 0]);
+    }
+
+    #[test]
+    fn mixed_block_keeps_sequenced_order_within_groups() {
+        let groups = [
+            Some(Group::BundleableGeneral),
+            None,
+            Some(Group::UnbundleableSudo),
+            Some(Group::BundleableGeneral),
+            Some(Group::UnbundleableGeneral),
+            Some(Group::UnbundleableSudo),
+            None,
+            Some(Group::BundleableSudo),
+            Some(Group::BundleableGeneral),
+        ];
+        assert_eq!(execution_order(groups), vec![2, 5, 7, 4, 0, 3, 8, 1, 6]);
+    }
+
+    #[test]
+    fn general_block_keeps_sequenced_order() {
+        let groups = [Some(Group::BundleableGeneral); 5];
+        assert_eq!(execution_order(groups), vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn transactions_never_run_ahead_of_lower_nonces_of_their_account() {
+        let transactions = vec![
+            (Group::BundleableGeneral, 'a', 0),
+            (Group::UnbundleableSudo, 'a', 1),
+            (Group::UnbundleableSudo, 'b', 0),
+            (Group::BundleableSudo, 'a', 2),
+            (Group::UnbundleableGeneral, 'b', 1),
+            (Group::UnbundleableSudo, 'b', 2),
+        ];
+        let order: Vec<_> = sort_for_execution(transactions, |&key| Some(key))
+            .into_iter()
+            .map(|(_, account, nonce)| (account, nonce))
+            .collect();
+        assert_eq!(
+            order,
+            vec![('b', 0), ('b', 1), ('b', 2), ('a', 0), ('a', 1), ('a', 2),]
+        );
+    }
+
+    #[test]
+    fn transactions_of_an_account_run_in_nonce_order_within_a_group() {
+        let transactions = vec![
+            (Group::BundleableGeneral, 'a', 2),
+            (Group::BundleableGeneral, 'b', 0),
+            (Group::BundleableGeneral, 'a', 1),
+            (Group::UnbundleableSudo, 'c', 0),
+            (Group::BundleableGeneral, 'a', 0),
+        ];
+        let order: Vec<_> = sort_for_execution(transactions, |&key| Some(key))
+            .into_iter()
+            .map(|(_, account, nonce)| (account, nonce))
+            .collect();
+        assert_eq!(
+            order,
+            vec![('c', 0), ('a', 0), ('b', 0), ('a', 1), ('a', 2)]
+        );
+    }
+}
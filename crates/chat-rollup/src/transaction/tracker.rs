@@ -0,0 +1,328 @@
+//! Tracks the lifecycle of transactions from their submission until their block is firm.
+//!
+//! A transaction is `submitted` once it was forwarded to the composer, `sequenced` once it was
+//! executed in a rollup block, and `soft` or `firm` once the commitment state reaches that block.
+//! A submitted transaction is `dropped` if the submission backend gave up forwarding it.
+//! Transactions are only held in memory until they are firm; after that their status follows
+//! from their receipt and the commitment state.
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use astria_eyre::eyre::{Result, WrapErr as _};
+use cnidarium::StateRead;
+use tokio::sync::broadcast;
+
+use crate::{receipts::StateReadExt as _, rollup::state_ext::StateReadExt as _};
+
+/// How long a submitted transaction is tracked if it is never sequenced, for example because it
+/// was dropped by the sequencer, and how long a dropped transaction is tracked.
+const SUBMITTED_TTL: Duration = Duration::from_secs(600);
+
+/// How many status updates are buffered for subscribers before the slowest one lags behind.
+const UPDATES_CAPACITY: usize = 1024;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum TransactionStatus {
+    Submitted,
+    Dropped,
+    Sequenced { height: u32 },
+    Soft { height: u32 },
+    Firm { height: u32 },
+}
+
+impl TransactionStatus {
+    /// Returns the status of a transaction executed at `height` given the soft and firm heights
+    /// of the commitment state.
+    fn at_height(height: u32, soft: u32, firm: u32) -> Self {
+        if height <= firm {
+            Self::Firm { height }
+        } else if height <= soft {
+            Self::Soft { height }
+        } else {
+            Self::Sequenced { height }
+        }
+    }
+
+    fn height(self) -> Option<u32> {
+        match self {
+            Self::Submitted | Self::Dropped => None,
+            Self::Sequenced { height } | Self::Soft { height } | Self::Firm { height } => {
+                Some(height)
+            }
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Submitted => "submitted",
+            Self::Dropped => "dropped",
+            Self::Sequenced { .. } => "sequenced",
+            Self::Soft { .. } => "soft",
+            Self::Firm { .. } => "firm",
+        }
+    }
+
+    pub(crate) fn is_final(self) -> bool {
+        matches!(self, Self::Dropped | Self::Firm { .. })
+    }
+
+    pub(crate) fn to_json(self, transaction_hash: &[u8; 32]) -> serde_json::Value {
+        serde_json::json!({
+            "hash": hex::encode(transaction_hash),
+            "status": self.name(),
+            "height": self.height(),
+        })
+    }
+}
+
+/// A change of the status of a tracked transaction.
+#[derive(Clone, Debug)]
+pub(crate) struct StatusUpdate {
+    pub(crate) transaction_hash: [u8; 32],
+    pub(crate) status: TransactionStatus,
+}
+
+#[derive(Clone)]
+pub(crate) struct TransactionTracker {
+    tracked: Arc<Mutex<HashMap<[u8; 32], Tracked>>>,
+    updates: broadcast::Sender<StatusUpdate>,
+    submitted_ttl: Duration,
+}
+
+struct Tracked {
+    status: TransactionStatus,
+    updated_at: Instant,
+}
+
+impl TransactionTracker {
+    pub(crate) fn new() -> Self {
+        let (updates, _) = broadcast::channel(UPDATES_CAPACITY);
+        Self {
+            tracked: Arc::new(Mutex::new(HashMap::new())),
+            updates,
+            submitted_ttl: SUBMITTED_TTL,
+        }
+    }
+
+    /// Returns a receiver of all status updates made after this call.
+    pub(crate) fn subscribe(&self) -> broadcast::Receiver<StatusUpdate> {
+        self.updates.subscribe()
+    }
+
+    /// Records that the transaction with `transaction_hash` was forwarded to the composer.
+    pub(crate) fn submitted(&self, transaction_hash: [u8; 32]) {
+        let mut tracked = self.lock();
+        // the transaction might already have been sequenced if it was submitted twice, while a
+        // dropped transaction is tracked again
+        if tracked
+            .get(&transaction_hash)
+            .is_some_and(|entry| entry.status != TransactionStatus::Dropped)
+        {
+            return;
+        }
+        self.update(&mut tracked, transaction_hash, TransactionStatus::Submitted);
+    }
+
+    /// Records that the submitted transaction with `transaction_hash` was dropped before it was
+    /// forwarded. A transaction which was sequenced in the meantime keeps its status.
+    pub(crate) fn dropped(&self, transaction_hash: [u8; 32]) {
+        let mut tracked = self.lock();
+        if tracked.get(&transaction_hash).map(|entry| entry.status)
+            != Some(TransactionStatus::Submitted)
+        {
+            return;
+        }
+        self.update(&mut tracked, transaction_hash, TransactionStatus::Dropped);
+    }
+
+    /// Records that the transactions with `transaction_hashes` were executed in the block at
+    /// `height`.
+    pub(crate) fn sequenced(&self, transaction_hashes: &[[u8; 32]], height: u32) {
+        let mut tracked = self.lock();
+        for transaction_hash in transaction_hashes {
+            self.update(
+                &mut tracked,
+                *transaction_hash,
+                TransactionStatus::Sequenced { height },
+            );
+        }
+    }
+
+    /// Advances the tracked transactions to the new `soft` and `firm` heights of the commitment
+    /// state. Transactions which became firm are no longer tracked.
+    pub(crate) fn commitment_updated(&self, soft: u32, firm: u32) {
+        let now = Instant::now();
+        let mut tracked = self.lock();
+        let updates: Vec<_> = tracked
+            .iter()
+            .filter_map(|(transaction_hash, entry)| {
+                let height = entry.status.height()?;
+                let status = TransactionStatus::at_height(height, soft, firm);
+                (status != entry.status).then_some((*transaction_hash, status))
+            })
+            .collect();
+        for (transaction_hash, status) in updates {
+            self.update(&mut tracked, transaction_hash, status);
+        }
+        tracked.retain(|_, entry| match entry.status {
+            TransactionStatus::Submitted | TransactionStatus::Dropped => {
+                now.duration_since(entry.updated_at) < self.submitted_ttl
+            }
+            status => !status.is_final(),
+        });
+    }
+
+    /// Returns the status of the transaction with `transaction_hash`, falling back to its receipt
+    /// in `state` if it is not tracked anymore. Returns `None` if the transaction is unknown.
+    pub(crate) async fn status<S: StateRead>(
+        &self,
+        transaction_hash: &[u8; 32],
+        state: &S,
+    ) -> Result<Option<TransactionStatus>> {
+        let tracked_status = self.lock().get(transaction_hash).map(|entry| entry.status);
+        if tracked_status.is_some() {
+            return Ok(tracked_status);
+        }
+        let Some(receipt) = state
+            .get_receipt(transaction_hash)
+            .await
+            .wrap_err("failed to get receipt")?
+        else {
+            return Ok(None);
+        };
+        let commitment = state
+            .get_commitment_state()
+            .await
+            .wrap_err("failed to get commitment state")?;
+        Ok(Some(TransactionStatus::at_height(
+            receipt.height,
+            commitment.soft,
+            commitment.firm,
+        )))
+    }
+
+    fn update(
+        &self,
+        tracked: &mut HashMap<[u8; 32], Tracked>,
+        transaction_hash: [u8; 32],
+        status: TransactionStatus,
+    ) {
+        tracked.insert(
+            transaction_hash,
+            Tracked {
+                status,
+                updated_at: Instant::now(),
+            },
+        );
+        // there being no subscribers is not an error
+        let _ = self.updates.send(StatusUpdate {
+            transaction_hash,
+            status,
+        });
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<[u8; 32], Tracked>> {
+        self.tracked
+            .lock()
+            .expect("transaction tracker lock must not be poisoned")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cnidarium::StateDelta;
+
+    use super::*;
+    use crate::{
+        receipts::{Receipt, StateWriteExt as _},
+        rollup::state_ext::StateWriteExt as _,
+    };
+
+    #[test]
+    fn transaction_advances_with_commitment_state() {
+        let tracker = TransactionTracker::new();
+        let mut updates = tracker.subscribe();
+
+        tracker.submitted([1; 32]);
+        tracker.sequenced(&[[1; 32]], 5);
+        tracker.commitment_updated(5, 4);
+        tracker.commitment_updated(5, 5);
+
+        let statuses: Vec<_> = std::iter::from_fn(|| updates.try_recv().ok())
+            .map(|update| update.status)
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![
+                TransactionStatus::Submitted,
+                TransactionStatus::Sequenced { height: 5 },
+                TransactionStatus::Soft { height: 5 },
+                TransactionStatus::Firm { height: 5 },
+            ]
+        );
+        // firm transactions are not tracked anymore
+        assert!(tracker.lock().is_empty());
+    }
+
+    #[test]
+    fn dropped_transaction_can_be_submitted_again() {
+        let tracker = TransactionTracker::new();
+
+        tracker.submitted([1; 32]);
+        tracker.dropped([1; 32]);
+        assert_eq!(
+            tracker.lock().get(&[1; 32]).map(|entry| entry.status),
+            Some(TransactionStatus::Dropped),
+        );
+        tracker.submitted([1; 32]);
+        assert_eq!(
+            tracker.lock().get(&[1; 32]).map(|entry| entry.status),
+            Some(TransactionStatus::Submitted),
+        );
+
+        // a sequenced transaction is not dropped anymore
+        tracker.sequenced(&[[2; 32]], 5);
+        tracker.dropped([2; 32]);
+        assert_eq!(
+            tracker.lock().get(&[2; 32]).map(|entry| entry.status),
+            Some(TransactionStatus::Sequenced { height: 5 }),
+        );
+    }
+
+    #[test]
+    fn submitted_transactions_expire() {
+        let mut tracker = TransactionTracker::new();
+        tracker.submitted_ttl = Duration::ZERO;
+
+        tracker.submitted([1; 32]);
+        tracker.commitment_updated(0, 0);
+        assert!(tracker.lock().is_empty());
+    }
+
+    #[tokio::test]
+    async fn untracked_status_follows_from_receipt() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_commitment_state(3, 2, 0).unwrap();
+        state
+            .put_receipt(&Receipt {
+                transaction_hash: [1; 32],
+                height: 3,
+                index: 0,
+                error: None,
+            })
+            .await
+            .unwrap();
+        let tracker = TransactionTracker::new();
+
+        assert_eq!(
+            tracker.status(&[1; 32], &state).await.unwrap(),
+            Some(TransactionStatus::Soft { height: 3 }),
+        );
+        assert_eq!(tracker.status(&[2; 32], &state).await.unwrap(), None);
+    }
+}
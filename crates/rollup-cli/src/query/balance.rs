@@ -1,5 +1,7 @@
 use astria_core::primitive::v1::{asset, Address};
-use color_eyre::eyre;
+use color_eyre::eyre::{self, eyre};
+
+use crate::utils::{parse_block_hash, proof_url, verify_state_proof, ProvenValue};
 
 #[derive(clap::Args, Debug)]
 pub(super) struct Command {
@@ -22,10 +24,23 @@ pub(super) struct Command {
     /// The asset to transer.
     #[arg(long, default_value = "nria")]
     asset: asset::Denom,
+    /// Verify the balance against the state root of a block
+    #[arg(long, requires_all = ["height", "block_hash"])]
+    prove: bool,
+    /// The height of the block to verify the balance at
+    #[arg(long, requires = "prove")]
+    height: Option<u64>,
+    /// The hex encoded hash of the block at `height`, taken from a source trusted to report it
+    /// correctly. The state root returned by the rollup is checked against it
+    #[arg(long, requires = "prove", value_parser = parse_block_hash)]
+    block_hash: Option<[u8; 32]>,
 }
 
 impl Command {
     pub(super) async fn run(self) -> eyre::Result<()> {
+        if self.prove {
+            return self.run_with_proof().await;
+        }
         let response = reqwest::Client::new()
             .get(format!(
                 "{}/get_account_balance/{}/{}",
@@ -43,4 +58,34 @@ impl Command {
             Err(e) => Err(eyre::eyre!(e)),
         }
     }
+
+    async fn run_with_proof(self) -> eyre::Result<()> {
+        let url = format!(
+            "{}/get_account_balance/{}/{}",
+            self.rollup_url, self.address, self.asset
+        );
+        let response: ProvenValue<String> = reqwest::Client::new()
+            .get(proof_url(url, self.height))
+            .send()
+            .await?
+            .json()
+            .await?;
+        let block_hash = self
+            .block_hash
+            .ok_or_else(|| eyre!("a trusted block hash is required to verify the proof"))?;
+        verify_state_proof(&self.rollup_url, &response.proof, &block_hash).await?;
+        let balance = chat_rollup::accounts::proof::decode_balance(response.proof.value.as_deref())
+            .map_err(|err| eyre!("failed to decode proven balance: {err:#}"))?;
+        if balance.to_string() != response.value {
+            return Err(eyre!(
+                "returned balance {} does not match proven balance {balance}",
+                response.value
+            ));
+        }
+        println!(
+            "Balance: {}{} (verified at height {})",
+            balance, self.asset, response.proof.height
+        );
+        Ok(())
+    }
 }
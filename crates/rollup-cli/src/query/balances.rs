@@ -0,0 +1,49 @@
+use astria_core::primitive::v1::Address;
+use color_eyre::eyre;
+use serde_json::Value;
+
+#[derive(clap::Args, Debug)]
+pub(super) struct Command {
+    // The address to get the balances of
+    address: Address,
+    /// The url of the Rollup node
+    #[arg(
+        long,
+        env = "ROLLUP_URL",
+        default_value = crate::DEFAULT_SEQUENCER_RPC
+    )]
+    rollup_url: String,
+}
+
+impl Command {
+    pub(super) async fn run(self) -> eyre::Result<()> {
+        let response = reqwest::Client::new()
+            .get(format!(
+                "{}/accounts/{}/balances",
+                self.rollup_url, self.address
+            ))
+            .send()
+            .await?
+            .error_for_status()?;
+
+        let balances: Value = response.json().await?;
+        let Some(balances) = balances.as_array() else {
+            return Err(eyre::eyre!("response is not an array"));
+        };
+        if balances.is_empty() {
+            println!("No balances");
+        }
+        for balance in balances {
+            let denom = balance
+                .get("denom")
+                .and_then(|d| d.as_str())
+                .unwrap_or("Unknown");
+            let amount = balance
+                .get("balance")
+                .and_then(|b| b.as_str())
+                .unwrap_or("0");
+            println!("Balance: {}{}", amount, denom);
+        }
+        Ok(())
+    }
+}
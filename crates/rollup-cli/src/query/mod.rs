@@ -1,6 +1,7 @@
 use clap::Subcommand;
 use color_eyre::eyre;
 mod balance;
+mod balances;
 mod nonce;
 mod texts;
 
@@ -14,6 +15,7 @@ impl Command {
     pub(super) async fn run(self) -> eyre::Result<()> {
         match self.command {
             SubCommand::Balance(balance) => balance.run().await,
+            SubCommand::Balances(balances) => balances.run().await,
             SubCommand::Nonce(nonce) => nonce.run().await,
             SubCommand::Texts(texts) => texts.run().await,
             // SubCommand::Submit(submit) => submit.run().await,
@@ -25,6 +27,7 @@ impl Command {
 #[derive(Debug, Subcommand)]
 enum SubCommand {
     Balance(balance::Command),
+    Balances(balances::Command),
     Nonce(nonce::Command),
     Texts(texts::Command),
 }
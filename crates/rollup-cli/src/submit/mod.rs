@@ -1,5 +1,6 @@
 use clap::Subcommand;
 use color_eyre::eyre;
+mod sponsor_limit;
 mod text;
 mod transfer;
 
@@ -14,6 +15,7 @@ impl Command {
         match self.command {
             SubCommand::Transfer(transfer) => transfer.run().await,
             SubCommand::Text(send_text) => send_text.run().await,
+            SubCommand::SponsorLimit(sponsor_limit) => sponsor_limit.run().await,
             // SubCommand::Submit(submit) => submit.run().await,
         }
     }
@@ -24,4 +26,6 @@ impl Command {
 enum SubCommand {
     Transfer(transfer::Command),
     Text(text::Command),
+    /// Set how much of the fees of an account are paid by the signer
+    SponsorLimit(sponsor_limit::Command),
 }
@@ -0,0 +1,69 @@
+use astria_core::primitive::v1::{asset, Address};
+use color_eyre::eyre::{self, WrapErr as _};
+use rollup_core::transaction::v1::{action::SetSponsorLimit, Action};
+
+use crate::utils::submit_transaction;
+
+#[derive(clap::Args, Debug)]
+pub(super) struct Command {
+    // The address of the Rollup account whose fees are paid
+    sponsored_address: Address,
+    // The amount of fees paid at most; zero revokes the sponsorship
+    #[arg(long)]
+    limit: u128,
+    /// The bech32m prefix that will be used for constructing addresses using the private key
+    #[arg(long, default_value = "astria")]
+    prefix: String,
+    /// The private key of the sponsoring account
+    #[arg(long, env = "PRIVATE_KEY")]
+    // TODO: https://github.com/astriaorg/astria/issues/594
+    // Don't use a plain text private, prefer wrapper like from
+    // the secrecy crate with specialized `Debug` and `Drop` implementations
+    // that overwrite the key on drop and don't reveal it when printing.
+    private_key: String,
+    /// The url of the Sequencer node
+    #[arg(
+        long,
+        env = "ROLLUP_URL",
+        default_value = crate::DEFAULT_SEQUENCER_RPC
+    )]
+    rollup_url: String,
+    /// The chain id of the rollup chain being used
+    #[arg(
+        long = "chain-id",
+        env = "ROLLUP_CHAIN_ID",
+        default_value = crate::DEFAULT_SEQUENCER_CHAIN_ID
+    )]
+    chain_id: String,
+    /// The asset the fees are paid with.
+    #[arg(long, default_value = "nria")]
+    asset: asset::Denom,
+}
+
+impl Command {
+    pub(super) async fn run(self) -> eyre::Result<()> {
+        let res = submit_transaction(
+            self.rollup_url.as_str(),
+            self.chain_id.clone(),
+            &self.prefix,
+            self.private_key.as_str(),
+            Action::SetSponsorLimit(SetSponsorLimit {
+                sponsored: self.sponsored_address,
+                asset: self.asset,
+                limit: self.limit,
+            }),
+        )
+        .await
+        .wrap_err("failed to submit SetSponsorLimit transaction")?;
+        if res.status().is_success() {
+            println!("SetSponsorLimit Transaction completed!");
+            Ok(())
+        } else {
+            println!(
+                "SetSponsorLimit Transaction failed: {:?}",
+                res.error_for_status()
+            );
+            Err(eyre::eyre!("SetSponsorLimit failed"))
+        }
+    }
+}
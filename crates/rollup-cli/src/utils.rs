@@ -7,6 +7,8 @@ use color_eyre::eyre::{self, eyre, WrapErr as _};
 use prost::Message;
 use reqwest::Response;
 use rollup_core::generated::protocol::transaction::v1::Transaction;
+use rollup_core::{block::BlockHeader, proof::StateProof};
+use serde::Deserialize;
 
 pub(crate) async fn submit_transaction(
     rollup_url: &str,
@@ -78,3 +80,90 @@ pub(crate) fn address_from_signing_key(
     // Return the generated address
     Ok(from_address)
 }
+
+/// A value returned by the rollup together with a proof against the state root of a block.
+#[derive(Debug, Deserialize)]
+pub(crate) struct ProvenValue<T> {
+    pub(crate) value: T,
+    pub(crate) proof: StateProof,
+}
+
+/// Builds the url of a state read that returns a [`ProvenValue`].
+pub(crate) fn proof_url(url: String, height: Option<u64>) -> String {
+    match height {
+        Some(height) => format!("{url}?prove=true&height={height}"),
+        None => format!("{url}?prove=true"),
+    }
+}
+
+/// Parses the hex encoded hash of a block.
+pub(crate) fn parse_block_hash(hash: &str) -> Result<[u8; 32], hex::FromHexError> {
+    hex::FromHex::from_hex(hash)
+}
+
+/// Decodes the hex encoded 32 byte `field` of a block response.
+fn decode_root(field: &str, value: &str) -> eyre::Result<[u8; 32]> {
+    hex::decode(value)
+        .wrap_err_with(|| format!("failed to decode {field} from hex string"))?
+        .try_into()
+        .map_err(|_| eyre!("invalid {field} length; must be 32 bytes"))
+}
+
+/// A block as returned by the rollup's `/block/{height}` endpoint.
+#[derive(Debug, Deserialize)]
+struct BlockResponse {
+    number: u32,
+    parent_block_hash: String,
+    transactions_root: String,
+    deposits_root: String,
+    timestamp: BlockTimestamp,
+    state_root: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct BlockTimestamp {
+    seconds: i64,
+    nanos: i32,
+}
+
+/// Fetches the block `proof` was made against and verifies `proof` with its state root.
+///
+/// The rollup node is not trusted: the hash of the block is recomputed from the returned header
+/// and state root, and must match `trusted_block_hash`, which the caller obtained elsewhere (for
+/// example from the sequencer or from several replicas).
+pub(crate) async fn verify_state_proof(
+    rollup_url: &str,
+    proof: &StateProof,
+    trusted_block_hash: &[u8; 32],
+) -> eyre::Result<()> {
+    let block: BlockResponse = reqwest::Client::new()
+        .get(format!("{}/block/{}", rollup_url, proof.height))
+        .send()
+        .await?
+        .error_for_status()
+        .wrap_err("failed to fetch the block the proof was made against")?
+        .json()
+        .await
+        .wrap_err("failed to decode block response")?;
+    let state_root = decode_root("state root", &block.state_root)?;
+    let header = BlockHeader {
+        number: block.number,
+        parent_block_hash: hex::decode(&block.parent_block_hash)
+            .wrap_err("failed to decode parent block hash from hex string")?
+            .into(),
+        transactions_root: decode_root("transactions root", &block.transactions_root)?,
+        deposits_root: decode_root("deposits root", &block.deposits_root)?,
+        timestamp: pbjson_types::Timestamp {
+            seconds: block.timestamp.seconds,
+            nanos: block.timestamp.nanos,
+        },
+    };
+    if &header.hash(&state_root) != trusted_block_hash {
+        return Err(eyre!(
+            "the block the proof was made against does not match the trusted block hash"
+        ));
+    }
+    proof
+        .verify_against(&state_root)
+        .wrap_err("failed to verify state proof against the block state root")
+}
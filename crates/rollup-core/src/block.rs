@@ -0,0 +1,73 @@
+//! The blocks executed by the rollup.
+//!
+//! The hash of a block commits to its header and to the root of the state resulting from
+//! executing it. A client knowing the hash of a block from a source it trusts can therefore
+//! check a state root returned by a rollup node by recomputing the hash with [`BlockHeader::hash`]
+//! and comparing it to the trusted one.
+
+use astria_core::generated::astria::execution::v1::Block;
+use bytes::Bytes;
+use prost::Message as _;
+use sha2::Digest as _;
+
+/// The part of a block that is known before its state is committed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlockHeader {
+    pub number: u32,
+    pub parent_block_hash: Bytes,
+    /// The merkle root of the transactions included in the block.
+    pub transactions_root: [u8; 32],
+    /// The merkle root of the source transaction ids of the deposits included in the block.
+    pub deposits_root: [u8; 32],
+    pub timestamp: pbjson_types::Timestamp,
+}
+
+impl BlockHeader {
+    /// Returns the hash of the block with this header, committing to `state_root`.
+    #[must_use]
+    pub fn hash(&self, state_root: &[u8; 32]) -> [u8; 32] {
+        let mut hasher = sha2::Sha256::new();
+        hasher.update(&self.parent_block_hash);
+        hasher.update(self.transactions_root);
+        hasher.update(self.deposits_root);
+        hasher.update(state_root);
+        hasher.update(self.timestamp.encode_to_vec());
+        hasher.finalize().into()
+    }
+
+    /// Returns the block with this header that resulted in `state_root`.
+    #[must_use]
+    pub fn into_block(self, state_root: &[u8; 32]) -> Block {
+        let hash = self.hash(state_root);
+        Block {
+            number: self.number,
+            hash: Bytes::copy_from_slice(&hash),
+            parent_block_hash: self.parent_block_hash,
+            timestamp: Some(self.timestamp),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn header(number: u32) -> BlockHeader {
+        BlockHeader {
+            number,
+            parent_block_hash: Bytes::from_static(&[1; 32]),
+            transactions_root: [2; 32],
+            deposits_root: [3; 32],
+            timestamp: pbjson_types::Timestamp {
+                seconds: number.into(),
+                nanos: 0,
+            },
+        }
+    }
+
+    #[test]
+    fn hash_commits_to_state_root() {
+        assert_ne!(header(1).hash(&[4; 32]), header(1).hash(&[5; 32]));
+        assert_ne!(header(1).hash(&[4; 32]), header(2).hash(&[4; 32]));
+    }
+}
@@ -12,12 +12,23 @@ pub struct GenesisAppState {
     pub celestia_block_variance: u64,
     #[prost(message, repeated, tag = "5")]
     pub accounts: ::prost::alloc::vec::Vec<Account>,
-    #[prost(message, repeated, tag = "6")]
-    pub bridge_accounts:
-        ::prost::alloc::vec::Vec<::astria_core::generated::astria::primitive::v1::Address>,
+    /// The account authorized to execute sudo actions. It is validated but not
+    /// used yet, as the rollup has no sudo actions.
     #[prost(message, optional, tag = "7")]
     pub authority_sudo_address:
         ::core::option::Option<::astria_core::generated::astria::primitive::v1::Address>,
+    /// Limits on posting texts. Unset means no limits.
+    #[prost(message, optional, tag = "8")]
+    pub text_params: ::core::option::Option<TextParams>,
+    /// Limits on the resources a block can use. Unset means no limits.
+    #[prost(message, optional, tag = "9")]
+    pub block_params: ::core::option::Option<BlockParams>,
+    /// Metering of the compute units used by actions. Unset means actions are not
+    /// metered.
+    #[prost(message, optional, tag = "10")]
+    pub compute_params: ::core::option::Option<ComputeParams>,
+    #[prost(message, repeated, tag = "11")]
+    pub bridge_accounts: ::prost::alloc::vec::Vec<BridgeAccount>,
 }
 impl ::prost::Name for GenesisAppState {
     const NAME: &'static str = "GenesisAppState";
@@ -29,6 +40,95 @@ impl ::prost::Name for GenesisAppState {
         "/genesis.v1.GenesisAppState".into()
     }
 }
+/// `TextParams` limits how much an account can post, so that the chat cannot
+/// be flooded by an account with a small balance. A limit of zero means no
+/// limit.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TextParams {
+    /// The number of texts an account can post within a window of
+    /// `window_blocks` blocks. Windows start at multiples of `window_blocks`.
+    #[prost(uint32, tag = "1")]
+    pub max_texts_per_window: u32,
+    /// The number of blocks in a window. Must be set if `max_texts_per_window`
+    /// is set.
+    #[prost(uint32, tag = "2")]
+    pub window_blocks: u32,
+    /// The maximum length of a text in bytes.
+    #[prost(uint32, tag = "3")]
+    pub max_text_length: u32,
+    /// The maximum number of actions in a transaction.
+    #[prost(uint32, tag = "4")]
+    pub max_actions_per_transaction: u32,
+}
+impl ::prost::Name for TextParams {
+    const NAME: &'static str = "TextParams";
+    const PACKAGE: &'static str = "genesis.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "genesis.v1.TextParams".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/genesis.v1.TextParams".into()
+    }
+}
+/// `BlockParams` limit the resources a rollup block can use, so that an
+/// oversized block cannot overwhelm the rollup nodes. A transaction that does
+/// not fit into the rest of a block is skipped together with the later
+/// transactions of its sender, and one too large for any block fails. Deposits
+/// beyond the limit are refunded. A limit of zero means no limit.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BlockParams {
+    /// The maximum number of transactions executed in a block.
+    #[prost(uint32, tag = "1")]
+    pub max_transactions: u32,
+    /// The maximum total size of the encoded transactions executed in a block.
+    #[prost(uint64, tag = "2")]
+    pub max_transaction_bytes: u64,
+    /// The maximum total length of the texts posted by transactions in a block.
+    /// Must not exceed `max_transaction_bytes` if that is set.
+    #[prost(uint64, tag = "3")]
+    pub max_text_bytes: u64,
+    /// The maximum number of deposits executed in a block.
+    #[prost(uint32, tag = "4")]
+    pub max_deposits: u32,
+}
+impl ::prost::Name for BlockParams {
+    const NAME: &'static str = "BlockParams";
+    const PACKAGE: &'static str = "genesis.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "genesis.v1.BlockParams".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/genesis.v1.BlockParams".into()
+    }
+}
+/// `ComputeParams` meter the work an action causes, so that heavy actions pay
+/// proportionally. Each action uses compute units for the state it reads and
+/// writes and for the bytes it writes, counted while it is executed.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ComputeParams {
+    /// The maximum number of compute units a transaction can use. Zero means no
+    /// limit.
+    #[prost(uint64, tag = "1")]
+    pub max_units_per_transaction: u64,
+    /// The fee charged per compute unit, in the fee asset of the action or in the
+    /// native asset for actions without one. Requires
+    /// `max_units_per_transaction` to be set.
+    #[prost(uint64, tag = "2")]
+    pub unit_price: u64,
+}
+impl ::prost::Name for ComputeParams {
+    const NAME: &'static str = "ComputeParams";
+    const PACKAGE: &'static str = "genesis.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "genesis.v1.ComputeParams".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/genesis.v1.ComputeParams".into()
+    }
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Account {
@@ -47,3 +147,23 @@ impl ::prost::Name for Account {
         "/genesis.v1.Account".into()
     }
 }
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BridgeAccount {
+    #[prost(message, optional, tag = "1")]
+    pub address: ::core::option::Option<::astria_core::generated::astria::primitive::v1::Address>,
+    /// The asset the bridge account locks on the sequencer. Deposits of other
+    /// assets through this bridge account are not credited.
+    #[prost(string, tag = "2")]
+    pub asset: ::prost::alloc::string::String,
+}
+impl ::prost::Name for BridgeAccount {
+    const NAME: &'static str = "BridgeAccount";
+    const PACKAGE: &'static str = "genesis.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "genesis.v1.BridgeAccount".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/genesis.v1.BridgeAccount".into()
+    }
+}
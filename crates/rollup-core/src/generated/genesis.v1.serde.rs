@@ -108,6 +108,424 @@ impl<'de> serde::Deserialize<'de> for Account {
         deserializer.deserialize_struct("genesis.v1.Account", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for BlockParams {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.max_transactions != 0 {
+            len += 1;
+        }
+        if self.max_transaction_bytes != 0 {
+            len += 1;
+        }
+        if self.max_text_bytes != 0 {
+            len += 1;
+        }
+        if self.max_deposits != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("genesis.v1.BlockParams", len)?;
+        if self.max_transactions != 0 {
+            struct_ser.serialize_field("maxTransactions", &self.max_transactions)?;
+        }
+        if self.max_transaction_bytes != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field(
+                "maxTransactionBytes",
+                ToString::to_string(&self.max_transaction_bytes).as_str(),
+            )?;
+        }
+        if self.max_text_bytes != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field(
+                "maxTextBytes",
+                ToString::to_string(&self.max_text_bytes).as_str(),
+            )?;
+        }
+        if self.max_deposits != 0 {
+            struct_ser.serialize_field("maxDeposits", &self.max_deposits)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BlockParams {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "max_transactions",
+            "maxTransactions",
+            "max_transaction_bytes",
+            "maxTransactionBytes",
+            "max_text_bytes",
+            "maxTextBytes",
+            "max_deposits",
+            "maxDeposits",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            MaxTransactions,
+            MaxTransactionBytes,
+            MaxTextBytes,
+            MaxDeposits,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                #[allow(clippy::needless_lifetimes)]
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(
+                        &self,
+                        formatter: &mut std::fmt::Formatter<'_>,
+                    ) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "maxTransactions" | "max_transactions" => {
+                                Ok(GeneratedField::MaxTransactions)
+                            }
+                            "maxTransactionBytes" | "max_transaction_bytes" => {
+                                Ok(GeneratedField::MaxTransactionBytes)
+                            }
+                            "maxTextBytes" | "max_text_bytes" => Ok(GeneratedField::MaxTextBytes),
+                            "maxDeposits" | "max_deposits" => Ok(GeneratedField::MaxDeposits),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        #[allow(clippy::needless_lifetimes)]
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BlockParams;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct genesis.v1.BlockParams")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BlockParams, V::Error>
+            where
+                V: serde::de::MapAccess<'de>,
+            {
+                let mut max_transactions__ = None;
+                let mut max_transaction_bytes__ = None;
+                let mut max_text_bytes__ = None;
+                let mut max_deposits__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::MaxTransactions => {
+                            if max_transactions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxTransactions"));
+                            }
+                            max_transactions__ = Some(
+                                map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?
+                                    .0,
+                            );
+                        }
+                        GeneratedField::MaxTransactionBytes => {
+                            if max_transaction_bytes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field(
+                                    "maxTransactionBytes",
+                                ));
+                            }
+                            max_transaction_bytes__ = Some(
+                                map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?
+                                    .0,
+                            );
+                        }
+                        GeneratedField::MaxTextBytes => {
+                            if max_text_bytes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxTextBytes"));
+                            }
+                            max_text_bytes__ = Some(
+                                map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?
+                                    .0,
+                            );
+                        }
+                        GeneratedField::MaxDeposits => {
+                            if max_deposits__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxDeposits"));
+                            }
+                            max_deposits__ = Some(
+                                map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?
+                                    .0,
+                            );
+                        }
+                    }
+                }
+                Ok(BlockParams {
+                    max_transactions: max_transactions__.unwrap_or_default(),
+                    max_transaction_bytes: max_transaction_bytes__.unwrap_or_default(),
+                    max_text_bytes: max_text_bytes__.unwrap_or_default(),
+                    max_deposits: max_deposits__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("genesis.v1.BlockParams", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for BridgeAccount {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.address.is_some() {
+            len += 1;
+        }
+        if !self.asset.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("genesis.v1.BridgeAccount", len)?;
+        if let Some(v) = self.address.as_ref() {
+            struct_ser.serialize_field("address", v)?;
+        }
+        if !self.asset.is_empty() {
+            struct_ser.serialize_field("asset", &self.asset)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BridgeAccount {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["address", "asset"];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Address,
+            Asset,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                #[allow(clippy::needless_lifetimes)]
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(
+                        &self,
+                        formatter: &mut std::fmt::Formatter<'_>,
+                    ) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "address" => Ok(GeneratedField::Address),
+                            "asset" => Ok(GeneratedField::Asset),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        #[allow(clippy::needless_lifetimes)]
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BridgeAccount;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct genesis.v1.BridgeAccount")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BridgeAccount, V::Error>
+            where
+                V: serde::de::MapAccess<'de>,
+            {
+                let mut address__ = None;
+                let mut asset__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Address => {
+                            if address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("address"));
+                            }
+                            address__ = map_.next_value()?;
+                        }
+                        GeneratedField::Asset => {
+                            if asset__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("asset"));
+                            }
+                            asset__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(BridgeAccount {
+                    address: address__,
+                    asset: asset__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("genesis.v1.BridgeAccount", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ComputeParams {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.max_units_per_transaction != 0 {
+            len += 1;
+        }
+        if self.unit_price != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("genesis.v1.ComputeParams", len)?;
+        if self.max_units_per_transaction != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field(
+                "maxUnitsPerTransaction",
+                ToString::to_string(&self.max_units_per_transaction).as_str(),
+            )?;
+        }
+        if self.unit_price != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser
+                .serialize_field("unitPrice", ToString::to_string(&self.unit_price).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ComputeParams {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "max_units_per_transaction",
+            "maxUnitsPerTransaction",
+            "unit_price",
+            "unitPrice",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            MaxUnitsPerTransaction,
+            UnitPrice,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                #[allow(clippy::needless_lifetimes)]
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(
+                        &self,
+                        formatter: &mut std::fmt::Formatter<'_>,
+                    ) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "maxUnitsPerTransaction" | "max_units_per_transaction" => {
+                                Ok(GeneratedField::MaxUnitsPerTransaction)
+                            }
+                            "unitPrice" | "unit_price" => Ok(GeneratedField::UnitPrice),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        #[allow(clippy::needless_lifetimes)]
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ComputeParams;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct genesis.v1.ComputeParams")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ComputeParams, V::Error>
+            where
+                V: serde::de::MapAccess<'de>,
+            {
+                let mut max_units_per_transaction__ = None;
+                let mut unit_price__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::MaxUnitsPerTransaction => {
+                            if max_units_per_transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field(
+                                    "maxUnitsPerTransaction",
+                                ));
+                            }
+                            max_units_per_transaction__ = Some(
+                                map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?
+                                    .0,
+                            );
+                        }
+                        GeneratedField::UnitPrice => {
+                            if unit_price__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unitPrice"));
+                            }
+                            unit_price__ = Some(
+                                map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?
+                                    .0,
+                            );
+                        }
+                    }
+                }
+                Ok(ComputeParams {
+                    max_units_per_transaction: max_units_per_transaction__.unwrap_or_default(),
+                    unit_price: unit_price__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("genesis.v1.ComputeParams", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for GenesisAppState {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -131,10 +549,19 @@ impl serde::Serialize for GenesisAppState {
         if !self.accounts.is_empty() {
             len += 1;
         }
-        if !self.bridge_accounts.is_empty() {
+        if self.authority_sudo_address.is_some() {
             len += 1;
         }
-        if self.authority_sudo_address.is_some() {
+        if self.text_params.is_some() {
+            len += 1;
+        }
+        if self.block_params.is_some() {
+            len += 1;
+        }
+        if self.compute_params.is_some() {
+            len += 1;
+        }
+        if !self.bridge_accounts.is_empty() {
             len += 1;
         }
         let mut struct_ser = serializer.serialize_struct("genesis.v1.GenesisAppState", len)?;
@@ -163,12 +590,21 @@ impl serde::Serialize for GenesisAppState {
         if !self.accounts.is_empty() {
             struct_ser.serialize_field("accounts", &self.accounts)?;
         }
-        if !self.bridge_accounts.is_empty() {
-            struct_ser.serialize_field("bridgeAccounts", &self.bridge_accounts)?;
-        }
         if let Some(v) = self.authority_sudo_address.as_ref() {
             struct_ser.serialize_field("authoritySudoAddress", v)?;
         }
+        if let Some(v) = self.text_params.as_ref() {
+            struct_ser.serialize_field("textParams", v)?;
+        }
+        if let Some(v) = self.block_params.as_ref() {
+            struct_ser.serialize_field("blockParams", v)?;
+        }
+        if let Some(v) = self.compute_params.as_ref() {
+            struct_ser.serialize_field("computeParams", v)?;
+        }
+        if !self.bridge_accounts.is_empty() {
+            struct_ser.serialize_field("bridgeAccounts", &self.bridge_accounts)?;
+        }
         struct_ser.end()
     }
 }
@@ -188,10 +624,16 @@ impl<'de> serde::Deserialize<'de> for GenesisAppState {
             "celestia_block_variance",
             "celestiaBlockVariance",
             "accounts",
-            "bridge_accounts",
-            "bridgeAccounts",
             "authority_sudo_address",
             "authoritySudoAddress",
+            "text_params",
+            "textParams",
+            "block_params",
+            "blockParams",
+            "compute_params",
+            "computeParams",
+            "bridge_accounts",
+            "bridgeAccounts",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -201,8 +643,11 @@ impl<'de> serde::Deserialize<'de> for GenesisAppState {
             CelestiaGenesisBlockHeight,
             CelestiaBlockVariance,
             Accounts,
-            BridgeAccounts,
             AuthoritySudoAddress,
+            TextParams,
+            BlockParams,
+            ComputeParams,
+            BridgeAccounts,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -239,12 +684,15 @@ impl<'de> serde::Deserialize<'de> for GenesisAppState {
                                 Ok(GeneratedField::CelestiaBlockVariance)
                             }
                             "accounts" => Ok(GeneratedField::Accounts),
-                            "bridgeAccounts" | "bridge_accounts" => {
-                                Ok(GeneratedField::BridgeAccounts)
-                            }
                             "authoritySudoAddress" | "authority_sudo_address" => {
                                 Ok(GeneratedField::AuthoritySudoAddress)
                             }
+                            "textParams" | "text_params" => Ok(GeneratedField::TextParams),
+                            "blockParams" | "block_params" => Ok(GeneratedField::BlockParams),
+                            "computeParams" | "compute_params" => Ok(GeneratedField::ComputeParams),
+                            "bridgeAccounts" | "bridge_accounts" => {
+                                Ok(GeneratedField::BridgeAccounts)
+                            }
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -270,8 +718,11 @@ impl<'de> serde::Deserialize<'de> for GenesisAppState {
                 let mut celestia_genesis_block_height__ = None;
                 let mut celestia_block_variance__ = None;
                 let mut accounts__ = None;
-                let mut bridge_accounts__ = None;
                 let mut authority_sudo_address__ = None;
+                let mut text_params__ = None;
+                let mut block_params__ = None;
+                let mut compute_params__ = None;
+                let mut bridge_accounts__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::RollupName => {
@@ -319,12 +770,6 @@ impl<'de> serde::Deserialize<'de> for GenesisAppState {
                             }
                             accounts__ = Some(map_.next_value()?);
                         }
-                        GeneratedField::BridgeAccounts => {
-                            if bridge_accounts__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("bridgeAccounts"));
-                            }
-                            bridge_accounts__ = Some(map_.next_value()?);
-                        }
                         GeneratedField::AuthoritySudoAddress => {
                             if authority_sudo_address__.is_some() {
                                 return Err(serde::de::Error::duplicate_field(
@@ -333,6 +778,30 @@ impl<'de> serde::Deserialize<'de> for GenesisAppState {
                             }
                             authority_sudo_address__ = map_.next_value()?;
                         }
+                        GeneratedField::TextParams => {
+                            if text_params__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("textParams"));
+                            }
+                            text_params__ = map_.next_value()?;
+                        }
+                        GeneratedField::BlockParams => {
+                            if block_params__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blockParams"));
+                            }
+                            block_params__ = map_.next_value()?;
+                        }
+                        GeneratedField::ComputeParams => {
+                            if compute_params__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("computeParams"));
+                            }
+                            compute_params__ = map_.next_value()?;
+                        }
+                        GeneratedField::BridgeAccounts => {
+                            if bridge_accounts__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("bridgeAccounts"));
+                            }
+                            bridge_accounts__ = Some(map_.next_value()?);
+                        }
                     }
                 }
                 Ok(GenesisAppState {
@@ -343,11 +812,188 @@ impl<'de> serde::Deserialize<'de> for GenesisAppState {
                         .unwrap_or_default(),
                     celestia_block_variance: celestia_block_variance__.unwrap_or_default(),
                     accounts: accounts__.unwrap_or_default(),
-                    bridge_accounts: bridge_accounts__.unwrap_or_default(),
                     authority_sudo_address: authority_sudo_address__,
+                    text_params: text_params__,
+                    block_params: block_params__,
+                    compute_params: compute_params__,
+                    bridge_accounts: bridge_accounts__.unwrap_or_default(),
                 })
             }
         }
         deserializer.deserialize_struct("genesis.v1.GenesisAppState", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for TextParams {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.max_texts_per_window != 0 {
+            len += 1;
+        }
+        if self.window_blocks != 0 {
+            len += 1;
+        }
+        if self.max_text_length != 0 {
+            len += 1;
+        }
+        if self.max_actions_per_transaction != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("genesis.v1.TextParams", len)?;
+        if self.max_texts_per_window != 0 {
+            struct_ser.serialize_field("maxTextsPerWindow", &self.max_texts_per_window)?;
+        }
+        if self.window_blocks != 0 {
+            struct_ser.serialize_field("windowBlocks", &self.window_blocks)?;
+        }
+        if self.max_text_length != 0 {
+            struct_ser.serialize_field("maxTextLength", &self.max_text_length)?;
+        }
+        if self.max_actions_per_transaction != 0 {
+            struct_ser.serialize_field(
+                "maxActionsPerTransaction",
+                &self.max_actions_per_transaction,
+            )?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TextParams {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "max_texts_per_window",
+            "maxTextsPerWindow",
+            "window_blocks",
+            "windowBlocks",
+            "max_text_length",
+            "maxTextLength",
+            "max_actions_per_transaction",
+            "maxActionsPerTransaction",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            MaxTextsPerWindow,
+            WindowBlocks,
+            MaxTextLength,
+            MaxActionsPerTransaction,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                #[allow(clippy::needless_lifetimes)]
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(
+                        &self,
+                        formatter: &mut std::fmt::Formatter<'_>,
+                    ) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "maxTextsPerWindow" | "max_texts_per_window" => {
+                                Ok(GeneratedField::MaxTextsPerWindow)
+                            }
+                            "windowBlocks" | "window_blocks" => Ok(GeneratedField::WindowBlocks),
+                            "maxTextLength" | "max_text_length" => {
+                                Ok(GeneratedField::MaxTextLength)
+                            }
+                            "maxActionsPerTransaction" | "max_actions_per_transaction" => {
+                                Ok(GeneratedField::MaxActionsPerTransaction)
+                            }
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        #[allow(clippy::needless_lifetimes)]
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TextParams;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct genesis.v1.TextParams")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TextParams, V::Error>
+            where
+                V: serde::de::MapAccess<'de>,
+            {
+                let mut max_texts_per_window__ = None;
+                let mut window_blocks__ = None;
+                let mut max_text_length__ = None;
+                let mut max_actions_per_transaction__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::MaxTextsPerWindow => {
+                            if max_texts_per_window__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxTextsPerWindow"));
+                            }
+                            max_texts_per_window__ = Some(
+                                map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?
+                                    .0,
+                            );
+                        }
+                        GeneratedField::WindowBlocks => {
+                            if window_blocks__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("windowBlocks"));
+                            }
+                            window_blocks__ = Some(
+                                map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?
+                                    .0,
+                            );
+                        }
+                        GeneratedField::MaxTextLength => {
+                            if max_text_length__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxTextLength"));
+                            }
+                            max_text_length__ = Some(
+                                map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?
+                                    .0,
+                            );
+                        }
+                        GeneratedField::MaxActionsPerTransaction => {
+                            if max_actions_per_transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field(
+                                    "maxActionsPerTransaction",
+                                ));
+                            }
+                            max_actions_per_transaction__ = Some(
+                                map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?
+                                    .0,
+                            );
+                        }
+                    }
+                }
+                Ok(TextParams {
+                    max_texts_per_window: max_texts_per_window__.unwrap_or_default(),
+                    window_blocks: window_blocks__.unwrap_or_default(),
+                    max_text_length: max_text_length__.unwrap_or_default(),
+                    max_actions_per_transaction: max_actions_per_transaction__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("genesis.v1.TextParams", FIELDS, GeneratedVisitor)
+    }
+}
@@ -36,4 +36,16 @@ pub mod protocol {
             }
         }
     }
+
+    #[path = ""]
+    pub mod query {
+        pub mod v1 {
+            include!("query.v1.rs");
+
+            mod _serde_impl {
+                use super::*;
+                include!("query.v1.serde.rs");
+            }
+        }
+    }
 }
@@ -2,7 +2,7 @@
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Action {
-    #[prost(oneof = "action::Value", tags = "1, 2")]
+    #[prost(oneof = "action::Value", tags = "1, 2, 3, 4, 5, 6")]
     pub value: ::core::option::Option<action::Value>,
 }
 /// Nested message and enum types in `Action`.
@@ -15,6 +15,14 @@ pub mod action {
         Transfer(super::Transfer),
         #[prost(message, tag = "2")]
         SendText(super::SendText),
+        #[prost(message, tag = "3")]
+        SetSponsorLimit(super::SetSponsorLimit),
+        #[prost(message, tag = "4")]
+        GrantSessionKey(super::GrantSessionKey),
+        #[prost(message, tag = "5")]
+        RevokeSessionKey(super::RevokeSessionKey),
+        #[prost(message, tag = "6")]
+        RegisterMultisig(super::RegisterMultisig),
     }
 }
 impl ::prost::Name for Action {
@@ -78,6 +86,98 @@ impl ::prost::Name for SendText {
         "/transaction.v1.SendText".into()
     }
 }
+/// `SetSponsorLimit` sets how much of `asset` the signer will spend on fees
+/// of transactions sent by `sponsored`. A limit of zero revokes the
+/// sponsorship.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetSponsorLimit {
+    #[prost(message, optional, tag = "1")]
+    pub sponsored: ::core::option::Option<::astria_core::generated::astria::primitive::v1::Address>,
+    #[prost(string, tag = "2")]
+    pub asset: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub limit: ::core::option::Option<::astria_core::generated::astria::primitive::v1::Uint128>,
+}
+impl ::prost::Name for SetSponsorLimit {
+    const NAME: &'static str = "SetSponsorLimit";
+    const PACKAGE: &'static str = "transaction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "transaction.v1.SetSponsorLimit".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/transaction.v1.SetSponsorLimit".into()
+    }
+}
+/// `GrantSessionKey` authorizes `verification_key` to sign transactions with
+/// the listed actions on behalf of the signer, up to and including the
+/// rollup height `expires_at_height`. Granting an already granted key
+/// replaces its grant.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GrantSessionKey {
+    #[prost(bytes = "bytes", tag = "1")]
+    pub verification_key: ::prost::bytes::Bytes,
+    /// The names of the permitted actions, e.g. `SendText`.
+    #[prost(string, repeated, tag = "2")]
+    pub actions: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// The last rollup height at which the session key can sign, inclusive
+    /// like `TransactionParams.valid_until_height`. From the next height on
+    /// the key is expired.
+    #[prost(uint32, tag = "3")]
+    pub expires_at_height: u32,
+}
+impl ::prost::Name for GrantSessionKey {
+    const NAME: &'static str = "GrantSessionKey";
+    const PACKAGE: &'static str = "transaction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "transaction.v1.GrantSessionKey".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/transaction.v1.GrantSessionKey".into()
+    }
+}
+/// `RevokeSessionKey` revokes a session key granted by the signer.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RevokeSessionKey {
+    #[prost(bytes = "bytes", tag = "1")]
+    pub verification_key: ::prost::bytes::Bytes,
+}
+impl ::prost::Name for RevokeSessionKey {
+    const NAME: &'static str = "RevokeSessionKey";
+    const PACKAGE: &'static str = "transaction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "transaction.v1.RevokeSessionKey".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/transaction.v1.RevokeSessionKey".into()
+    }
+}
+/// `RegisterMultisig` registers a multisig account controlled by
+/// `public_keys`, of which `threshold` must sign its transactions. The
+/// address of the account is derived from the keys and the threshold.
+///
+/// `public_keys` are Ed25519 verification keys. Secp256k1 keys cannot be
+/// members, as cosignatures are Ed25519 signatures only.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RegisterMultisig {
+    #[prost(bytes = "bytes", repeated, tag = "1")]
+    pub public_keys: ::prost::alloc::vec::Vec<::prost::bytes::Bytes>,
+    #[prost(uint32, tag = "2")]
+    pub threshold: u32,
+}
+impl ::prost::Name for RegisterMultisig {
+    const NAME: &'static str = "RegisterMultisig";
+    const PACKAGE: &'static str = "transaction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "transaction.v1.RegisterMultisig".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/transaction.v1.RegisterMultisig".into()
+    }
+}
 /// `Transaction` is a transaction `TransactionBody` together with a public
 /// ket and a signature.
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -89,6 +189,16 @@ pub struct Transaction {
     pub public_key: ::prost::bytes::Bytes,
     #[prost(message, optional, tag = "3")]
     pub body: ::core::option::Option<::pbjson_types::Any>,
+    /// Set if and only if the body names a `sponsor`.
+    #[prost(message, optional, tag = "4")]
+    pub sponsor_signature: ::core::option::Option<SponsorSignature>,
+    /// Signatures over the body by further members of the multisig account
+    /// named in the body, in addition to `signature`.
+    #[prost(message, repeated, tag = "5")]
+    pub cosignatures: ::prost::alloc::vec::Vec<Cosignature>,
+    /// The scheme of `signature` and `public_key`.
+    #[prost(enumeration = "SignatureScheme", tag = "6")]
+    pub signature_scheme: i32,
 }
 impl ::prost::Name for Transaction {
     const NAME: &'static str = "Transaction";
@@ -100,6 +210,51 @@ impl ::prost::Name for Transaction {
         "/transaction.v1.Transaction".into()
     }
 }
+/// `Cosignature` is the signature of a further signer of a `Transaction`
+/// over its body. It is always an Ed25519 signature, and `public_key` an
+/// Ed25519 verification key. The transaction of a multisig account must
+/// also be signed with an Ed25519 key.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Cosignature {
+    #[prost(bytes = "bytes", tag = "1")]
+    pub signature: ::prost::bytes::Bytes,
+    #[prost(bytes = "bytes", tag = "2")]
+    pub public_key: ::prost::bytes::Bytes,
+}
+impl ::prost::Name for Cosignature {
+    const NAME: &'static str = "Cosignature";
+    const PACKAGE: &'static str = "transaction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "transaction.v1.Cosignature".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/transaction.v1.Cosignature".into()
+    }
+}
+/// `SponsorSignature` is the signature of the sponsor of a `Transaction`
+/// over its body, agreeing to pay the fees of the transaction.
+///
+/// The sponsor signs the body bytes prefixed by `chat-rollup/sponsor`, so
+/// that its signature cannot be mistaken for the signature of a sender.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SponsorSignature {
+    #[prost(bytes = "bytes", tag = "1")]
+    pub signature: ::prost::bytes::Bytes,
+    #[prost(bytes = "bytes", tag = "2")]
+    pub public_key: ::prost::bytes::Bytes,
+}
+impl ::prost::Name for SponsorSignature {
+    const NAME: &'static str = "SponsorSignature";
+    const PACKAGE: &'static str = "transaction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "transaction.v1.SponsorSignature".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/transaction.v1.SponsorSignature".into()
+    }
+}
 /// The `TransactionBody` of the `Transaction` that is being signed over.
 /// It contains transaction `TransactionParams` and `Actions`.
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -109,6 +264,17 @@ pub struct TransactionBody {
     pub params: ::core::option::Option<TransactionParams>,
     #[prost(message, repeated, tag = "2")]
     pub actions: ::prost::alloc::vec::Vec<Action>,
+    /// The account paying the fees of the transaction in place of its signer.
+    #[prost(message, optional, tag = "3")]
+    pub sponsor: ::core::option::Option<::astria_core::generated::astria::primitive::v1::Address>,
+    /// The account the transaction acts for, if it is signed by a session key
+    /// granted by that account instead of the account's own key.
+    #[prost(message, optional, tag = "4")]
+    pub granter: ::core::option::Option<::astria_core::generated::astria::primitive::v1::Address>,
+    /// The multisig account the transaction acts for, if it is signed by
+    /// members of that account. Cannot be combined with `granter`.
+    #[prost(message, optional, tag = "5")]
+    pub multisig: ::core::option::Option<::astria_core::generated::astria::primitive::v1::Address>,
 }
 impl ::prost::Name for TransactionBody {
     const NAME: &'static str = "TransactionBody";
@@ -129,6 +295,15 @@ pub struct TransactionParams {
     pub nonce: u32,
     #[prost(string, tag = "2")]
     pub chain_id: ::prost::alloc::string::String,
+    /// The last rollup height at which the transaction can be executed,
+    /// inclusive like `GrantSessionKey.expires_at_height`. Zero means the
+    /// transaction does not expire by height.
+    #[prost(uint32, tag = "3")]
+    pub valid_until_height: u32,
+    /// The last block timestamp at which the transaction can be executed. Unset
+    /// means the transaction does not expire by time.
+    #[prost(message, optional, tag = "4")]
+    pub valid_until_timestamp: ::core::option::Option<::pbjson_types::Timestamp>,
 }
 impl ::prost::Name for TransactionParams {
     const NAME: &'static str = "TransactionParams";
@@ -140,3 +315,39 @@ impl ::prost::Name for TransactionParams {
         "/transaction.v1.TransactionParams".into()
     }
 }
+/// `SignatureScheme` is the scheme a `Transaction` is signed with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum SignatureScheme {
+    /// An Ed25519 signature over the body bytes. `public_key` is the 32 byte
+    /// verification key, and the signer's address is the first 20 bytes of its
+    /// sha256 hash.
+    Ed25519 = 0,
+    /// A recoverable secp256k1 signature over the EIP-191 (`personal_sign`) hash
+    /// of the body bytes, as made by Ethereum wallets. `signature` is the 65 byte
+    /// `r || s || v` signature, where `v` is 27 or 28, and `public_key` is either
+    /// empty or the SEC1 encoded key recovered from it. The signer's address is
+    /// its Ethereum address: the last 20 bytes of the keccak256 hash of the
+    /// uncompressed key.
+    Secp256k1 = 1,
+}
+impl SignatureScheme {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            SignatureScheme::Ed25519 => "SIGNATURE_SCHEME_ED25519",
+            SignatureScheme::Secp256k1 => "SIGNATURE_SCHEME_SECP256K1",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SIGNATURE_SCHEME_ED25519" => Some(Self::Ed25519),
+            "SIGNATURE_SCHEME_SECP256K1" => Some(Self::Secp256k1),
+            _ => None,
+        }
+    }
+}
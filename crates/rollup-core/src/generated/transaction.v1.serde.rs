@@ -18,23 +18,836 @@ impl serde::Serialize for Action {
                 action::Value::SendText(v) => {
                     struct_ser.serialize_field("sendText", v)?;
                 }
+                action::Value::SetSponsorLimit(v) => {
+                    struct_ser.serialize_field("setSponsorLimit", v)?;
+                }
+                action::Value::GrantSessionKey(v) => {
+                    struct_ser.serialize_field("grantSessionKey", v)?;
+                }
+                action::Value::RevokeSessionKey(v) => {
+                    struct_ser.serialize_field("revokeSessionKey", v)?;
+                }
+                action::Value::RegisterMultisig(v) => {
+                    struct_ser.serialize_field("registerMultisig", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Action {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "transfer",
+            "send_text",
+            "sendText",
+            "set_sponsor_limit",
+            "setSponsorLimit",
+            "grant_session_key",
+            "grantSessionKey",
+            "revoke_session_key",
+            "revokeSessionKey",
+            "register_multisig",
+            "registerMultisig",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Transfer,
+            SendText,
+            SetSponsorLimit,
+            GrantSessionKey,
+            RevokeSessionKey,
+            RegisterMultisig,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                #[allow(clippy::needless_lifetimes)]
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(
+                        &self,
+                        formatter: &mut std::fmt::Formatter<'_>,
+                    ) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "transfer" => Ok(GeneratedField::Transfer),
+                            "sendText" | "send_text" => Ok(GeneratedField::SendText),
+                            "setSponsorLimit" | "set_sponsor_limit" => {
+                                Ok(GeneratedField::SetSponsorLimit)
+                            }
+                            "grantSessionKey" | "grant_session_key" => {
+                                Ok(GeneratedField::GrantSessionKey)
+                            }
+                            "revokeSessionKey" | "revoke_session_key" => {
+                                Ok(GeneratedField::RevokeSessionKey)
+                            }
+                            "registerMultisig" | "register_multisig" => {
+                                Ok(GeneratedField::RegisterMultisig)
+                            }
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        #[allow(clippy::needless_lifetimes)]
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Action;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct transaction.v1.Action")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Action, V::Error>
+            where
+                V: serde::de::MapAccess<'de>,
+            {
+                let mut value__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Transfer => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transfer"));
+                            }
+                            value__ = map_
+                                .next_value::<::std::option::Option<_>>()?
+                                .map(action::Value::Transfer);
+                        }
+                        GeneratedField::SendText => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sendText"));
+                            }
+                            value__ = map_
+                                .next_value::<::std::option::Option<_>>()?
+                                .map(action::Value::SendText);
+                        }
+                        GeneratedField::SetSponsorLimit => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("setSponsorLimit"));
+                            }
+                            value__ = map_
+                                .next_value::<::std::option::Option<_>>()?
+                                .map(action::Value::SetSponsorLimit);
+                        }
+                        GeneratedField::GrantSessionKey => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("grantSessionKey"));
+                            }
+                            value__ = map_
+                                .next_value::<::std::option::Option<_>>()?
+                                .map(action::Value::GrantSessionKey);
+                        }
+                        GeneratedField::RevokeSessionKey => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("revokeSessionKey"));
+                            }
+                            value__ = map_
+                                .next_value::<::std::option::Option<_>>()?
+                                .map(action::Value::RevokeSessionKey);
+                        }
+                        GeneratedField::RegisterMultisig => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("registerMultisig"));
+                            }
+                            value__ = map_
+                                .next_value::<::std::option::Option<_>>()?
+                                .map(action::Value::RegisterMultisig);
+                        }
+                    }
+                }
+                Ok(Action { value: value__ })
+            }
+        }
+        deserializer.deserialize_struct("transaction.v1.Action", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Cosignature {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.signature.is_empty() {
+            len += 1;
+        }
+        if !self.public_key.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("transaction.v1.Cosignature", len)?;
+        if !self.signature.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field(
+                "signature",
+                pbjson::private::base64::encode(&self.signature).as_str(),
+            )?;
+        }
+        if !self.public_key.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field(
+                "publicKey",
+                pbjson::private::base64::encode(&self.public_key).as_str(),
+            )?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Cosignature {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["signature", "public_key", "publicKey"];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Signature,
+            PublicKey,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                #[allow(clippy::needless_lifetimes)]
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(
+                        &self,
+                        formatter: &mut std::fmt::Formatter<'_>,
+                    ) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "signature" => Ok(GeneratedField::Signature),
+                            "publicKey" | "public_key" => Ok(GeneratedField::PublicKey),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        #[allow(clippy::needless_lifetimes)]
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Cosignature;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct transaction.v1.Cosignature")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Cosignature, V::Error>
+            where
+                V: serde::de::MapAccess<'de>,
+            {
+                let mut signature__ = None;
+                let mut public_key__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Signature => {
+                            if signature__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("signature"));
+                            }
+                            signature__ = Some(
+                                map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?
+                                    .0,
+                            );
+                        }
+                        GeneratedField::PublicKey => {
+                            if public_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("publicKey"));
+                            }
+                            public_key__ = Some(
+                                map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?
+                                    .0,
+                            );
+                        }
+                    }
+                }
+                Ok(Cosignature {
+                    signature: signature__.unwrap_or_default(),
+                    public_key: public_key__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("transaction.v1.Cosignature", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for GrantSessionKey {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.verification_key.is_empty() {
+            len += 1;
+        }
+        if !self.actions.is_empty() {
+            len += 1;
+        }
+        if self.expires_at_height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("transaction.v1.GrantSessionKey", len)?;
+        if !self.verification_key.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field(
+                "verificationKey",
+                pbjson::private::base64::encode(&self.verification_key).as_str(),
+            )?;
+        }
+        if !self.actions.is_empty() {
+            struct_ser.serialize_field("actions", &self.actions)?;
+        }
+        if self.expires_at_height != 0 {
+            struct_ser.serialize_field("expiresAtHeight", &self.expires_at_height)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GrantSessionKey {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "verification_key",
+            "verificationKey",
+            "actions",
+            "expires_at_height",
+            "expiresAtHeight",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            VerificationKey,
+            Actions,
+            ExpiresAtHeight,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                #[allow(clippy::needless_lifetimes)]
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(
+                        &self,
+                        formatter: &mut std::fmt::Formatter<'_>,
+                    ) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "verificationKey" | "verification_key" => {
+                                Ok(GeneratedField::VerificationKey)
+                            }
+                            "actions" => Ok(GeneratedField::Actions),
+                            "expiresAtHeight" | "expires_at_height" => {
+                                Ok(GeneratedField::ExpiresAtHeight)
+                            }
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        #[allow(clippy::needless_lifetimes)]
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GrantSessionKey;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct transaction.v1.GrantSessionKey")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GrantSessionKey, V::Error>
+            where
+                V: serde::de::MapAccess<'de>,
+            {
+                let mut verification_key__ = None;
+                let mut actions__ = None;
+                let mut expires_at_height__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::VerificationKey => {
+                            if verification_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("verificationKey"));
+                            }
+                            verification_key__ = Some(
+                                map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?
+                                    .0,
+                            );
+                        }
+                        GeneratedField::Actions => {
+                            if actions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("actions"));
+                            }
+                            actions__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ExpiresAtHeight => {
+                            if expires_at_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("expiresAtHeight"));
+                            }
+                            expires_at_height__ = Some(
+                                map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?
+                                    .0,
+                            );
+                        }
+                    }
+                }
+                Ok(GrantSessionKey {
+                    verification_key: verification_key__.unwrap_or_default(),
+                    actions: actions__.unwrap_or_default(),
+                    expires_at_height: expires_at_height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("transaction.v1.GrantSessionKey", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RegisterMultisig {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.public_keys.is_empty() {
+            len += 1;
+        }
+        if self.threshold != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("transaction.v1.RegisterMultisig", len)?;
+        if !self.public_keys.is_empty() {
+            struct_ser.serialize_field(
+                "publicKeys",
+                &self
+                    .public_keys
+                    .iter()
+                    .map(pbjson::private::base64::encode)
+                    .collect::<Vec<_>>(),
+            )?;
+        }
+        if self.threshold != 0 {
+            struct_ser.serialize_field("threshold", &self.threshold)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RegisterMultisig {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["public_keys", "publicKeys", "threshold"];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PublicKeys,
+            Threshold,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                #[allow(clippy::needless_lifetimes)]
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(
+                        &self,
+                        formatter: &mut std::fmt::Formatter<'_>,
+                    ) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "publicKeys" | "public_keys" => Ok(GeneratedField::PublicKeys),
+                            "threshold" => Ok(GeneratedField::Threshold),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        #[allow(clippy::needless_lifetimes)]
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RegisterMultisig;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct transaction.v1.RegisterMultisig")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RegisterMultisig, V::Error>
+            where
+                V: serde::de::MapAccess<'de>,
+            {
+                let mut public_keys__ = None;
+                let mut threshold__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PublicKeys => {
+                            if public_keys__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("publicKeys"));
+                            }
+                            public_keys__ = Some(
+                                map_.next_value::<Vec<::pbjson::private::BytesDeserialize<_>>>()?
+                                    .into_iter()
+                                    .map(|x| x.0)
+                                    .collect(),
+                            );
+                        }
+                        GeneratedField::Threshold => {
+                            if threshold__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("threshold"));
+                            }
+                            threshold__ = Some(
+                                map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?
+                                    .0,
+                            );
+                        }
+                    }
+                }
+                Ok(RegisterMultisig {
+                    public_keys: public_keys__.unwrap_or_default(),
+                    threshold: threshold__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("transaction.v1.RegisterMultisig", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RevokeSessionKey {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.verification_key.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("transaction.v1.RevokeSessionKey", len)?;
+        if !self.verification_key.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field(
+                "verificationKey",
+                pbjson::private::base64::encode(&self.verification_key).as_str(),
+            )?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RevokeSessionKey {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["verification_key", "verificationKey"];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            VerificationKey,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                #[allow(clippy::needless_lifetimes)]
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(
+                        &self,
+                        formatter: &mut std::fmt::Formatter<'_>,
+                    ) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "verificationKey" | "verification_key" => {
+                                Ok(GeneratedField::VerificationKey)
+                            }
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        #[allow(clippy::needless_lifetimes)]
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RevokeSessionKey;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct transaction.v1.RevokeSessionKey")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RevokeSessionKey, V::Error>
+            where
+                V: serde::de::MapAccess<'de>,
+            {
+                let mut verification_key__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::VerificationKey => {
+                            if verification_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("verificationKey"));
+                            }
+                            verification_key__ = Some(
+                                map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?
+                                    .0,
+                            );
+                        }
+                    }
+                }
+                Ok(RevokeSessionKey {
+                    verification_key: verification_key__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("transaction.v1.RevokeSessionKey", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SendText {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.text.is_empty() {
+            len += 1;
+        }
+        if !self.from.is_empty() {
+            len += 1;
+        }
+        if !self.fee_asset.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("transaction.v1.SendText", len)?;
+        if !self.text.is_empty() {
+            struct_ser.serialize_field("text", &self.text)?;
+        }
+        if !self.from.is_empty() {
+            struct_ser.serialize_field("from", &self.from)?;
+        }
+        if !self.fee_asset.is_empty() {
+            struct_ser.serialize_field("feeAsset", &self.fee_asset)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SendText {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["text", "from", "fee_asset", "feeAsset"];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Text,
+            From,
+            FeeAsset,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                #[allow(clippy::needless_lifetimes)]
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(
+                        &self,
+                        formatter: &mut std::fmt::Formatter<'_>,
+                    ) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "text" => Ok(GeneratedField::Text),
+                            "from" => Ok(GeneratedField::From),
+                            "feeAsset" | "fee_asset" => Ok(GeneratedField::FeeAsset),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        #[allow(clippy::needless_lifetimes)]
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SendText;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct transaction.v1.SendText")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SendText, V::Error>
+            where
+                V: serde::de::MapAccess<'de>,
+            {
+                let mut text__ = None;
+                let mut from__ = None;
+                let mut fee_asset__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Text => {
+                            if text__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("text"));
+                            }
+                            text__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::From => {
+                            if from__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("from"));
+                            }
+                            from__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::FeeAsset => {
+                            if fee_asset__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeAsset"));
+                            }
+                            fee_asset__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(SendText {
+                    text: text__.unwrap_or_default(),
+                    from: from__.unwrap_or_default(),
+                    fee_asset: fee_asset__.unwrap_or_default(),
+                })
             }
         }
+        deserializer.deserialize_struct("transaction.v1.SendText", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SetSponsorLimit {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.sponsored.is_some() {
+            len += 1;
+        }
+        if !self.asset.is_empty() {
+            len += 1;
+        }
+        if self.limit.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("transaction.v1.SetSponsorLimit", len)?;
+        if let Some(v) = self.sponsored.as_ref() {
+            struct_ser.serialize_field("sponsored", v)?;
+        }
+        if !self.asset.is_empty() {
+            struct_ser.serialize_field("asset", &self.asset)?;
+        }
+        if let Some(v) = self.limit.as_ref() {
+            struct_ser.serialize_field("limit", v)?;
+        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for Action {
+impl<'de> serde::Deserialize<'de> for SetSponsorLimit {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
-        const FIELDS: &[&str] = &["transfer", "send_text", "sendText"];
+        const FIELDS: &[&str] = &["sponsored", "asset", "limit"];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Transfer,
-            SendText,
+            Sponsored,
+            Asset,
+            Limit,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -60,8 +873,9 @@ impl<'de> serde::Deserialize<'de> for Action {
                         E: serde::de::Error,
                     {
                         match value {
-                            "transfer" => Ok(GeneratedField::Transfer),
-                            "sendText" | "send_text" => Ok(GeneratedField::SendText),
+                            "sponsored" => Ok(GeneratedField::Sponsored),
+                            "asset" => Ok(GeneratedField::Asset),
+                            "limit" => Ok(GeneratedField::Limit),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -72,44 +886,121 @@ impl<'de> serde::Deserialize<'de> for Action {
         struct GeneratedVisitor;
         #[allow(clippy::needless_lifetimes)]
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = Action;
+            type Value = SetSponsorLimit;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct transaction.v1.Action")
+                formatter.write_str("struct transaction.v1.SetSponsorLimit")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Action, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SetSponsorLimit, V::Error>
             where
                 V: serde::de::MapAccess<'de>,
             {
-                let mut value__ = None;
+                let mut sponsored__ = None;
+                let mut asset__ = None;
+                let mut limit__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Transfer => {
-                            if value__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("transfer"));
+                        GeneratedField::Sponsored => {
+                            if sponsored__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sponsored"));
                             }
-                            value__ = map_
-                                .next_value::<::std::option::Option<_>>()?
-                                .map(action::Value::Transfer);
+                            sponsored__ = map_.next_value()?;
                         }
-                        GeneratedField::SendText => {
-                            if value__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("sendText"));
+                        GeneratedField::Asset => {
+                            if asset__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("asset"));
                             }
-                            value__ = map_
-                                .next_value::<::std::option::Option<_>>()?
-                                .map(action::Value::SendText);
+                            asset__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Limit => {
+                            if limit__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("limit"));
+                            }
+                            limit__ = map_.next_value()?;
                         }
                     }
                 }
-                Ok(Action { value: value__ })
+                Ok(SetSponsorLimit {
+                    sponsored: sponsored__,
+                    asset: asset__.unwrap_or_default(),
+                    limit: limit__,
+                })
             }
         }
-        deserializer.deserialize_struct("transaction.v1.Action", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("transaction.v1.SetSponsorLimit", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SendText {
+impl serde::Serialize for SignatureScheme {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Ed25519 => "SIGNATURE_SCHEME_ED25519",
+            Self::Secp256k1 => "SIGNATURE_SCHEME_SECP256K1",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for SignatureScheme {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["SIGNATURE_SCHEME_ED25519", "SIGNATURE_SCHEME_SECP256K1"];
+
+        struct GeneratedVisitor;
+
+        #[allow(clippy::needless_lifetimes)]
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SignatureScheme;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "SIGNATURE_SCHEME_ED25519" => Ok(SignatureScheme::Ed25519),
+                    "SIGNATURE_SCHEME_SECP256K1" => Ok(SignatureScheme::Secp256k1),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SponsorSignature {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -117,41 +1008,42 @@ impl serde::Serialize for SendText {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if !self.text.is_empty() {
-            len += 1;
-        }
-        if !self.from.is_empty() {
+        if !self.signature.is_empty() {
             len += 1;
         }
-        if !self.fee_asset.is_empty() {
+        if !self.public_key.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("transaction.v1.SendText", len)?;
-        if !self.text.is_empty() {
-            struct_ser.serialize_field("text", &self.text)?;
-        }
-        if !self.from.is_empty() {
-            struct_ser.serialize_field("from", &self.from)?;
+        let mut struct_ser = serializer.serialize_struct("transaction.v1.SponsorSignature", len)?;
+        if !self.signature.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field(
+                "signature",
+                pbjson::private::base64::encode(&self.signature).as_str(),
+            )?;
         }
-        if !self.fee_asset.is_empty() {
-            struct_ser.serialize_field("feeAsset", &self.fee_asset)?;
+        if !self.public_key.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field(
+                "publicKey",
+                pbjson::private::base64::encode(&self.public_key).as_str(),
+            )?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SendText {
+impl<'de> serde::Deserialize<'de> for SponsorSignature {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
-        const FIELDS: &[&str] = &["text", "from", "fee_asset", "feeAsset"];
+        const FIELDS: &[&str] = &["signature", "public_key", "publicKey"];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Text,
-            From,
-            FeeAsset,
+            Signature,
+            PublicKey,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -177,9 +1069,8 @@ impl<'de> serde::Deserialize<'de> for SendText {
                         E: serde::de::Error,
                     {
                         match value {
-                            "text" => Ok(GeneratedField::Text),
-                            "from" => Ok(GeneratedField::From),
-                            "feeAsset" | "fee_asset" => Ok(GeneratedField::FeeAsset),
+                            "signature" => Ok(GeneratedField::Signature),
+                            "publicKey" | "public_key" => Ok(GeneratedField::PublicKey),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -190,49 +1081,47 @@ impl<'de> serde::Deserialize<'de> for SendText {
         struct GeneratedVisitor;
         #[allow(clippy::needless_lifetimes)]
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SendText;
+            type Value = SponsorSignature;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct transaction.v1.SendText")
+                formatter.write_str("struct transaction.v1.SponsorSignature")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SendText, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SponsorSignature, V::Error>
             where
                 V: serde::de::MapAccess<'de>,
             {
-                let mut text__ = None;
-                let mut from__ = None;
-                let mut fee_asset__ = None;
+                let mut signature__ = None;
+                let mut public_key__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Text => {
-                            if text__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("text"));
-                            }
-                            text__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::From => {
-                            if from__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("from"));
+                        GeneratedField::Signature => {
+                            if signature__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("signature"));
                             }
-                            from__ = Some(map_.next_value()?);
+                            signature__ = Some(
+                                map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?
+                                    .0,
+                            );
                         }
-                        GeneratedField::FeeAsset => {
-                            if fee_asset__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("feeAsset"));
+                        GeneratedField::PublicKey => {
+                            if public_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("publicKey"));
                             }
-                            fee_asset__ = Some(map_.next_value()?);
+                            public_key__ = Some(
+                                map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?
+                                    .0,
+                            );
                         }
                     }
                 }
-                Ok(SendText {
-                    text: text__.unwrap_or_default(),
-                    from: from__.unwrap_or_default(),
-                    fee_asset: fee_asset__.unwrap_or_default(),
+                Ok(SponsorSignature {
+                    signature: signature__.unwrap_or_default(),
+                    public_key: public_key__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("transaction.v1.SendText", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("transaction.v1.SponsorSignature", FIELDS, GeneratedVisitor)
     }
 }
 impl serde::Serialize for Transaction {
@@ -252,6 +1141,15 @@ impl serde::Serialize for Transaction {
         if self.body.is_some() {
             len += 1;
         }
+        if self.sponsor_signature.is_some() {
+            len += 1;
+        }
+        if !self.cosignatures.is_empty() {
+            len += 1;
+        }
+        if self.signature_scheme != 0 {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("transaction.v1.Transaction", len)?;
         if !self.signature.is_empty() {
             #[allow(clippy::needless_borrow)]
@@ -270,6 +1168,18 @@ impl serde::Serialize for Transaction {
         if let Some(v) = self.body.as_ref() {
             struct_ser.serialize_field("body", v)?;
         }
+        if let Some(v) = self.sponsor_signature.as_ref() {
+            struct_ser.serialize_field("sponsorSignature", v)?;
+        }
+        if !self.cosignatures.is_empty() {
+            struct_ser.serialize_field("cosignatures", &self.cosignatures)?;
+        }
+        if self.signature_scheme != 0 {
+            let v = SignatureScheme::try_from(self.signature_scheme).map_err(|_| {
+                serde::ser::Error::custom(format!("Invalid variant {}", self.signature_scheme))
+            })?;
+            struct_ser.serialize_field("signatureScheme", &v)?;
+        }
         struct_ser.end()
     }
 }
@@ -279,13 +1189,26 @@ impl<'de> serde::Deserialize<'de> for Transaction {
     where
         D: serde::Deserializer<'de>,
     {
-        const FIELDS: &[&str] = &["signature", "public_key", "publicKey", "body"];
+        const FIELDS: &[&str] = &[
+            "signature",
+            "public_key",
+            "publicKey",
+            "body",
+            "sponsor_signature",
+            "sponsorSignature",
+            "cosignatures",
+            "signature_scheme",
+            "signatureScheme",
+        ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Signature,
             PublicKey,
             Body,
+            SponsorSignature,
+            Cosignatures,
+            SignatureScheme,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -314,6 +1237,13 @@ impl<'de> serde::Deserialize<'de> for Transaction {
                             "signature" => Ok(GeneratedField::Signature),
                             "publicKey" | "public_key" => Ok(GeneratedField::PublicKey),
                             "body" => Ok(GeneratedField::Body),
+                            "sponsorSignature" | "sponsor_signature" => {
+                                Ok(GeneratedField::SponsorSignature)
+                            }
+                            "cosignatures" => Ok(GeneratedField::Cosignatures),
+                            "signatureScheme" | "signature_scheme" => {
+                                Ok(GeneratedField::SignatureScheme)
+                            }
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -337,6 +1267,9 @@ impl<'de> serde::Deserialize<'de> for Transaction {
                 let mut signature__ = None;
                 let mut public_key__ = None;
                 let mut body__ = None;
+                let mut sponsor_signature__ = None;
+                let mut cosignatures__ = None;
+                let mut signature_scheme__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Signature => {
@@ -363,12 +1296,33 @@ impl<'de> serde::Deserialize<'de> for Transaction {
                             }
                             body__ = map_.next_value()?;
                         }
+                        GeneratedField::SponsorSignature => {
+                            if sponsor_signature__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sponsorSignature"));
+                            }
+                            sponsor_signature__ = map_.next_value()?;
+                        }
+                        GeneratedField::Cosignatures => {
+                            if cosignatures__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cosignatures"));
+                            }
+                            cosignatures__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::SignatureScheme => {
+                            if signature_scheme__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("signatureScheme"));
+                            }
+                            signature_scheme__ = Some(map_.next_value::<SignatureScheme>()? as i32);
+                        }
                     }
                 }
                 Ok(Transaction {
                     signature: signature__.unwrap_or_default(),
                     public_key: public_key__.unwrap_or_default(),
                     body: body__,
+                    sponsor_signature: sponsor_signature__,
+                    cosignatures: cosignatures__.unwrap_or_default(),
+                    signature_scheme: signature_scheme__.unwrap_or_default(),
                 })
             }
         }
@@ -389,6 +1343,15 @@ impl serde::Serialize for TransactionBody {
         if !self.actions.is_empty() {
             len += 1;
         }
+        if self.sponsor.is_some() {
+            len += 1;
+        }
+        if self.granter.is_some() {
+            len += 1;
+        }
+        if self.multisig.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("transaction.v1.TransactionBody", len)?;
         if let Some(v) = self.params.as_ref() {
             struct_ser.serialize_field("params", v)?;
@@ -396,6 +1359,15 @@ impl serde::Serialize for TransactionBody {
         if !self.actions.is_empty() {
             struct_ser.serialize_field("actions", &self.actions)?;
         }
+        if let Some(v) = self.sponsor.as_ref() {
+            struct_ser.serialize_field("sponsor", v)?;
+        }
+        if let Some(v) = self.granter.as_ref() {
+            struct_ser.serialize_field("granter", v)?;
+        }
+        if let Some(v) = self.multisig.as_ref() {
+            struct_ser.serialize_field("multisig", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -405,12 +1377,15 @@ impl<'de> serde::Deserialize<'de> for TransactionBody {
     where
         D: serde::Deserializer<'de>,
     {
-        const FIELDS: &[&str] = &["params", "actions"];
+        const FIELDS: &[&str] = &["params", "actions", "sponsor", "granter", "multisig"];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Params,
             Actions,
+            Sponsor,
+            Granter,
+            Multisig,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -438,6 +1413,9 @@ impl<'de> serde::Deserialize<'de> for TransactionBody {
                         match value {
                             "params" => Ok(GeneratedField::Params),
                             "actions" => Ok(GeneratedField::Actions),
+                            "sponsor" => Ok(GeneratedField::Sponsor),
+                            "granter" => Ok(GeneratedField::Granter),
+                            "multisig" => Ok(GeneratedField::Multisig),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -460,6 +1438,9 @@ impl<'de> serde::Deserialize<'de> for TransactionBody {
             {
                 let mut params__ = None;
                 let mut actions__ = None;
+                let mut sponsor__ = None;
+                let mut granter__ = None;
+                let mut multisig__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Params => {
@@ -474,11 +1455,32 @@ impl<'de> serde::Deserialize<'de> for TransactionBody {
                             }
                             actions__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::Sponsor => {
+                            if sponsor__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sponsor"));
+                            }
+                            sponsor__ = map_.next_value()?;
+                        }
+                        GeneratedField::Granter => {
+                            if granter__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("granter"));
+                            }
+                            granter__ = map_.next_value()?;
+                        }
+                        GeneratedField::Multisig => {
+                            if multisig__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("multisig"));
+                            }
+                            multisig__ = map_.next_value()?;
+                        }
                     }
                 }
                 Ok(TransactionBody {
                     params: params__,
                     actions: actions__.unwrap_or_default(),
+                    sponsor: sponsor__,
+                    granter: granter__,
+                    multisig: multisig__,
                 })
             }
         }
@@ -499,6 +1501,12 @@ impl serde::Serialize for TransactionParams {
         if !self.chain_id.is_empty() {
             len += 1;
         }
+        if self.valid_until_height != 0 {
+            len += 1;
+        }
+        if self.valid_until_timestamp.is_some() {
+            len += 1;
+        }
         let mut struct_ser =
             serializer.serialize_struct("transaction.v1.TransactionParams", len)?;
         if self.nonce != 0 {
@@ -507,6 +1515,12 @@ impl serde::Serialize for TransactionParams {
         if !self.chain_id.is_empty() {
             struct_ser.serialize_field("chainId", &self.chain_id)?;
         }
+        if self.valid_until_height != 0 {
+            struct_ser.serialize_field("validUntilHeight", &self.valid_until_height)?;
+        }
+        if let Some(v) = self.valid_until_timestamp.as_ref() {
+            struct_ser.serialize_field("validUntilTimestamp", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -516,12 +1530,22 @@ impl<'de> serde::Deserialize<'de> for TransactionParams {
     where
         D: serde::Deserializer<'de>,
     {
-        const FIELDS: &[&str] = &["nonce", "chain_id", "chainId"];
+        const FIELDS: &[&str] = &[
+            "nonce",
+            "chain_id",
+            "chainId",
+            "valid_until_height",
+            "validUntilHeight",
+            "valid_until_timestamp",
+            "validUntilTimestamp",
+        ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Nonce,
             ChainId,
+            ValidUntilHeight,
+            ValidUntilTimestamp,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -549,6 +1573,12 @@ impl<'de> serde::Deserialize<'de> for TransactionParams {
                         match value {
                             "nonce" => Ok(GeneratedField::Nonce),
                             "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            "validUntilHeight" | "valid_until_height" => {
+                                Ok(GeneratedField::ValidUntilHeight)
+                            }
+                            "validUntilTimestamp" | "valid_until_timestamp" => {
+                                Ok(GeneratedField::ValidUntilTimestamp)
+                            }
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -571,6 +1601,8 @@ impl<'de> serde::Deserialize<'de> for TransactionParams {
             {
                 let mut nonce__ = None;
                 let mut chain_id__ = None;
+                let mut valid_until_height__ = None;
+                let mut valid_until_timestamp__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Nonce => {
@@ -588,11 +1620,30 @@ impl<'de> serde::Deserialize<'de> for TransactionParams {
                             }
                             chain_id__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::ValidUntilHeight => {
+                            if valid_until_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validUntilHeight"));
+                            }
+                            valid_until_height__ = Some(
+                                map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?
+                                    .0,
+                            );
+                        }
+                        GeneratedField::ValidUntilTimestamp => {
+                            if valid_until_timestamp__.is_some() {
+                                return Err(serde::de::Error::duplicate_field(
+                                    "validUntilTimestamp",
+                                ));
+                            }
+                            valid_until_timestamp__ = map_.next_value()?;
+                        }
                     }
                 }
                 Ok(TransactionParams {
                     nonce: nonce__.unwrap_or_default(),
                     chain_id: chain_id__.unwrap_or_default(),
+                    valid_until_height: valid_until_height__.unwrap_or_default(),
+                    valid_until_timestamp: valid_until_timestamp__,
                 })
             }
         }
@@ -1,4 +1,5 @@
 use crate::generated::protocol::genesis::v1 as raw;
+use astria_core::primitive::v1::asset::{self, denom::ParseDenomError};
 use astria_core::primitive::v1::Address;
 use astria_core::primitive::v1::AddressError;
 use astria_core::Protobuf;
@@ -18,8 +19,11 @@ pub struct GenesisAppState {
     celestia_genesis_block_height: u32,
     celestia_block_variance: u64,
     accounts: Vec<Account>,
-    bridge_accounts: Vec<Address>,
+    bridge_accounts: Vec<BridgeAccount>,
     authority_sudo_address: astria_core::primitive::v1::Address,
+    text_params: Option<TextParams>,
+    block_params: Option<BlockParams>,
+    compute_params: Option<ComputeParams>,
 }
 
 impl GenesisAppState {
@@ -29,7 +33,7 @@ impl GenesisAppState {
     }
 
     #[must_use]
-    pub fn bridge_accounts(&self) -> &[Address] {
+    pub fn bridge_accounts(&self) -> &[BridgeAccount] {
         &self.bridge_accounts
     }
 
@@ -38,6 +42,24 @@ impl GenesisAppState {
         &self.authority_sudo_address
     }
 
+    /// Returns the limits on posting texts, if any.
+    #[must_use]
+    pub fn text_params(&self) -> Option<&TextParams> {
+        self.text_params.as_ref()
+    }
+
+    /// Returns the limits on the resources a block can use, if any.
+    #[must_use]
+    pub fn block_params(&self) -> Option<&BlockParams> {
+        self.block_params.as_ref()
+    }
+
+    /// Returns the metering of compute units used by actions, if any.
+    #[must_use]
+    pub fn compute_params(&self) -> Option<&ComputeParams> {
+        self.compute_params.as_ref()
+    }
+
     #[must_use]
     pub fn rollup_name(&self) -> &str {
         &self.rollup_name
@@ -78,6 +100,9 @@ impl Protobuf for GenesisAppState {
             sequencer_genesis_block_height,
             celestia_genesis_block_height,
             celestia_block_variance,
+            text_params,
+            block_params,
+            compute_params,
         } = raw;
         let accounts = accounts
             .iter()
@@ -87,9 +112,9 @@ impl Protobuf for GenesisAppState {
 
         let bridge_accounts = bridge_accounts
             .iter()
-            .map(Address::try_from_raw_ref)
+            .map(BridgeAccount::try_from_raw_ref)
             .collect::<Result<Vec<_>, _>>()
-            .map_err(Self::Error::authority_sudo_address)?;
+            .map_err(Self::Error::bridge_accounts)?;
 
         let authority_sudo_address = authority_sudo_address
             .as_ref()
@@ -98,6 +123,22 @@ impl Protobuf for GenesisAppState {
                 Address::try_from_raw_ref(addr).map_err(Self::Error::authority_sudo_address)
             })?;
 
+        let text_params = text_params
+            .as_ref()
+            .map(TextParams::try_from_raw_ref)
+            .transpose()
+            .map_err(Self::Error::text_params)?;
+        let block_params = block_params
+            .as_ref()
+            .map(BlockParams::try_from_raw_ref)
+            .transpose()
+            .map_err(Self::Error::block_params)?;
+        let compute_params = compute_params
+            .as_ref()
+            .map(ComputeParams::try_from_raw_ref)
+            .transpose()
+            .map_err(Self::Error::compute_params)?;
+
         let this = Self {
             accounts,
             bridge_accounts,
@@ -106,6 +147,9 @@ impl Protobuf for GenesisAppState {
             sequencer_genesis_block_height: *sequencer_genesis_block_height,
             celestia_genesis_block_height: *celestia_genesis_block_height,
             celestia_block_variance: *celestia_block_variance,
+            text_params,
+            block_params,
+            compute_params,
         };
         Ok(this)
     }
@@ -119,16 +163,21 @@ impl Protobuf for GenesisAppState {
             sequencer_genesis_block_height,
             celestia_genesis_block_height,
             celestia_block_variance,
+            text_params,
+            block_params,
+            compute_params,
         } = self;
         Self::Raw {
             accounts: accounts.iter().map(Account::to_raw).collect(),
-            bridge_accounts: bridge_accounts.iter().map(Address::to_raw).collect(),
+            bridge_accounts: bridge_accounts.iter().map(BridgeAccount::to_raw).collect(),
             authority_sudo_address: Some(authority_sudo_address.to_raw()),
             rollup_name: rollup_name.clone(),
             sequencer_genesis_block_height: *sequencer_genesis_block_height,
             celestia_genesis_block_height: *celestia_genesis_block_height,
-
             celestia_block_variance: *celestia_block_variance,
+            text_params: text_params.as_ref().map(TextParams::to_raw),
+            block_params: block_params.as_ref().map(BlockParams::to_raw),
+            compute_params: compute_params.as_ref().map(ComputeParams::to_raw),
         }
     }
 }
@@ -156,6 +205,10 @@ impl GenesisAppStateError {
         Self(GenesisAppStateErrorKind::Accounts { source })
     }
 
+    fn bridge_accounts(source: BridgeAccountError) -> Self {
+        Self(GenesisAppStateErrorKind::BridgeAccounts { source })
+    }
+
     fn authority_sudo_address(source: AddressError) -> Self {
         Self(GenesisAppStateErrorKind::AuthoritySudoAddress { source })
     }
@@ -163,6 +216,18 @@ impl GenesisAppStateError {
     fn field_not_set(name: &'static str) -> Self {
         Self(GenesisAppStateErrorKind::FieldNotSet { name })
     }
+
+    fn text_params(source: TextParamsError) -> Self {
+        Self(GenesisAppStateErrorKind::TextParams { source })
+    }
+
+    fn block_params(source: BlockParamsError) -> Self {
+        Self(GenesisAppStateErrorKind::BlockParams { source })
+    }
+
+    fn compute_params(source: ComputeParamsError) -> Self {
+        Self(GenesisAppStateErrorKind::ComputeParams { source })
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -170,10 +235,18 @@ impl GenesisAppStateError {
 enum GenesisAppStateErrorKind {
     #[error("`accounts` field was invalid")]
     Accounts { source: AccountError },
+    #[error("`bridge_accounts` field was invalid")]
+    BridgeAccounts { source: BridgeAccountError },
     #[error("`authority_sudo_address` field was invalid")]
     AuthoritySudoAddress { source: AddressError },
     #[error("field was not set: `{name}`")]
     FieldNotSet { name: &'static str },
+    #[error("`text_params` field was invalid")]
+    TextParams { source: TextParamsError },
+    #[error("`block_params` field was invalid")]
+    BlockParams { source: BlockParamsError },
+    #[error("`compute_params` field was invalid")]
+    ComputeParams { source: ComputeParamsError },
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -230,6 +303,268 @@ enum AccountErrorKind {
     FieldNotSet { name: &'static str },
 }
 
+/// A bridge account together with the asset it locks on the sequencer.
+#[derive(Clone, Debug)]
+pub struct BridgeAccount {
+    pub address: Address,
+    pub asset: asset::Denom,
+}
+
+impl Protobuf for BridgeAccount {
+    type Error = BridgeAccountError;
+    type Raw = raw::BridgeAccount;
+
+    fn try_from_raw_ref(raw: &Self::Raw) -> Result<Self, Self::Error> {
+        let Self::Raw { address, asset } = raw;
+        let address = address
+            .as_ref()
+            .ok_or_else(|| BridgeAccountError::field_not_set("address"))
+            .and_then(|addr| Address::try_from_raw_ref(addr).map_err(Self::Error::address))?;
+        let asset = asset.parse().map_err(Self::Error::asset)?;
+        Ok(Self { address, asset })
+    }
+
+    fn to_raw(&self) -> Self::Raw {
+        let Self { address, asset } = self;
+        Self::Raw {
+            address: Some(address.to_raw()),
+            asset: asset.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct BridgeAccountError(BridgeAccountErrorKind);
+
+impl BridgeAccountError {
+    fn address(source: AddressError) -> Self {
+        Self(BridgeAccountErrorKind::Address { source })
+    }
+
+    fn asset(source: ParseDenomError) -> Self {
+        Self(BridgeAccountErrorKind::Asset { source })
+    }
+
+    fn field_not_set(name: &'static str) -> Self {
+        Self(BridgeAccountErrorKind::FieldNotSet { name })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("failed ensuring invariants of {}", BridgeAccount::full_name())]
+enum BridgeAccountErrorKind {
+    #[error("`address` field was invalid")]
+    Address { source: AddressError },
+    #[error("`asset` field was invalid")]
+    Asset { source: ParseDenomError },
+    #[error("field was not set: `{name}`")]
+    FieldNotSet { name: &'static str },
+}
+
+/// Limits on posting texts, so that the chat cannot be flooded by an account with a small balance.
+///
+/// A limit of zero means no limit.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TextParams {
+    /// The number of texts an account can post within a window of `window_blocks` blocks.
+    pub max_texts_per_window: u32,
+    /// The number of blocks in a window. Windows start at multiples of `window_blocks`.
+    pub window_blocks: u32,
+    /// The maximum length of a text in bytes.
+    pub max_text_length: u32,
+    /// The maximum number of actions in a transaction.
+    pub max_actions_per_transaction: u32,
+}
+
+impl Protobuf for TextParams {
+    type Error = TextParamsError;
+    type Raw = raw::TextParams;
+
+    fn try_from_raw_ref(raw: &Self::Raw) -> Result<Self, Self::Error> {
+        let Self::Raw {
+            max_texts_per_window,
+            window_blocks,
+            max_text_length,
+            max_actions_per_transaction,
+        } = *raw;
+        if max_texts_per_window > 0 && window_blocks == 0 {
+            return Err(TextParamsError::window_blocks_not_set());
+        }
+        Ok(Self {
+            max_texts_per_window,
+            window_blocks,
+            max_text_length,
+            max_actions_per_transaction,
+        })
+    }
+
+    fn to_raw(&self) -> Self::Raw {
+        let Self {
+            max_texts_per_window,
+            window_blocks,
+            max_text_length,
+            max_actions_per_transaction,
+        } = *self;
+        Self::Raw {
+            max_texts_per_window,
+            window_blocks,
+            max_text_length,
+            max_actions_per_transaction,
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct TextParamsError(TextParamsErrorKind);
+
+impl TextParamsError {
+    fn window_blocks_not_set() -> Self {
+        Self(TextParamsErrorKind::WindowBlocksNotSet)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("failed ensuring invariants of {}", TextParams::full_name())]
+enum TextParamsErrorKind {
+    #[error("`window_blocks` must be set if `max_texts_per_window` is set")]
+    WindowBlocksNotSet,
+}
+
+/// Limits on the resources a rollup block can use, so that an oversized block cannot overwhelm
+/// the rollup nodes.
+///
+/// A limit of zero means no limit.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BlockParams {
+    /// The maximum number of transactions executed in a block.
+    pub max_transactions: u32,
+    /// The maximum total size of the encoded transactions executed in a block.
+    pub max_transaction_bytes: u64,
+    /// The maximum total length of the texts posted by transactions in a block.
+    pub max_text_bytes: u64,
+    /// The maximum number of deposits executed in a block.
+    pub max_deposits: u32,
+}
+
+impl Protobuf for BlockParams {
+    type Error = BlockParamsError;
+    type Raw = raw::BlockParams;
+
+    fn try_from_raw_ref(raw: &Self::Raw) -> Result<Self, Self::Error> {
+        let Self::Raw {
+            max_transactions,
+            max_transaction_bytes,
+            max_text_bytes,
+            max_deposits,
+        } = *raw;
+        // texts are part of the transactions posting them
+        if max_transaction_bytes > 0 && max_text_bytes > max_transaction_bytes {
+            return Err(BlockParamsError::text_bytes_above_transaction_bytes());
+        }
+        Ok(Self {
+            max_transactions,
+            max_transaction_bytes,
+            max_text_bytes,
+            max_deposits,
+        })
+    }
+
+    fn to_raw(&self) -> Self::Raw {
+        let Self {
+            max_transactions,
+            max_transaction_bytes,
+            max_text_bytes,
+            max_deposits,
+        } = *self;
+        Self::Raw {
+            max_transactions,
+            max_transaction_bytes,
+            max_text_bytes,
+            max_deposits,
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct BlockParamsError(BlockParamsErrorKind);
+
+impl BlockParamsError {
+    fn text_bytes_above_transaction_bytes() -> Self {
+        Self(BlockParamsErrorKind::TextBytesAboveTransactionBytes)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("failed ensuring invariants of {}", BlockParams::full_name())]
+enum BlockParamsErrorKind {
+    #[error("`max_text_bytes` must not exceed `max_transaction_bytes` if that is set")]
+    TextBytesAboveTransactionBytes,
+}
+
+/// Metering of the work an action causes, so that heavy actions pay proportionally.
+///
+/// Each action uses compute units for the state it reads and writes and for the bytes it writes,
+/// counted while it is executed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ComputeParams {
+    /// The maximum number of compute units a transaction can use. Zero means no limit.
+    pub max_units_per_transaction: u64,
+    /// The fee charged per compute unit. Requires `max_units_per_transaction` to be set.
+    pub unit_price: u64,
+}
+
+impl Protobuf for ComputeParams {
+    type Error = ComputeParamsError;
+    type Raw = raw::ComputeParams;
+
+    fn try_from_raw_ref(raw: &Self::Raw) -> Result<Self, Self::Error> {
+        let Self::Raw {
+            max_units_per_transaction,
+            unit_price,
+        } = *raw;
+        // the units are only known once a transaction is executed, so the limit is what bounds
+        // the compute fees it can be charged
+        if unit_price > 0 && max_units_per_transaction == 0 {
+            return Err(ComputeParamsError::units_not_limited());
+        }
+        Ok(Self {
+            max_units_per_transaction,
+            unit_price,
+        })
+    }
+
+    fn to_raw(&self) -> Self::Raw {
+        let Self {
+            max_units_per_transaction,
+            unit_price,
+        } = *self;
+        Self::Raw {
+            max_units_per_transaction,
+            unit_price,
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct ComputeParamsError(ComputeParamsErrorKind);
+
+impl ComputeParamsError {
+    fn units_not_limited() -> Self {
+        Self(ComputeParamsErrorKind::UnitsNotLimited)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("failed ensuring invariants of {}", ComputeParams::full_name())]
+enum ComputeParamsErrorKind {
+    #[error("`max_units_per_transaction` must be set if `unit_price` is set")]
+    UnitsNotLimited,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -277,12 +612,18 @@ mod tests {
                     balance: Some(1_000_000_000_000_000_000.into()),
                 },
             ],
-            bridge_accounts: vec![], // TODO: add bridge accounts
+            bridge_accounts: vec![raw::BridgeAccount {
+                address: Some(charlie().to_raw()),
+                asset: "transfer/channel-0/utia".to_string(),
+            }],
             authority_sudo_address: Some(alice().to_raw()),
             rollup_name: "astria-1".to_string(),
             sequencer_genesis_block_height: 0,
             celestia_genesis_block_height: 0,
             celestia_block_variance: 0,
+            text_params: None,
+            block_params: None,
+            compute_params: None,
         }
     }
 
@@ -290,6 +631,69 @@ mod tests {
         proto_genesis_state().try_into().unwrap()
     }
 
+    #[test]
+    fn bridge_accounts_roundtrip() {
+        let genesis_state = genesis_state();
+        let [bridge_account] = genesis_state.bridge_accounts() else {
+            panic!("expected exactly one bridge account");
+        };
+        assert_eq!(bridge_account.address, charlie());
+        assert_eq!(bridge_account.asset.to_string(), "transfer/channel-0/utia");
+        assert_eq!(genesis_state.into_raw(), proto_genesis_state());
+    }
+
+    #[test]
+    fn bridge_account_without_asset_is_rejected() {
+        let mut proto = proto_genesis_state();
+        proto.bridge_accounts[0].asset = String::new();
+        GenesisAppState::try_from_raw(proto).unwrap_err();
+    }
+
+    #[test]
+    fn text_params_roundtrip() {
+        let mut proto = proto_genesis_state();
+        proto.text_params = Some(raw::TextParams {
+            max_texts_per_window: 10,
+            window_blocks: 5,
+            max_text_length: 280,
+            max_actions_per_transaction: 20,
+        });
+        let genesis_state = GenesisAppState::try_from_raw(proto.clone()).unwrap();
+        assert_eq!(genesis_state.text_params().unwrap().max_text_length, 280);
+        assert_eq!(genesis_state.into_raw(), proto);
+    }
+
+    #[test]
+    fn text_params_without_window_are_rejected() {
+        let mut proto = proto_genesis_state();
+        proto.text_params = Some(raw::TextParams {
+            max_texts_per_window: 10,
+            ..raw::TextParams::default()
+        });
+        GenesisAppState::try_from_raw(proto).unwrap_err();
+    }
+
+    #[test]
+    fn block_params_with_text_bytes_above_transaction_bytes_are_rejected() {
+        let mut proto = proto_genesis_state();
+        proto.block_params = Some(raw::BlockParams {
+            max_transaction_bytes: 100,
+            max_text_bytes: 101,
+            ..raw::BlockParams::default()
+        });
+        GenesisAppState::try_from_raw(proto).unwrap_err();
+    }
+
+    #[test]
+    fn compute_params_pricing_unlimited_units_are_rejected() {
+        let mut proto = proto_genesis_state();
+        proto.compute_params = Some(raw::ComputeParams {
+            max_units_per_transaction: 0,
+            unit_price: 1,
+        });
+        GenesisAppState::try_from_raw(proto).unwrap_err();
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn genesis_state_is_unchanged() {
@@ -1,3 +1,5 @@
+pub mod block;
 pub mod generated;
 pub mod genesis;
+pub mod proof;
 pub mod transaction;
@@ -0,0 +1,216 @@
+//! State inclusion proofs returned by the rollup.
+//!
+//! The rollup stores its state in a Jellyfish Merkle Tree whose root hash is committed to in
+//! every block (see the `state_root` returned alongside a block). A [`StateProof`] ties a single
+//! key/value pair of that state to the state root of a block, allowing clients to verify values
+//! returned by a rollup node without trusting it.
+
+use ics23::{CommitmentProof, HostFunctionsManager};
+use prost::Message as _;
+
+/// A value read from the rollup state at block `height`, together with the ics23 commitment
+/// proofs of its inclusion (or exclusion if `value` is `None`) in the state with root
+/// `state_root`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct StateProof {
+    pub height: u64,
+    #[cfg_attr(feature = "serde", serde(with = "hex::serde"))]
+    pub state_root: [u8; 32],
+    pub key: String,
+    /// The raw bytes stored under `key`, or `None` if the key is not present in the state.
+    #[cfg_attr(feature = "serde", serde(with = "serde_hex_option"))]
+    pub value: Option<Vec<u8>>,
+    /// Protobuf encoded [`ics23::CommitmentProof`]s.
+    #[cfg_attr(feature = "serde", serde(with = "serde_hex_list"))]
+    pub proofs: Vec<Vec<u8>>,
+}
+
+impl StateProof {
+    /// Verifies that `value` is stored under `key` (or that `key` is absent if `value` is
+    /// `None`) in the state with root `state_root`.
+    ///
+    /// `state_root` must come from a source the caller trusts, such as a block whose hash is
+    /// known to be correct (see [`crate::block::BlockHeader::hash`]). The state root contained
+    /// in the proof itself only says which state the node claims to have proven against.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - `state_root` does not match the state root contained in the proof,
+    /// - the proof does not contain exactly one commitment proof,
+    /// - the commitment proof cannot be decoded,
+    /// - the commitment proof does not verify against `state_root`.
+    pub fn verify_against(&self, state_root: &[u8; 32]) -> Result<(), ProofError> {
+        if &self.state_root != state_root {
+            return Err(ProofError::root_mismatch());
+        }
+        // the rollup keeps all of its state in the main store, so a single proof links the key
+        // to the root hash.
+        let [proof] = self.proofs.as_slice() else {
+            return Err(ProofError::unexpected_proof_count(self.proofs.len()));
+        };
+        let proof = CommitmentProof::decode(proof.as_slice()).map_err(ProofError::decode)?;
+        let spec = jmt::ics23_spec();
+        let root = state_root.to_vec();
+        let verified = match &self.value {
+            Some(value) => ics23::verify_membership::<HostFunctionsManager>(
+                &proof,
+                &spec,
+                &root,
+                self.key.as_bytes(),
+                value,
+            ),
+            None => ics23::verify_non_membership::<HostFunctionsManager>(
+                &proof,
+                &spec,
+                &root,
+                self.key.as_bytes(),
+            ),
+        };
+        if !verified {
+            return Err(ProofError::verification(self.value.is_some()));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct ProofError(ProofErrorKind);
+
+impl ProofError {
+    fn root_mismatch() -> Self {
+        Self(ProofErrorKind::RootMismatch)
+    }
+
+    fn unexpected_proof_count(count: usize) -> Self {
+        Self(ProofErrorKind::UnexpectedProofCount(count))
+    }
+
+    fn decode(inner: prost::DecodeError) -> Self {
+        Self(ProofErrorKind::Decode(inner))
+    }
+
+    fn verification(membership: bool) -> Self {
+        if membership {
+            Self(ProofErrorKind::Membership)
+        } else {
+            Self(ProofErrorKind::NonMembership)
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum ProofErrorKind {
+    #[error("the state root of the proof does not match the expected state root")]
+    RootMismatch,
+    #[error("expected exactly one commitment proof, got {0}")]
+    UnexpectedProofCount(usize),
+    #[error("failed decoding commitment proof")]
+    Decode(#[source] prost::DecodeError),
+    #[error("membership proof did not verify against the state root")]
+    Membership,
+    #[error("non-membership proof did not verify against the state root")]
+    NonMembership,
+}
+
+#[cfg(feature = "serde")]
+mod serde_hex_option {
+    use serde::{Deserialize as _, Deserializer, Serializer};
+
+    pub(super) fn serialize<S: Serializer>(
+        value: &Option<Vec<u8>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(bytes) => serializer.serialize_some(&hex::encode(bytes)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Vec<u8>>, D::Error> {
+        Option::<String>::deserialize(deserializer)?
+            .map(|value| hex::decode(value).map_err(serde::de::Error::custom))
+            .transpose()
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_hex_list {
+    use serde::{ser::SerializeSeq as _, Deserialize as _, Deserializer, Serializer};
+
+    pub(super) fn serialize<S: Serializer>(
+        values: &[Vec<u8>],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(values.len()))?;
+        for value in values {
+            seq.serialize_element(&hex::encode(value))?;
+        }
+        seq.end()
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<Vec<u8>>, D::Error> {
+        Vec::<String>::deserialize(deserializer)?
+            .into_iter()
+            .map(|value| hex::decode(value).map_err(serde::de::Error::custom))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn proof() -> StateProof {
+        StateProof {
+            height: 1,
+            state_root: [1; 32],
+            key: "accounts/key".to_string(),
+            value: Some(vec![1, 2, 3]),
+            proofs: vec![],
+        }
+    }
+
+    #[test]
+    fn verify_against_other_root_fails() {
+        let error = proof().verify_against(&[2; 32]).unwrap_err().0;
+        assert!(
+            matches!(error, ProofErrorKind::RootMismatch),
+            "expected ProofErrorKind::RootMismatch, got {error:?}"
+        );
+    }
+
+    #[test]
+    fn verify_without_commitment_proof_fails() {
+        let error = proof().verify_against(&[1; 32]).unwrap_err().0;
+        assert!(
+            matches!(error, ProofErrorKind::UnexpectedProofCount(0)),
+            "expected ProofErrorKind::UnexpectedProofCount, got {error:?}"
+        );
+    }
+
+    #[test]
+    fn verify_with_invalid_commitment_proof_fails() {
+        let mut proof = proof();
+        proof.proofs = vec![vec![0xff; 8]];
+        let error = proof.verify_against(&[1; 32]).unwrap_err().0;
+        assert!(
+            matches!(error, ProofErrorKind::Decode(_)),
+            "expected ProofErrorKind::Decode, got {error:?}"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json_roundtrip() {
+        let mut proof = proof();
+        proof.proofs = vec![vec![4, 5, 6]];
+        let json = serde_json::to_string(&proof).unwrap();
+        assert_eq!(proof, serde_json::from_str(&json).unwrap());
+    }
+}
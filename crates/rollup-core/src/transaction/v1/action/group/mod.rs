@@ -3,7 +3,10 @@ mod tests;
 
 use std::fmt::{self, Debug};
 
-use super::{Action, ActionName, SendText, Transfer};
+use super::{
+    Action, ActionName, GrantSessionKey, RegisterMultisig, RevokeSessionKey, SendText,
+    SetSponsorLimit, Transfer,
+};
 
 trait BelongsToGroup {
     const GROUP: Group;
@@ -21,7 +24,11 @@ macro_rules! impl_belong_to_group {
 
 impl_belong_to_group!(
     (Transfer, Group::BundleableGeneral),
-    (SendText, Group::BundleableGeneral)
+    (SendText, Group::BundleableGeneral),
+    (SetSponsorLimit, Group::BundleableGeneral),
+    (GrantSessionKey, Group::BundleableGeneral),
+    (RevokeSessionKey, Group::BundleableGeneral),
+    (RegisterMultisig, Group::BundleableGeneral),
 );
 
 impl Action {
@@ -29,6 +36,10 @@ impl Action {
         match self {
             Action::Transfer(_) => Transfer::GROUP,
             Action::Text(_) => SendText::GROUP,
+            Action::SetSponsorLimit(_) => SetSponsorLimit::GROUP,
+            Action::GrantSessionKey(_) => GrantSessionKey::GROUP,
+            Action::RevokeSessionKey(_) => RevokeSessionKey::GROUP,
+            Action::RegisterMultisig(_) => RegisterMultisig::GROUP,
         }
     }
 }
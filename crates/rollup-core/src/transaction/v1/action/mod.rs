@@ -2,9 +2,12 @@
 
 use super::raw;
 pub mod group;
+use std::{fmt, str::FromStr};
+
+use astria_core::crypto::{self, VerificationKey};
 use astria_core::primitive::v1::{
     asset::{self},
-    Address, AddressError,
+    Address, AddressError, ADDRESS_LEN,
 };
 use astria_core::Protobuf;
 
@@ -17,6 +20,10 @@ use astria_core::Protobuf;
 pub enum Action {
     Transfer(Transfer),
     Text(SendText),
+    SetSponsorLimit(SetSponsorLimit),
+    GrantSessionKey(GrantSessionKey),
+    RevokeSessionKey(RevokeSessionKey),
+    RegisterMultisig(RegisterMultisig),
 }
 
 impl Protobuf for Action {
@@ -29,6 +36,10 @@ impl Protobuf for Action {
         let kind = match self {
             Action::Transfer(act) => Value::Transfer(act.to_raw()),
             Action::Text(act) => Value::SendText(act.to_raw()),
+            Action::SetSponsorLimit(act) => Value::SetSponsorLimit(act.to_raw()),
+            Action::GrantSessionKey(act) => Value::GrantSessionKey(act.to_raw()),
+            Action::RevokeSessionKey(act) => Value::RevokeSessionKey(act.to_raw()),
+            Action::RegisterMultisig(act) => Value::RegisterMultisig(act.to_raw()),
         };
         raw::Action { value: Some(kind) }
     }
@@ -62,6 +73,18 @@ impl Protobuf for Action {
             Value::SendText(account) => {
                 Self::Text(SendText::try_from_raw(account).map_err(Error::send_text)?)
             }
+            Value::SetSponsorLimit(act) => Self::SetSponsorLimit(
+                SetSponsorLimit::try_from_raw(act).map_err(Error::set_sponsor_limit)?,
+            ),
+            Value::GrantSessionKey(act) => Self::GrantSessionKey(
+                GrantSessionKey::try_from_raw(act).map_err(Error::grant_session_key)?,
+            ),
+            Value::RevokeSessionKey(act) => Self::RevokeSessionKey(
+                RevokeSessionKey::try_from_raw(act).map_err(Error::revoke_session_key)?,
+            ),
+            Value::RegisterMultisig(act) => Self::RegisterMultisig(
+                RegisterMultisig::try_from_raw(act).map_err(Error::register_multisig)?,
+            ),
         };
         Ok(action)
     }
@@ -76,6 +99,69 @@ impl Action {
         };
         Some(transfer_action)
     }
+
+    #[must_use]
+    pub const fn kind(&self) -> ActionKind {
+        match self {
+            Action::Transfer(_) => ActionKind::Transfer,
+            Action::Text(_) => ActionKind::SendText,
+            Action::SetSponsorLimit(_) => ActionKind::SetSponsorLimit,
+            Action::GrantSessionKey(_) => ActionKind::GrantSessionKey,
+            Action::RevokeSessionKey(_) => ActionKind::RevokeSessionKey,
+            Action::RegisterMultisig(_) => ActionKind::RegisterMultisig,
+        }
+    }
+}
+
+/// The kind of an [`Action`], named after its protobuf message.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ActionKind {
+    Transfer,
+    SendText,
+    SetSponsorLimit,
+    GrantSessionKey,
+    RevokeSessionKey,
+    RegisterMultisig,
+}
+
+impl ActionKind {
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            ActionKind::Transfer => "Transfer",
+            ActionKind::SendText => "SendText",
+            ActionKind::SetSponsorLimit => "SetSponsorLimit",
+            ActionKind::GrantSessionKey => "GrantSessionKey",
+            ActionKind::RevokeSessionKey => "RevokeSessionKey",
+            ActionKind::RegisterMultisig => "RegisterMultisig",
+        }
+    }
+}
+
+impl fmt::Display for ActionKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("unknown action kind `{0}`")]
+pub struct UnknownActionKind(String);
+
+impl FromStr for ActionKind {
+    type Err = UnknownActionKind;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Transfer" => Ok(ActionKind::Transfer),
+            "SendText" => Ok(ActionKind::SendText),
+            "SetSponsorLimit" => Ok(ActionKind::SetSponsorLimit),
+            "GrantSessionKey" => Ok(ActionKind::GrantSessionKey),
+            "RevokeSessionKey" => Ok(ActionKind::RevokeSessionKey),
+            "RegisterMultisig" => Ok(ActionKind::RegisterMultisig),
+            other => Err(UnknownActionKind(other.to_string())),
+        }
+    }
 }
 
 impl From<Transfer> for Action {
@@ -90,6 +176,30 @@ impl From<SendText> for Action {
     }
 }
 
+impl From<SetSponsorLimit> for Action {
+    fn from(value: SetSponsorLimit) -> Self {
+        Self::SetSponsorLimit(value)
+    }
+}
+
+impl From<GrantSessionKey> for Action {
+    fn from(value: GrantSessionKey) -> Self {
+        Self::GrantSessionKey(value)
+    }
+}
+
+impl From<RevokeSessionKey> for Action {
+    fn from(value: RevokeSessionKey) -> Self {
+        Self::RevokeSessionKey(value)
+    }
+}
+
+impl From<RegisterMultisig> for Action {
+    fn from(value: RegisterMultisig) -> Self {
+        Self::RegisterMultisig(value)
+    }
+}
+
 impl From<Action> for raw::Action {
     fn from(value: Action) -> Self {
         value.into_raw()
@@ -115,6 +225,10 @@ impl ActionName for Action {
         match self {
             Action::Transfer(_) => "Transfer",
             Action::Text(_) => "Text",
+            Action::SetSponsorLimit(_) => "SetSponsorLimit",
+            Action::GrantSessionKey(_) => "GrantSessionKey",
+            Action::RevokeSessionKey(_) => "RevokeSessionKey",
+            Action::RegisterMultisig(_) => "RegisterMultisig",
         }
     }
 }
@@ -135,6 +249,22 @@ impl Error {
     fn send_text(inner: SendTextError) -> Self {
         Self(ActionErrorKind::SendText(inner))
     }
+
+    fn set_sponsor_limit(inner: SetSponsorLimitError) -> Self {
+        Self(ActionErrorKind::SetSponsorLimit(inner))
+    }
+
+    fn grant_session_key(inner: GrantSessionKeyError) -> Self {
+        Self(ActionErrorKind::GrantSessionKey(inner))
+    }
+
+    fn revoke_session_key(inner: RevokeSessionKeyError) -> Self {
+        Self(ActionErrorKind::RevokeSessionKey(inner))
+    }
+
+    fn register_multisig(inner: RegisterMultisigError) -> Self {
+        Self(ActionErrorKind::RegisterMultisig(inner))
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -145,6 +275,14 @@ enum ActionErrorKind {
     Transfer(#[source] TransferError),
     #[error("send text action was not valid")]
     SendText(#[source] SendTextError),
+    #[error("set sponsor limit action was not valid")]
+    SetSponsorLimit(#[source] SetSponsorLimitError),
+    #[error("grant session key action was not valid")]
+    GrantSessionKey(#[source] GrantSessionKeyError),
+    #[error("revoke session key action was not valid")]
+    RevokeSessionKey(#[source] RevokeSessionKeyError),
+    #[error("register multisig action was not valid")]
+    RegisterMultisig(#[source] RegisterMultisigError),
 }
 
 #[derive(Clone, Debug)]
@@ -319,3 +457,351 @@ enum TransferActionErrorKind {
     #[error("`fee_asset` field did not contain a valid asset ID")]
     FeeAsset(#[source] asset::ParseDenomError),
 }
+
+/// Sets how much of `asset` the signer spends on the fees of transactions sent by `sponsored`.
+///
+/// A `limit` of zero revokes the sponsorship.
+#[derive(Clone, Debug)]
+pub struct SetSponsorLimit {
+    pub sponsored: Address,
+    pub asset: asset::Denom,
+    pub limit: u128,
+}
+
+impl Protobuf for SetSponsorLimit {
+    type Error = SetSponsorLimitError;
+    type Raw = raw::SetSponsorLimit;
+
+    #[must_use]
+    fn to_raw(&self) -> raw::SetSponsorLimit {
+        let Self {
+            sponsored,
+            asset,
+            limit,
+        } = self;
+        raw::SetSponsorLimit {
+            sponsored: Some(sponsored.to_raw()),
+            asset: asset.to_string(),
+            limit: Some((*limit).into()),
+        }
+    }
+
+    /// Convert from a reference to the raw protobuf type.
+    ///
+    /// # Errors
+    /// Returns `SetSponsorLimitError` if the raw action's `sponsored` address is unset or invalid,
+    /// or if its `asset` is not a valid denom.
+    fn try_from_raw_ref(raw: &Self::Raw) -> Result<Self, Self::Error> {
+        let raw::SetSponsorLimit {
+            sponsored,
+            asset,
+            limit,
+        } = raw;
+        let Some(sponsored) = sponsored else {
+            return Err(SetSponsorLimitError::field_not_set("sponsored"));
+        };
+        let sponsored =
+            Address::try_from_raw_ref(sponsored).map_err(SetSponsorLimitError::address)?;
+        let asset = asset.parse().map_err(SetSponsorLimitError::asset)?;
+        let limit = limit.map_or(0, Into::into);
+        Ok(Self {
+            sponsored,
+            asset,
+            limit,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct SetSponsorLimitError(SetSponsorLimitActionErrorKind);
+
+impl SetSponsorLimitError {
+    fn field_not_set(field: &'static str) -> Self {
+        Self(SetSponsorLimitActionErrorKind::FieldNotSet(field))
+    }
+
+    fn address(inner: AddressError) -> Self {
+        Self(SetSponsorLimitActionErrorKind::Address(inner))
+    }
+
+    fn asset(inner: asset::ParseDenomError) -> Self {
+        Self(SetSponsorLimitActionErrorKind::Asset(inner))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum SetSponsorLimitActionErrorKind {
+    #[error("the expected field in the raw source type was not set: `{0}`")]
+    FieldNotSet(&'static str),
+    #[error("`sponsored` field did not contain a valid address")]
+    Address(#[source] AddressError),
+    #[error("`asset` field did not contain a valid asset ID")]
+    Asset(#[source] asset::ParseDenomError),
+}
+
+/// Authorizes `verification_key` to sign transactions containing only `actions` on behalf of the
+/// signer, up to and including the rollup height `expires_at_height`.
+#[derive(Clone, Debug)]
+pub struct GrantSessionKey {
+    pub verification_key: VerificationKey,
+    pub actions: Vec<ActionKind>,
+    pub expires_at_height: u32,
+}
+
+impl Protobuf for GrantSessionKey {
+    type Error = GrantSessionKeyError;
+    type Raw = raw::GrantSessionKey;
+
+    #[must_use]
+    fn to_raw(&self) -> raw::GrantSessionKey {
+        let Self {
+            verification_key,
+            actions,
+            expires_at_height,
+        } = self;
+        raw::GrantSessionKey {
+            verification_key: bytes::Bytes::copy_from_slice(&verification_key.to_bytes()),
+            actions: actions.iter().map(ToString::to_string).collect(),
+            expires_at_height: *expires_at_height,
+        }
+    }
+
+    /// Convert from a reference to the raw protobuf type.
+    ///
+    /// # Errors
+    /// Returns `GrantSessionKeyError` if the raw action's `verification_key` is not a valid key,
+    /// or if `actions` is empty or names an unknown action.
+    fn try_from_raw_ref(raw: &Self::Raw) -> Result<Self, Self::Error> {
+        let raw::GrantSessionKey {
+            verification_key,
+            actions,
+            expires_at_height,
+        } = raw;
+        let verification_key = VerificationKey::try_from(&**verification_key)
+            .map_err(GrantSessionKeyError::verification_key)?;
+        if actions.is_empty() {
+            return Err(GrantSessionKeyError::field_not_set("actions"));
+        }
+        let actions = actions
+            .iter()
+            .map(|action| action.parse())
+            .collect::<Result<_, _>>()
+            .map_err(GrantSessionKeyError::action_kind)?;
+        Ok(Self {
+            verification_key,
+            actions,
+            expires_at_height: *expires_at_height,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct GrantSessionKeyError(GrantSessionKeyActionErrorKind);
+
+impl GrantSessionKeyError {
+    fn field_not_set(field: &'static str) -> Self {
+        Self(GrantSessionKeyActionErrorKind::FieldNotSet(field))
+    }
+
+    fn verification_key(inner: crypto::Error) -> Self {
+        Self(GrantSessionKeyActionErrorKind::VerificationKey(inner))
+    }
+
+    fn action_kind(inner: UnknownActionKind) -> Self {
+        Self(GrantSessionKeyActionErrorKind::ActionKind(inner))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum GrantSessionKeyActionErrorKind {
+    #[error("the expected field in the raw source type was not set: `{0}`")]
+    FieldNotSet(&'static str),
+    #[error("`verification_key` field did not contain a valid verification key")]
+    VerificationKey(#[source] crypto::Error),
+    #[error("`actions` field contained an invalid action kind")]
+    ActionKind(#[source] UnknownActionKind),
+}
+
+/// Revokes the session key `verification_key` granted by the signer.
+#[derive(Clone, Debug)]
+pub struct RevokeSessionKey {
+    pub verification_key: VerificationKey,
+}
+
+impl Protobuf for RevokeSessionKey {
+    type Error = RevokeSessionKeyError;
+    type Raw = raw::RevokeSessionKey;
+
+    #[must_use]
+    fn to_raw(&self) -> raw::RevokeSessionKey {
+        raw::RevokeSessionKey {
+            verification_key: bytes::Bytes::copy_from_slice(&self.verification_key.to_bytes()),
+        }
+    }
+
+    /// Convert from a reference to the raw protobuf type.
+    ///
+    /// # Errors
+    /// Returns `RevokeSessionKeyError` if the raw action's `verification_key` is not a valid key.
+    fn try_from_raw_ref(raw: &Self::Raw) -> Result<Self, Self::Error> {
+        let verification_key = VerificationKey::try_from(&*raw.verification_key)
+            .map_err(RevokeSessionKeyError::verification_key)?;
+        Ok(Self { verification_key })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct RevokeSessionKeyError(RevokeSessionKeyActionErrorKind);
+
+impl RevokeSessionKeyError {
+    fn verification_key(inner: crypto::Error) -> Self {
+        Self(RevokeSessionKeyActionErrorKind::VerificationKey(inner))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum RevokeSessionKeyActionErrorKind {
+    #[error("`verification_key` field did not contain a valid verification key")]
+    VerificationKey(#[source] crypto::Error),
+}
+
+/// Prefixed to the keys and threshold hashed into the address of a multisig account.
+const MULTISIG_ADDRESS_PREFIX: &[u8] = b"chat-rollup/multisig";
+
+/// Registers a multisig account controlled by `public_keys`, of which `threshold` must sign its
+/// transactions.
+///
+/// Members are Ed25519 keys only: cosignatures are Ed25519 signatures, so a secp256k1 key could
+/// never sign for the account.
+#[derive(Clone, Debug)]
+pub struct RegisterMultisig {
+    public_keys: Vec<VerificationKey>,
+    threshold: u32,
+}
+
+impl RegisterMultisig {
+    /// Returns the action registering a multisig account of `public_keys` with `threshold`.
+    ///
+    /// # Errors
+    /// Returns an error if `public_keys` contains duplicates, or if `threshold` is zero or larger
+    /// than the number of keys.
+    pub fn try_new(
+        public_keys: Vec<VerificationKey>,
+        threshold: u32,
+    ) -> Result<Self, RegisterMultisigError> {
+        let mut addresses: Vec<_> = public_keys.iter().map(|key| key.address_bytes()).collect();
+        addresses.sort_unstable();
+        addresses.dedup();
+        if addresses.len() != public_keys.len() {
+            return Err(RegisterMultisigError::duplicate_public_key());
+        }
+        let enough_keys = usize::try_from(threshold).is_ok_and(|t| t <= public_keys.len());
+        if threshold == 0 || !enough_keys {
+            return Err(RegisterMultisigError::invalid_threshold(
+                threshold,
+                public_keys.len(),
+            ));
+        }
+        Ok(Self {
+            public_keys,
+            threshold,
+        })
+    }
+
+    #[must_use]
+    pub fn public_keys(&self) -> &[VerificationKey] {
+        &self.public_keys
+    }
+
+    #[must_use]
+    pub fn threshold(&self) -> u32 {
+        self.threshold
+    }
+
+    /// Returns the address of the registered multisig account.
+    ///
+    /// The address is the first 20 bytes of the sha256 hash of the threshold and the sorted
+    /// public keys, so it does not depend on the order the keys are listed in.
+    #[must_use]
+    pub fn address_bytes(&self) -> [u8; ADDRESS_LEN] {
+        use sha2::{Digest as _, Sha256};
+        let mut public_keys: Vec<_> = self.public_keys.iter().map(|key| key.to_bytes()).collect();
+        public_keys.sort_unstable();
+        let mut hasher = Sha256::new();
+        hasher.update(MULTISIG_ADDRESS_PREFIX);
+        hasher.update(self.threshold.to_be_bytes());
+        for public_key in public_keys {
+            hasher.update(public_key);
+        }
+        let hash = hasher.finalize();
+        let mut address = [0; ADDRESS_LEN];
+        address.copy_from_slice(&hash[..ADDRESS_LEN]);
+        address
+    }
+}
+
+impl Protobuf for RegisterMultisig {
+    type Error = RegisterMultisigError;
+    type Raw = raw::RegisterMultisig;
+
+    #[must_use]
+    fn to_raw(&self) -> raw::RegisterMultisig {
+        raw::RegisterMultisig {
+            public_keys: self
+                .public_keys
+                .iter()
+                .map(|key| bytes::Bytes::copy_from_slice(&key.to_bytes()))
+                .collect(),
+            threshold: self.threshold,
+        }
+    }
+
+    /// Convert from a reference to the raw protobuf type.
+    ///
+    /// # Errors
+    /// Returns `RegisterMultisigError` if one of the raw action's `public_keys` is not a valid
+    /// Ed25519 key, if they contain duplicates, or if `threshold` cannot be met.
+    fn try_from_raw_ref(raw: &Self::Raw) -> Result<Self, Self::Error> {
+        let raw::RegisterMultisig {
+            public_keys,
+            threshold,
+        } = raw;
+        let public_keys = public_keys
+            .iter()
+            .map(|key| VerificationKey::try_from(&**key))
+            .collect::<Result<_, _>>()
+            .map_err(RegisterMultisigError::public_key)?;
+        Self::try_new(public_keys, *threshold)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct RegisterMultisigError(RegisterMultisigActionErrorKind);
+
+impl RegisterMultisigError {
+    fn public_key(inner: crypto::Error) -> Self {
+        Self(RegisterMultisigActionErrorKind::PublicKey(inner))
+    }
+
+    fn duplicate_public_key() -> Self {
+        Self(RegisterMultisigActionErrorKind::DuplicatePublicKey)
+    }
+
+    fn invalid_threshold(threshold: u32, keys: usize) -> Self {
+        Self(RegisterMultisigActionErrorKind::InvalidThreshold { threshold, keys })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum RegisterMultisigActionErrorKind {
+    #[error("`public_keys` field contained an invalid verification key")]
+    PublicKey(#[source] crypto::Error),
+    #[error("`public_keys` field contained the same key more than once")]
+    DuplicatePublicKey,
+    #[error("`threshold` must be between 1 and the number of keys `{keys}`, got `{threshold}`")]
+    InvalidThreshold { threshold: u32, keys: usize },
+}
@@ -4,11 +4,12 @@ use prost::{Message as _, Name as _};
 use crate::generated::protocol::transaction::v1 as raw;
 use astria_core::{
     crypto::{self, Signature, SigningKey, VerificationKey},
-    primitive::v1::{TransactionId, ADDRESS_LEN},
+    primitive::v1::{Address, AddressError, TransactionId, ADDRESS_LEN},
     Protobuf,
 };
 
 pub mod action;
+pub mod secp256k1;
 use action::group::Actions;
 pub use action::{
     group::{Error, Group},
@@ -39,6 +40,62 @@ impl TransactionError {
     fn unset_body() -> Self {
         Self(TransactionErrorKind::UnsetBody)
     }
+
+    fn sponsor_signature(inner: crypto::Error) -> Self {
+        Self(TransactionErrorKind::SponsorSignature(inner))
+    }
+
+    fn sponsor_verification(inner: crypto::Error) -> Self {
+        Self(TransactionErrorKind::SponsorVerification(inner))
+    }
+
+    fn sponsor_verification_key(inner: crypto::Error) -> Self {
+        Self(TransactionErrorKind::SponsorVerificationKey(inner))
+    }
+
+    fn unset_sponsor_signature() -> Self {
+        Self(TransactionErrorKind::UnsetSponsorSignature)
+    }
+
+    fn unexpected_sponsor_signature() -> Self {
+        Self(TransactionErrorKind::UnexpectedSponsorSignature)
+    }
+
+    fn sponsor_mismatch() -> Self {
+        Self(TransactionErrorKind::SponsorMismatch)
+    }
+
+    fn cosignature(inner: crypto::Error) -> Self {
+        Self(TransactionErrorKind::Cosignature(inner))
+    }
+
+    fn cosignature_verification_key(inner: crypto::Error) -> Self {
+        Self(TransactionErrorKind::CosignatureVerificationKey(inner))
+    }
+
+    fn cosignature_verification(inner: crypto::Error) -> Self {
+        Self(TransactionErrorKind::CosignatureVerification(inner))
+    }
+
+    fn unexpected_cosignatures() -> Self {
+        Self(TransactionErrorKind::UnexpectedCosignatures)
+    }
+
+    fn secp256k1_multisig_signer() -> Self {
+        Self(TransactionErrorKind::Secp256k1MultisigSigner)
+    }
+
+    fn duplicate_signer() -> Self {
+        Self(TransactionErrorKind::DuplicateSigner)
+    }
+
+    fn signature_scheme(got: i32) -> Self {
+        Self(TransactionErrorKind::SignatureScheme { got })
+    }
+
+    fn secp256k1(inner: secp256k1::Secp256k1Error) -> Self {
+        Self(TransactionErrorKind::Secp256k1(inner))
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -53,6 +110,259 @@ enum TransactionErrorKind {
     VerificationKey(#[source] crypto::Error),
     #[error("transaction could not be verified given the signature and verification key")]
     Verification(crypto::Error),
+    #[error("`sponsor_signature` field not set, but the body names a sponsor")]
+    UnsetSponsorSignature,
+    #[error("`sponsor_signature` field set, but the body does not name a sponsor")]
+    UnexpectedSponsorSignature,
+    #[error("`sponsor_signature.signature` field invalid")]
+    SponsorSignature(#[source] crypto::Error),
+    #[error("`sponsor_signature.public_key` field invalid")]
+    SponsorVerificationKey(#[source] crypto::Error),
+    #[error("`sponsor_signature.public_key` does not belong to the sponsor named in the body")]
+    SponsorMismatch,
+    #[error("transaction could not be verified given the sponsor signature and verification key")]
+    SponsorVerification(crypto::Error),
+    #[error("`cosignatures` field set, but the body does not name a multisig account")]
+    UnexpectedCosignatures,
+    #[error("multisig transaction was signed with a secp256k1 key, but members are Ed25519 keys")]
+    Secp256k1MultisigSigner,
+    #[error("`cosignatures.signature` field invalid")]
+    Cosignature(#[source] crypto::Error),
+    #[error("`cosignatures.public_key` field invalid")]
+    CosignatureVerificationKey(#[source] crypto::Error),
+    #[error("transaction could not be verified given a cosignature and its verification key")]
+    CosignatureVerification(crypto::Error),
+    #[error("transaction was signed more than once by the same key")]
+    DuplicateSigner,
+    #[error("`signature_scheme` field contained unknown scheme `{got}`")]
+    SignatureScheme { got: i32 },
+    #[error("transaction could not be verified as signed with a secp256k1 key")]
+    Secp256k1(#[source] secp256k1::Secp256k1Error),
+}
+
+/// Prefixed to the body bytes signed by the sponsor of a transaction, so that a sponsor
+/// signature can never be used as the signature of a sender.
+pub const SPONSOR_SIGNATURE_PREFIX: &[u8] = b"chat-rollup/sponsor";
+
+fn sponsor_signing_bytes(body_bytes: &[u8]) -> Vec<u8> {
+    [SPONSOR_SIGNATURE_PREFIX, body_bytes].concat()
+}
+
+/// The signature of the sponsor of a [`Transaction`], agreeing to pay its fees.
+#[derive(Clone, Debug)]
+pub struct SponsorSignature {
+    signature: Signature,
+    verification_key: VerificationKey,
+}
+
+impl SponsorSignature {
+    /// Verifies `raw` as the signature of `sponsor` over `body_bytes`.
+    fn try_from_raw_ref(
+        raw: &raw::SponsorSignature,
+        sponsor: &Address,
+        body_bytes: &[u8],
+    ) -> Result<Self, TransactionError> {
+        let signature =
+            Signature::try_from(&*raw.signature).map_err(TransactionError::sponsor_signature)?;
+        let verification_key = VerificationKey::try_from(&*raw.public_key)
+            .map_err(TransactionError::sponsor_verification_key)?;
+        if verification_key.address_bytes() != sponsor.as_bytes() {
+            return Err(TransactionError::sponsor_mismatch());
+        }
+        verification_key
+            .verify(&signature, &sponsor_signing_bytes(body_bytes))
+            .map_err(TransactionError::sponsor_verification)?;
+        Ok(Self {
+            signature,
+            verification_key,
+        })
+    }
+
+    fn to_raw(&self) -> raw::SponsorSignature {
+        raw::SponsorSignature {
+            signature: Bytes::copy_from_slice(&self.signature.to_bytes()),
+            public_key: Bytes::copy_from_slice(&self.verification_key.to_bytes()),
+        }
+    }
+
+    #[must_use]
+    pub fn signature(&self) -> Signature {
+        self.signature
+    }
+
+    #[must_use]
+    pub fn verification_key(&self) -> &VerificationKey {
+        &self.verification_key
+    }
+}
+
+/// Verifies that `sponsor_signature` is set exactly if `body` names a sponsor, and that it was
+/// made by that sponsor over `body_bytes`.
+fn verify_sponsor_signature(
+    body: &TransactionBody,
+    sponsor_signature: Option<&raw::SponsorSignature>,
+    body_bytes: &[u8],
+) -> Result<Option<SponsorSignature>, TransactionError> {
+    match (body.sponsor(), sponsor_signature) {
+        (None, None) => Ok(None),
+        (None, Some(_)) => Err(TransactionError::unexpected_sponsor_signature()),
+        (Some(_), None) => Err(TransactionError::unset_sponsor_signature()),
+        (Some(sponsor), Some(raw)) => {
+            SponsorSignature::try_from_raw_ref(raw, sponsor, body_bytes).map(Some)
+        }
+    }
+}
+
+/// The signature of a further member of the multisig account a [`Transaction`] acts for.
+///
+/// Members of multisig accounts are Ed25519 keys only, so cosignatures are always Ed25519
+/// signatures.
+#[derive(Clone, Debug)]
+pub struct Cosignature {
+    signature: Signature,
+    verification_key: VerificationKey,
+}
+
+impl Cosignature {
+    /// Verifies `raw` as a signature over `body_bytes`.
+    fn try_from_raw_ref(
+        raw: &raw::Cosignature,
+        body_bytes: &[u8],
+    ) -> Result<Self, TransactionError> {
+        let signature =
+            Signature::try_from(&*raw.signature).map_err(TransactionError::cosignature)?;
+        let verification_key = VerificationKey::try_from(&*raw.public_key)
+            .map_err(TransactionError::cosignature_verification_key)?;
+        verification_key
+            .verify(&signature, body_bytes)
+            .map_err(TransactionError::cosignature_verification)?;
+        Ok(Self {
+            signature,
+            verification_key,
+        })
+    }
+
+    fn to_raw(&self) -> raw::Cosignature {
+        raw::Cosignature {
+            signature: Bytes::copy_from_slice(&self.signature.to_bytes()),
+            public_key: Bytes::copy_from_slice(&self.verification_key.to_bytes()),
+        }
+    }
+
+    #[must_use]
+    pub fn signature(&self) -> Signature {
+        self.signature
+    }
+
+    #[must_use]
+    pub fn verification_key(&self) -> &VerificationKey {
+        &self.verification_key
+    }
+}
+
+/// Verifies that `cosignatures` are only set if `body` names a multisig account, that each was
+/// made over `body_bytes`, and that no key signed more than once. The transaction of a multisig
+/// account must be signed with an Ed25519 key, as its members are Ed25519 keys.
+fn verify_cosignatures(
+    body: &TransactionBody,
+    cosignatures: &[raw::Cosignature],
+    signer: &Signer,
+    body_bytes: &[u8],
+) -> Result<Vec<Cosignature>, TransactionError> {
+    if body.multisig().is_none() && !cosignatures.is_empty() {
+        return Err(TransactionError::unexpected_cosignatures());
+    }
+    if body.multisig().is_some() && matches!(signer, Signer::Secp256k1(_)) {
+        return Err(TransactionError::secp256k1_multisig_signer());
+    }
+    let mut signers = vec![*signer.address_bytes()];
+    let mut verified = Vec::with_capacity(cosignatures.len());
+    for raw in cosignatures {
+        let cosignature = Cosignature::try_from_raw_ref(raw, body_bytes)?;
+        let signer = *cosignature.verification_key.address_bytes();
+        if signers.contains(&signer) {
+            return Err(TransactionError::duplicate_signer());
+        }
+        signers.push(signer);
+        verified.push(cosignature);
+    }
+    Ok(verified)
+}
+
+/// The key that signed a [`Transaction`] together with its signature.
+///
+/// Accounts of both kinds coexist: an Ed25519 key signs for the address derived from its
+/// verification key, a secp256k1 key for its Ethereum address.
+#[derive(Clone, Debug)]
+pub enum Signer {
+    Ed25519 {
+        signature: Signature,
+        verification_key: VerificationKey,
+    },
+    Secp256k1(secp256k1::Secp256k1Signature),
+}
+
+impl Signer {
+    /// Verifies `signature` by `public_key` over `body_bytes` using `signature_scheme`.
+    fn try_from_raw_parts(
+        signature_scheme: i32,
+        signature: &[u8],
+        public_key: &Bytes,
+        body_bytes: &[u8],
+    ) -> Result<Self, TransactionError> {
+        let signature_scheme = raw::SignatureScheme::try_from(signature_scheme)
+            .map_err(|_| TransactionError::signature_scheme(signature_scheme))?;
+        match signature_scheme {
+            raw::SignatureScheme::Ed25519 => {
+                let signature =
+                    Signature::try_from(signature).map_err(TransactionError::signature)?;
+                let verification_key = VerificationKey::try_from(&**public_key)
+                    .map_err(TransactionError::verification_key)?;
+                verification_key
+                    .verify(&signature, body_bytes)
+                    .map_err(TransactionError::verification)?;
+                Ok(Self::Ed25519 {
+                    signature,
+                    verification_key,
+                })
+            }
+            raw::SignatureScheme::Secp256k1 => {
+                secp256k1::Secp256k1Signature::recover(signature, public_key, body_bytes)
+                    .map(Self::Secp256k1)
+                    .map_err(TransactionError::secp256k1)
+            }
+        }
+    }
+
+    /// Returns the `signature`, `public_key` and `signature_scheme` fields of the transaction.
+    fn to_raw_parts(&self) -> (Bytes, Bytes, raw::SignatureScheme) {
+        match self {
+            Self::Ed25519 {
+                signature,
+                verification_key,
+            } => (
+                Bytes::copy_from_slice(&signature.to_bytes()),
+                Bytes::copy_from_slice(&verification_key.to_bytes()),
+                raw::SignatureScheme::Ed25519,
+            ),
+            Self::Secp256k1(signature) => (
+                Bytes::copy_from_slice(&signature.to_bytes()),
+                signature.public_key().clone(),
+                raw::SignatureScheme::Secp256k1,
+            ),
+        }
+    }
+
+    /// Returns the address bytes of the signing key.
+    #[must_use]
+    pub fn address_bytes(&self) -> &[u8; ADDRESS_LEN] {
+        match self {
+            Self::Ed25519 {
+                verification_key, ..
+            } => verification_key.address_bytes(),
+            Self::Secp256k1(signature) => signature.address_bytes(),
+        }
+    }
 }
 
 /// An Astria transaction.
@@ -61,10 +371,11 @@ enum TransactionErrorKind {
 /// with its signature and public key.
 #[derive(Clone, Debug)]
 pub struct Transaction {
-    signature: Signature,
-    verification_key: VerificationKey,
+    signer: Signer,
     body: TransactionBody,
     body_bytes: bytes::Bytes,
+    sponsor_signature: Option<SponsorSignature>,
+    cosignatures: Vec<Cosignature>,
 }
 
 impl Protobuf for Transaction {
@@ -76,24 +387,26 @@ impl Protobuf for Transaction {
             signature,
             public_key,
             body,
+            sponsor_signature,
+            cosignatures,
+            signature_scheme,
         } = raw;
-        let signature = Signature::try_from(&**signature).map_err(TransactionError::signature)?;
-        let verification_key =
-            VerificationKey::try_from(&**public_key).map_err(TransactionError::verification_key)?;
         let Some(body) = body else {
             return Err(TransactionError::unset_body());
         };
         let bytes = body.value.clone();
-        verification_key
-            .verify(&signature, &bytes)
-            .map_err(TransactionError::verification)?;
+        let signer = Signer::try_from_raw_parts(*signature_scheme, signature, public_key, &bytes)?;
         let transaction =
             TransactionBody::try_from_any(body.clone()).map_err(TransactionError::body)?;
+        let sponsor_signature =
+            verify_sponsor_signature(&transaction, sponsor_signature.as_ref(), &bytes)?;
+        let cosignatures = verify_cosignatures(&transaction, cosignatures, &signer, &bytes)?;
         Ok(Self {
-            signature,
-            verification_key,
+            signer,
             body: transaction,
             body_bytes: bytes,
+            sponsor_signature,
+            cosignatures,
         })
     }
 
@@ -102,64 +415,190 @@ impl Protobuf for Transaction {
             signature,
             public_key,
             body,
+            sponsor_signature,
+            cosignatures,
+            signature_scheme,
         } = raw;
-        let signature = Signature::try_from(&*signature).map_err(TransactionError::signature)?;
-        let verification_key =
-            VerificationKey::try_from(&*public_key).map_err(TransactionError::verification_key)?;
         let Some(body) = body else {
             return Err(TransactionError::unset_body());
         };
         let bytes = body.value.clone();
-        verification_key
-            .verify(&signature, &bytes)
-            .map_err(TransactionError::verification)?;
+        let signer = Signer::try_from_raw_parts(signature_scheme, &signature, &public_key, &bytes)?;
         let transaction = TransactionBody::try_from_any(body).map_err(TransactionError::body)?;
+        let sponsor_signature =
+            verify_sponsor_signature(&transaction, sponsor_signature.as_ref(), &bytes)?;
+        let cosignatures = verify_cosignatures(&transaction, cosignatures, &signer, &bytes)?;
         Ok(Self {
-            signature,
-            verification_key,
+            signer,
             body: transaction,
             body_bytes: bytes,
+            sponsor_signature,
+            cosignatures,
         })
     }
 
     fn into_raw(self) -> raw::Transaction {
         let Self {
-            signature,
-            verification_key,
+            signer,
             body_bytes: transaction_bytes,
+            sponsor_signature,
+            cosignatures,
             ..
         } = self;
+        let (signature, public_key, signature_scheme) = signer.to_raw_parts();
         Self::Raw {
-            signature: Bytes::copy_from_slice(&signature.to_bytes()),
-            public_key: Bytes::copy_from_slice(&verification_key.to_bytes()),
+            signature,
+            public_key,
             body: Some(pbjson_types::Any {
                 type_url: raw::TransactionBody::type_url(),
                 value: transaction_bytes,
             }),
+            sponsor_signature: sponsor_signature.as_ref().map(SponsorSignature::to_raw),
+            cosignatures: cosignatures.iter().map(Cosignature::to_raw).collect(),
+            signature_scheme: signature_scheme.into(),
         }
     }
 
     fn to_raw(&self) -> raw::Transaction {
         let Self {
-            signature,
-            verification_key,
+            signer,
             body_bytes: transaction_bytes,
+            sponsor_signature,
+            cosignatures,
             ..
         } = self;
+        let (signature, public_key, signature_scheme) = signer.to_raw_parts();
         Self::Raw {
-            signature: Bytes::copy_from_slice(&signature.to_bytes()),
-            public_key: Bytes::copy_from_slice(&verification_key.to_bytes()),
+            signature,
+            public_key,
             body: Some(pbjson_types::Any {
                 type_url: raw::TransactionBody::type_url(),
                 value: transaction_bytes.clone(),
             }),
+            sponsor_signature: sponsor_signature.as_ref().map(SponsorSignature::to_raw),
+            cosignatures: cosignatures.iter().map(Cosignature::to_raw).collect(),
+            signature_scheme: signature_scheme.into(),
         }
     }
 }
 
 impl Transaction {
+    /// Returns the address bytes of the account the transaction acts for: the multisig account
+    /// if it is signed by members of one, its granter if it is signed by a session key, its signer
+    /// otherwise.
+    ///
+    /// Whether the signers are members of the multisig account or hold a session key of the
+    /// granter is not checked here, as it depends on the state of the rollup.
     pub fn address_bytes(&self) -> &[u8; ADDRESS_LEN] {
-        self.verification_key.address_bytes()
+        self.body
+            .multisig()
+            .or(self.body.granter())
+            .map_or_else(|| self.signer_address_bytes(), Address::as_bytes)
+    }
+
+    /// Returns the multisig account the transaction acts for, if any.
+    #[must_use]
+    pub fn multisig(&self) -> Option<&Address> {
+        self.body.multisig()
+    }
+
+    #[must_use]
+    pub fn cosignatures(&self) -> &[Cosignature] {
+        &self.cosignatures
+    }
+
+    /// Returns the address bytes of the keys that signed the transaction: its signer followed by
+    /// its cosigners.
+    pub fn signers(&self) -> impl Iterator<Item = &[u8; ADDRESS_LEN]> {
+        std::iter::once(self.signer.address_bytes()).chain(
+            self.cosignatures
+                .iter()
+                .map(|cosignature| cosignature.verification_key.address_bytes()),
+        )
+    }
+
+    /// Adds the signature of a further member of the multisig account named in the body.
+    ///
+    /// # Errors
+    /// Returns an error if the body does not name a multisig account, if the transaction was
+    /// signed with a secp256k1 key, or if `signing_key` already signed the transaction.
+    pub fn cosign(mut self, signing_key: &SigningKey) -> Result<Self, TransactionError> {
+        if self.body.multisig().is_none() {
+            return Err(TransactionError::unexpected_cosignatures());
+        }
+        if matches!(self.signer, Signer::Secp256k1(_)) {
+            return Err(TransactionError::secp256k1_multisig_signer());
+        }
+        let verification_key = signing_key.verification_key();
+        if self
+            .signers()
+            .any(|signer| signer == verification_key.address_bytes())
+        {
+            return Err(TransactionError::duplicate_signer());
+        }
+        let signature = signing_key.sign(&self.body_bytes);
+        self.cosignatures.push(Cosignature {
+            signature,
+            verification_key,
+        });
+        Ok(self)
+    }
+
+    /// Returns the address bytes of the key that signed the transaction.
+    #[must_use]
+    pub fn signer_address_bytes(&self) -> &[u8; ADDRESS_LEN] {
+        self.signer.address_bytes()
+    }
+
+    /// Returns the account that granted the session key signing the transaction, if any.
+    #[must_use]
+    pub fn granter(&self) -> Option<&Address> {
+        self.body.granter()
+    }
+
+    /// Returns the address bytes of the account paying the fees of the transaction: its sponsor
+    /// if it has one, its signer otherwise.
+    #[must_use]
+    pub fn fee_payer_address_bytes(&self) -> &[u8; ADDRESS_LEN] {
+        self.sponsor_signature.as_ref().map_or_else(
+            || self.address_bytes(),
+            |sponsor| sponsor.verification_key.address_bytes(),
+        )
+    }
+
+    /// Returns the sponsor named in the body of the transaction.
+    #[must_use]
+    pub fn sponsor(&self) -> Option<&Address> {
+        self.body.sponsor()
+    }
+
+    #[must_use]
+    pub fn sponsor_signature(&self) -> Option<&SponsorSignature> {
+        self.sponsor_signature.as_ref()
+    }
+
+    /// Adds the signature of the sponsor named in the body, agreeing to pay the fees of the
+    /// transaction.
+    ///
+    /// # Errors
+    /// Returns an error if the body does not name a sponsor, or if `signing_key` does not belong
+    /// to it.
+    pub fn sign_as_sponsor(self, signing_key: &SigningKey) -> Result<Self, TransactionError> {
+        let Some(sponsor) = self.body.sponsor() else {
+            return Err(TransactionError::unexpected_sponsor_signature());
+        };
+        let verification_key = signing_key.verification_key();
+        if verification_key.address_bytes() != sponsor.as_bytes() {
+            return Err(TransactionError::sponsor_mismatch());
+        }
+        let signature = signing_key.sign(&sponsor_signing_bytes(&self.body_bytes));
+        Ok(Self {
+            sponsor_signature: Some(SponsorSignature {
+                signature,
+                verification_key,
+            }),
+            ..self
+        })
     }
 
     /// Returns the transaction ID, containing the transaction hash.
@@ -194,13 +633,8 @@ impl Transaction {
     }
 
     #[must_use]
-    pub fn signature(&self) -> Signature {
-        self.signature
-    }
-
-    #[must_use]
-    pub fn verification_key(&self) -> &VerificationKey {
-        &self.verification_key
+    pub fn signer(&self) -> &Signer {
+        &self.signer
     }
 
     #[must_use]
@@ -216,6 +650,16 @@ impl Transaction {
     pub fn nonce(&self) -> u32 {
         self.body.nonce()
     }
+
+    #[must_use]
+    pub fn valid_until_height(&self) -> Option<u32> {
+        self.body.valid_until_height()
+    }
+
+    #[must_use]
+    pub fn valid_until_timestamp(&self) -> Option<&pbjson_types::Timestamp> {
+        self.body.valid_until_timestamp()
+    }
 }
 
 impl From<Transaction> for raw::Transaction {
@@ -236,6 +680,9 @@ impl TryFrom<raw::Transaction> for Transaction {
 pub struct TransactionBody {
     actions: Actions,
     params: TransactionParams,
+    sponsor: Option<Address>,
+    granter: Option<Address>,
+    multisig: Option<Address>,
 }
 
 impl Protobuf for TransactionBody {
@@ -243,12 +690,36 @@ impl Protobuf for TransactionBody {
     type Raw = raw::TransactionBody;
 
     fn try_from_raw_ref(raw: &Self::Raw) -> Result<Self, Self::Error> {
-        let raw::TransactionBody { actions, params } = raw;
+        let raw::TransactionBody {
+            actions,
+            params,
+            sponsor,
+            granter,
+            multisig,
+        } = raw;
 
         let Some(params) = params else {
             return Err(TransactionBodyError::unset_params());
         };
         let params = TransactionParams::from_raw_ref(params);
+        let sponsor = sponsor
+            .as_ref()
+            .map(Address::try_from_raw_ref)
+            .transpose()
+            .map_err(TransactionBodyError::sponsor)?;
+        let granter = granter
+            .as_ref()
+            .map(Address::try_from_raw_ref)
+            .transpose()
+            .map_err(TransactionBodyError::granter)?;
+        let multisig = multisig
+            .as_ref()
+            .map(Address::try_from_raw_ref)
+            .transpose()
+            .map_err(TransactionBodyError::multisig)?;
+        if granter.is_some() && multisig.is_some() {
+            return Err(TransactionBodyError::granter_and_multisig());
+        }
         let actions: Vec<_> = actions
             .iter()
             .map(Action::try_from_raw_ref)
@@ -259,16 +730,42 @@ impl Protobuf for TransactionBody {
             .actions(actions)
             .chain_id(params.chain_id)
             .nonce(params.nonce)
+            .valid_until_height(params.valid_until_height)
+            .valid_until_timestamp(params.valid_until_timestamp)
+            .sponsor(sponsor)
+            .granter(granter)
+            .multisig(multisig)
             .try_build()
             .map_err(TransactionBodyError::group)
     }
 
     fn try_from_raw(proto: Self::Raw) -> Result<Self, TransactionBodyError> {
-        let raw::TransactionBody { actions, params } = proto;
+        let raw::TransactionBody {
+            actions,
+            params,
+            sponsor,
+            granter,
+            multisig,
+        } = proto;
         let Some(params) = params else {
             return Err(TransactionBodyError::unset_params());
         };
         let params = TransactionParams::from_raw(params);
+        let sponsor = sponsor
+            .map(Address::try_from_raw)
+            .transpose()
+            .map_err(TransactionBodyError::sponsor)?;
+        let granter = granter
+            .map(Address::try_from_raw)
+            .transpose()
+            .map_err(TransactionBodyError::granter)?;
+        let multisig = multisig
+            .map(Address::try_from_raw)
+            .transpose()
+            .map_err(TransactionBodyError::multisig)?;
+        if granter.is_some() && multisig.is_some() {
+            return Err(TransactionBodyError::granter_and_multisig());
+        }
         let actions: Vec<_> = actions
             .into_iter()
             .map(Action::try_from_raw)
@@ -279,12 +776,23 @@ impl Protobuf for TransactionBody {
             .actions(actions)
             .chain_id(params.chain_id)
             .nonce(params.nonce)
+            .valid_until_height(params.valid_until_height)
+            .valid_until_timestamp(params.valid_until_timestamp)
+            .sponsor(sponsor)
+            .granter(granter)
+            .multisig(multisig)
             .try_build()
             .map_err(TransactionBodyError::group)
     }
 
     fn into_raw(self) -> raw::TransactionBody {
-        let Self { actions, params } = self;
+        let Self {
+            actions,
+            params,
+            sponsor,
+            granter,
+            multisig,
+        } = self;
         let actions = actions
             .into_actions()
             .into_iter()
@@ -293,16 +801,28 @@ impl Protobuf for TransactionBody {
         raw::TransactionBody {
             actions,
             params: Some(params.into_raw()),
+            sponsor: sponsor.map(Address::into_raw),
+            granter: granter.map(Address::into_raw),
+            multisig: multisig.map(Address::into_raw),
         }
     }
 
     fn to_raw(&self) -> Self::Raw {
-        let Self { actions, params } = self;
+        let Self {
+            actions,
+            params,
+            sponsor,
+            granter,
+            multisig,
+        } = self;
         let actions = actions.actions().iter().map(Action::to_raw).collect();
         let params = params.clone().into_raw();
         raw::TransactionBody {
             actions,
             params: Some(params),
+            sponsor: sponsor.as_ref().map(Address::to_raw),
+            granter: granter.as_ref().map(Address::to_raw),
+            multisig: multisig.as_ref().map(Address::to_raw),
         }
     }
 }
@@ -333,16 +853,70 @@ impl TransactionBody {
         &self.params.chain_id
     }
 
+    /// Returns the last rollup height at which the transaction can be executed, if any.
+    #[must_use]
+    pub fn valid_until_height(&self) -> Option<u32> {
+        self.params.valid_until_height
+    }
+
+    /// Returns the last block timestamp at which the transaction can be executed, if any.
+    #[must_use]
+    pub fn valid_until_timestamp(&self) -> Option<&pbjson_types::Timestamp> {
+        self.params.valid_until_timestamp.as_ref()
+    }
+
+    /// Returns the account paying the fees of the transaction in place of its signer.
+    #[must_use]
+    pub fn sponsor(&self) -> Option<&Address> {
+        self.sponsor.as_ref()
+    }
+
+    /// Returns the account the transaction acts for if it is signed by a session key.
+    #[must_use]
+    pub fn granter(&self) -> Option<&Address> {
+        self.granter.as_ref()
+    }
+
+    /// Returns the multisig account the transaction acts for if it is signed by its members.
+    #[must_use]
+    pub fn multisig(&self) -> Option<&Address> {
+        self.multisig.as_ref()
+    }
+
+    /// Signs the body with `signing_key`.
+    ///
+    /// If the body names a sponsor, the returned transaction must also be signed by it with
+    /// [`Transaction::sign_as_sponsor`] before it is valid.
     #[must_use]
     pub fn sign(self, signing_key: &SigningKey) -> Transaction {
         let bytes = self.to_raw().encode_to_vec();
-        let signature = signing_key.sign(&bytes);
-        let verification_key = signing_key.verification_key();
+        let signer = Signer::Ed25519 {
+            signature: signing_key.sign(&bytes),
+            verification_key: signing_key.verification_key(),
+        };
         Transaction {
-            signature,
-            verification_key,
+            signer,
             body: self,
             body_bytes: bytes.into(),
+            sponsor_signature: None,
+            cosignatures: Vec::new(),
+        }
+    }
+
+    /// Signs the body with the secp256k1 `signing_key`, the way an Ethereum wallet signs a
+    /// message with `personal_sign`.
+    ///
+    /// The transaction acts for the Ethereum address of the key.
+    #[must_use]
+    pub fn sign_secp256k1(self, signing_key: &secp256k1::SigningKey) -> Transaction {
+        let bytes = self.to_raw().encode_to_vec();
+        let signer = Signer::Secp256k1(secp256k1::Secp256k1Signature::sign(signing_key, &bytes));
+        Transaction {
+            signer,
+            body: self,
+            body_bytes: bytes.into(),
+            sponsor_signature: None,
+            cosignatures: Vec::new(),
         }
     }
 
@@ -401,6 +975,22 @@ impl TransactionBodyError {
     fn group(inner: action::group::Error) -> Self {
         Self(TransactionBodyErrorKind::Group(inner))
     }
+
+    fn sponsor(inner: AddressError) -> Self {
+        Self(TransactionBodyErrorKind::Sponsor(inner))
+    }
+
+    fn granter(inner: AddressError) -> Self {
+        Self(TransactionBodyErrorKind::Granter(inner))
+    }
+
+    fn multisig(inner: AddressError) -> Self {
+        Self(TransactionBodyErrorKind::Multisig(inner))
+    }
+
+    fn granter_and_multisig() -> Self {
+        Self(TransactionBodyErrorKind::GranterAndMultisig)
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -422,13 +1012,26 @@ enum TransactionBodyErrorKind {
     DecodeAny(#[source] prost::DecodeError),
     #[error("`actions` field does not form a valid group of actions")]
     Group(#[source] action::group::Error),
+    #[error("`sponsor` field did not contain a valid address")]
+    Sponsor(#[source] AddressError),
+    #[error("`granter` field did not contain a valid address")]
+    Granter(#[source] AddressError),
+    #[error("`multisig` field did not contain a valid address")]
+    Multisig(#[source] AddressError),
+    #[error("`granter` and `multisig` fields cannot both be set")]
+    GranterAndMultisig,
 }
 
 #[derive(Default)]
 pub struct TransactionBodyBuilder {
     nonce: u32,
     chain_id: String,
+    valid_until_height: Option<u32>,
+    valid_until_timestamp: Option<pbjson_types::Timestamp>,
     actions: Vec<Action>,
+    sponsor: Option<Address>,
+    granter: Option<Address>,
+    multisig: Option<Address>,
 }
 
 impl TransactionBodyBuilder {
@@ -447,7 +1050,12 @@ impl TransactionBodyBuilder {
         TransactionBodyBuilder {
             chain_id: chain_id.into(),
             nonce: self.nonce,
+            valid_until_height: self.valid_until_height,
+            valid_until_timestamp: self.valid_until_timestamp,
             actions: self.actions,
+            sponsor: self.sponsor,
+            granter: self.granter,
+            multisig: self.multisig,
         }
     }
 
@@ -456,6 +1064,56 @@ impl TransactionBodyBuilder {
         Self { nonce, ..self }
     }
 
+    /// Sets the last rollup height at which the transaction can be executed.
+    #[must_use]
+    pub fn valid_until_height<T: Into<Option<u32>>>(self, valid_until_height: T) -> Self {
+        Self {
+            valid_until_height: valid_until_height.into(),
+            ..self
+        }
+    }
+
+    /// Sets the last block timestamp at which the transaction can be executed.
+    #[must_use]
+    pub fn valid_until_timestamp<T: Into<Option<pbjson_types::Timestamp>>>(
+        self,
+        valid_until_timestamp: T,
+    ) -> Self {
+        Self {
+            valid_until_timestamp: valid_until_timestamp.into(),
+            ..self
+        }
+    }
+
+    /// Sets the account paying the fees of the transaction in place of its signer.
+    #[must_use]
+    pub fn sponsor<T: Into<Option<Address>>>(self, sponsor: T) -> Self {
+        Self {
+            sponsor: sponsor.into(),
+            ..self
+        }
+    }
+
+    /// Sets the account the transaction acts for, if it is signed by a session key granted by
+    /// that account.
+    #[must_use]
+    pub fn granter<T: Into<Option<Address>>>(self, granter: T) -> Self {
+        Self {
+            granter: granter.into(),
+            ..self
+        }
+    }
+
+    /// Sets the multisig account the transaction acts for, if it is signed by members of that
+    /// account.
+    #[must_use]
+    pub fn multisig<T: Into<Option<Address>>>(self, multisig: T) -> Self {
+        Self {
+            multisig: multisig.into(),
+            ..self
+        }
+    }
+
     /// Constructs a [`Body`] from the configured builder.
     ///
     /// # Errors
@@ -467,12 +1125,25 @@ impl TransactionBodyBuilder {
         let Self {
             nonce,
             chain_id,
+            valid_until_height,
+            valid_until_timestamp,
             actions,
+            sponsor,
+            granter,
+            multisig,
         } = self;
         let actions = Actions::try_from_list_of_actions(actions)?;
         Ok(TransactionBody {
             actions,
-            params: TransactionParams { nonce, chain_id },
+            params: TransactionParams {
+                nonce,
+                chain_id,
+                valid_until_height,
+                valid_until_timestamp,
+            },
+            sponsor,
+            granter,
+            multisig,
         })
     }
 }
@@ -481,33 +1152,60 @@ impl TransactionBodyBuilder {
 pub struct TransactionParams {
     nonce: u32,
     chain_id: String,
+    valid_until_height: Option<u32>,
+    valid_until_timestamp: Option<pbjson_types::Timestamp>,
 }
 
 impl TransactionParams {
     #[must_use]
     pub fn into_raw(self) -> raw::TransactionParams {
         let Self {
-            nonce, chain_id, ..
+            nonce,
+            chain_id,
+            valid_until_height,
+            valid_until_timestamp,
         } = self;
-        raw::TransactionParams { nonce, chain_id }
+        raw::TransactionParams {
+            nonce,
+            chain_id,
+            valid_until_height: valid_until_height.unwrap_or_default(),
+            valid_until_timestamp,
+        }
     }
 
     /// Convert from a raw protobuf [`raw::Body`].
     #[must_use]
     pub fn from_raw(proto: raw::TransactionParams) -> Self {
-        let raw::TransactionParams { nonce, chain_id } = proto;
+        let raw::TransactionParams {
+            nonce,
+            chain_id,
+            valid_until_height,
+            valid_until_timestamp,
+        } = proto;
 
-        Self { nonce, chain_id }
+        Self {
+            nonce,
+            chain_id,
+            valid_until_height: (valid_until_height != 0).then_some(valid_until_height),
+            valid_until_timestamp,
+        }
     }
 
     /// Convert from a raw protobuf [`raw::Body`].
     #[must_use]
     pub fn from_raw_ref(proto: &raw::TransactionParams) -> Self {
-        let raw::TransactionParams { nonce, chain_id } = proto;
+        let raw::TransactionParams {
+            nonce,
+            chain_id,
+            valid_until_height,
+            valid_until_timestamp,
+        } = proto;
 
         Self {
             nonce: *nonce,
             chain_id: chain_id.clone(),
+            valid_until_height: (*valid_until_height != 0).then_some(*valid_until_height),
+            valid_until_timestamp: valid_until_timestamp.clone(),
         }
     }
 }
@@ -515,7 +1213,7 @@ impl TransactionParams {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::transaction::v1::action::Transfer;
+    use crate::transaction::v1::action::{RegisterMultisig, SendText, Transfer};
     use astria_core::primitive::v1::{asset::Denom, Address};
     const ASTRIA_ADDRESS_PREFIX: &str = "astria";
 
@@ -554,4 +1252,354 @@ mod tests {
         // `try_from_raw` verifies the signature
         Transaction::try_from_raw(raw).unwrap();
     }
+
+    // The account and signature of `web3.eth.accounts.sign("Some data", ..)` from the web3.js
+    // documentation.
+    const ETHEREUM_PRIVATE_KEY: &str =
+        "4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+    const ETHEREUM_ADDRESS: &str = "2c7536e3605d9c16a7a3d7b1898e529396a65c23";
+    const ETHEREUM_SIGNATURE: &str = "b91467e570a6466aa9e9876cbcd013baba02900b8979d43fe208a4a4f33\
+                                      9f5fd6007e74cd82e037b800186422fc2da167c747ef045e5d18a5f5d43\
+                                      00f8e1a0291c";
+
+    fn ethereum_signing_key() -> secp256k1::SigningKey {
+        secp256k1::SigningKey::from_slice(&hex::decode(ETHEREUM_PRIVATE_KEY).unwrap()).unwrap()
+    }
+
+    fn send_text_body() -> TransactionBody {
+        let send_text = SendText {
+            text: "hello".to_string(),
+            from: "alice".to_string(),
+            fee_asset: asset(),
+        };
+        TransactionBody::builder()
+            .actions(vec![send_text.into()])
+            .chain_id("test-1")
+            .nonce(0)
+            .try_build()
+            .unwrap()
+    }
+
+    #[test]
+    fn secp256k1_signature_of_wallet_recovers_ethereum_address() {
+        let signature = hex::decode(ETHEREUM_SIGNATURE).unwrap();
+        let recovered =
+            secp256k1::Secp256k1Signature::recover(&signature, &Bytes::new(), b"Some data")
+                .unwrap();
+
+        assert_eq!(hex::encode(recovered.address_bytes()), ETHEREUM_ADDRESS);
+        assert_eq!(
+            secp256k1::address_bytes(ethereum_signing_key().verifying_key()),
+            *recovered.address_bytes(),
+        );
+    }
+
+    #[test]
+    fn secp256k1_transaction_verification_roundtrip() {
+        let raw = send_text_body()
+            .sign_secp256k1(&ethereum_signing_key())
+            .to_raw();
+
+        let transaction = Transaction::try_from_raw(raw.clone()).unwrap();
+        assert_eq!(hex::encode(transaction.address_bytes()), ETHEREUM_ADDRESS);
+        assert!(matches!(transaction.signer(), Signer::Secp256k1(_)));
+        assert_eq!(transaction.to_raw(), raw);
+    }
+
+    #[test]
+    fn secp256k1_signature_has_a_single_encoding() {
+        let mut raw = send_text_body()
+            .sign_secp256k1(&ethereum_signing_key())
+            .to_raw();
+        let v = raw.signature[secp256k1::SIGNATURE_LEN - 1];
+        assert!(matches!(v, 27 | 28));
+
+        // the raw recovery id names the same key, but is a second encoding of the signature
+        let mut signature = raw.signature.to_vec();
+        signature[secp256k1::SIGNATURE_LEN - 1] = v - 27;
+        raw.signature = signature.into();
+        assert!(Transaction::try_from_raw(raw).is_err());
+    }
+
+    #[test]
+    fn secp256k1_transaction_with_other_public_key_is_rejected() {
+        let signing_key = ethereum_signing_key();
+        let mut raw = send_text_body().sign_secp256k1(&signing_key).to_raw();
+        raw.public_key = Bytes::copy_from_slice(
+            signing_key
+                .verifying_key()
+                .to_encoded_point(true)
+                .as_bytes(),
+        );
+        Transaction::try_from_raw(raw.clone()).unwrap();
+
+        let other_key = secp256k1::SigningKey::from_slice(&[1; 32]).unwrap();
+        raw.public_key =
+            Bytes::copy_from_slice(other_key.verifying_key().to_encoded_point(true).as_bytes());
+        assert!(Transaction::try_from_raw(raw).is_err());
+    }
+
+    #[test]
+    fn signature_scheme_must_match_signature() {
+        let mut raw = send_text_body()
+            .sign_secp256k1(&ethereum_signing_key())
+            .to_raw();
+        raw.signature_scheme = raw::SignatureScheme::Ed25519.into();
+        assert!(Transaction::try_from_raw(raw).is_err());
+
+        let mut raw = send_text_body().sign(&SigningKey::from([1; 32])).to_raw();
+        raw.signature_scheme = raw::SignatureScheme::Secp256k1.into();
+        assert!(Transaction::try_from_raw(raw.clone()).is_err());
+
+        raw.signature_scheme = 2;
+        assert!(Transaction::try_from_raw(raw).is_err());
+    }
+
+    fn sponsored_body(sponsor: &SigningKey) -> TransactionBody {
+        let sponsor = Address::builder()
+            .array(*sponsor.verification_key().address_bytes())
+            .prefix(ASTRIA_ADDRESS_PREFIX)
+            .try_build()
+            .unwrap();
+        let send_text = SendText {
+            text: "hello".to_string(),
+            from: "alice".to_string(),
+            fee_asset: asset(),
+        };
+        TransactionBody::builder()
+            .actions(vec![send_text.into()])
+            .chain_id("test-1")
+            .nonce(0)
+            .sponsor(sponsor)
+            .try_build()
+            .unwrap()
+    }
+
+    #[test]
+    fn sponsored_transaction_verification_roundtrip() {
+        let sender = SigningKey::from([1; 32]);
+        let sponsor = SigningKey::from([2; 32]);
+        let signed_tx = sponsored_body(&sponsor)
+            .sign(&sender)
+            .sign_as_sponsor(&sponsor)
+            .unwrap();
+
+        let transaction = Transaction::try_from_raw(signed_tx.to_raw()).unwrap();
+        assert_eq!(
+            transaction.address_bytes(),
+            sender.verification_key().address_bytes()
+        );
+        assert_eq!(
+            transaction.fee_payer_address_bytes(),
+            sponsor.verification_key().address_bytes()
+        );
+    }
+
+    #[test]
+    fn sponsored_transaction_without_sponsor_signature_is_rejected() {
+        let sender = SigningKey::from([1; 32]);
+        let sponsor = SigningKey::from([2; 32]);
+        let raw = sponsored_body(&sponsor).sign(&sender).to_raw();
+
+        assert!(Transaction::try_from_raw(raw).is_err());
+    }
+
+    #[test]
+    fn sponsor_signature_by_other_key_is_rejected() {
+        let sender = SigningKey::from([1; 32]);
+        let sponsor = SigningKey::from([2; 32]);
+        let body = sponsored_body(&sponsor);
+
+        assert!(body.clone().sign(&sender).sign_as_sponsor(&sender).is_err());
+
+        let mut raw = body
+            .sign(&sender)
+            .sign_as_sponsor(&sponsor)
+            .unwrap()
+            .to_raw();
+        let forged = raw.sponsor_signature.as_mut().unwrap();
+        forged.public_key = Bytes::copy_from_slice(&sender.verification_key().to_bytes());
+        assert!(Transaction::try_from_raw(raw).is_err());
+    }
+
+    #[test]
+    fn sponsor_signature_cannot_be_used_as_sender_signature() {
+        let sender = SigningKey::from([1; 32]);
+        let sponsor = SigningKey::from([2; 32]);
+        let mut raw = sponsored_body(&sponsor)
+            .sign(&sender)
+            .sign_as_sponsor(&sponsor)
+            .unwrap()
+            .to_raw();
+        let sponsor_signature = raw.sponsor_signature.clone().unwrap();
+        raw.signature = sponsor_signature.signature;
+        raw.public_key = sponsor_signature.public_key;
+
+        assert!(Transaction::try_from_raw(raw).is_err());
+    }
+
+    #[test]
+    fn transaction_signed_by_session_key_acts_for_granter() {
+        let session_key = SigningKey::from([1; 32]);
+        let granter = Address::builder()
+            .array([9; 20])
+            .prefix(ASTRIA_ADDRESS_PREFIX)
+            .try_build()
+            .unwrap();
+        let send_text = SendText {
+            text: "hello".to_string(),
+            from: "alice".to_string(),
+            fee_asset: asset(),
+        };
+        let signed_tx = TransactionBody::builder()
+            .actions(vec![send_text.into()])
+            .chain_id("test-1")
+            .nonce(0)
+            .granter(granter)
+            .try_build()
+            .unwrap()
+            .sign(&session_key);
+
+        let transaction = Transaction::try_from_raw(signed_tx.to_raw()).unwrap();
+        assert_eq!(transaction.address_bytes(), &[9; 20]);
+        assert_eq!(
+            transaction.signer_address_bytes(),
+            session_key.verification_key().address_bytes()
+        );
+        assert_eq!(transaction.fee_payer_address_bytes(), &[9; 20]);
+    }
+
+    fn multisig_body() -> TransactionBody {
+        let multisig = Address::builder()
+            .array([7; 20])
+            .prefix(ASTRIA_ADDRESS_PREFIX)
+            .try_build()
+            .unwrap();
+        let send_text = SendText {
+            text: "hello".to_string(),
+            from: "treasury".to_string(),
+            fee_asset: asset(),
+        };
+        TransactionBody::builder()
+            .actions(vec![send_text.into()])
+            .chain_id("test-1")
+            .nonce(0)
+            .multisig(multisig)
+            .try_build()
+            .unwrap()
+    }
+
+    #[test]
+    fn cosigned_transaction_verification_roundtrip() {
+        let signed_tx = multisig_body()
+            .sign(&SigningKey::from([1; 32]))
+            .cosign(&SigningKey::from([2; 32]))
+            .unwrap()
+            .cosign(&SigningKey::from([3; 32]))
+            .unwrap();
+
+        let transaction = Transaction::try_from_raw(signed_tx.to_raw()).unwrap();
+        assert_eq!(transaction.address_bytes(), &[7; 20]);
+        assert_eq!(transaction.signers().count(), 3);
+    }
+
+    #[test]
+    fn transaction_cannot_be_signed_twice_by_the_same_key() {
+        let signed_tx = multisig_body().sign(&SigningKey::from([1; 32]));
+        assert!(signed_tx
+            .clone()
+            .cosign(&SigningKey::from([1; 32]))
+            .is_err());
+
+        let mut raw = signed_tx
+            .cosign(&SigningKey::from([2; 32]))
+            .unwrap()
+            .to_raw();
+        let duplicate = raw.cosignatures[0].clone();
+        raw.cosignatures.push(duplicate);
+        assert!(Transaction::try_from_raw(raw).is_err());
+    }
+
+    #[test]
+    fn cosignatures_require_multisig_account() {
+        let to_address: Address = Address::builder()
+            .array([0; 20])
+            .prefix(ASTRIA_ADDRESS_PREFIX)
+            .try_build()
+            .unwrap();
+        let signed_tx = TransactionBody::builder()
+            .actions(vec![Transfer {
+                to: to_address,
+                amount: 0,
+                asset: asset(),
+                fee_asset: asset(),
+            }
+            .into()])
+            .chain_id("test-1")
+            .nonce(0)
+            .try_build()
+            .unwrap()
+            .sign(&SigningKey::from([1; 32]));
+
+        assert!(signed_tx.cosign(&SigningKey::from([2; 32])).is_err());
+    }
+
+    #[test]
+    fn multisig_transaction_cannot_be_signed_with_secp256k1_key() {
+        let signed_tx = multisig_body().sign_secp256k1(&ethereum_signing_key());
+        assert!(Transaction::try_from_raw(signed_tx.to_raw()).is_err());
+        assert!(signed_tx.cosign(&SigningKey::from([2; 32])).is_err());
+    }
+
+    #[test]
+    fn multisig_address_does_not_depend_on_key_order() {
+        let keys: Vec<_> = (1..=3)
+            .map(|i| SigningKey::from([i; 32]).verification_key())
+            .collect();
+        let reversed = keys.iter().rev().cloned().collect();
+
+        assert_eq!(
+            RegisterMultisig::try_new(keys.clone(), 2)
+                .unwrap()
+                .address_bytes(),
+            RegisterMultisig::try_new(reversed, 2)
+                .unwrap()
+                .address_bytes(),
+        );
+        assert_ne!(
+            RegisterMultisig::try_new(keys.clone(), 2)
+                .unwrap()
+                .address_bytes(),
+            RegisterMultisig::try_new(keys, 3).unwrap().address_bytes(),
+        );
+    }
+
+    #[test]
+    fn multisig_threshold_must_be_reachable() {
+        let keys: Vec<_> = (1..=3)
+            .map(|i| SigningKey::from([i; 32]).verification_key())
+            .collect();
+
+        assert!(RegisterMultisig::try_new(keys.clone(), 0).is_err());
+        assert!(RegisterMultisig::try_new(keys.clone(), 4).is_err());
+        assert!(RegisterMultisig::try_new(vec![keys[0].clone(), keys[0].clone()], 1).is_err());
+    }
+
+    #[test]
+    fn multisig_members_cannot_be_secp256k1_keys() {
+        let secp256k1_key = ethereum_signing_key()
+            .verifying_key()
+            .to_encoded_point(true);
+        let raw = raw::RegisterMultisig {
+            public_keys: vec![
+                Bytes::copy_from_slice(&SigningKey::from([1; 32]).verification_key().to_bytes()),
+                Bytes::copy_from_slice(secp256k1_key.as_bytes()),
+            ],
+            threshold: 1,
+        };
+        assert!(matches!(
+            RegisterMultisig::try_from_raw(raw),
+            Err(err) if err.to_string().contains("invalid verification key"),
+        ));
+    }
 }
@@ -0,0 +1,182 @@
+//! Recoverable secp256k1 signatures, as made by Ethereum wallets.
+//!
+//! A wallet signs the EIP-191 (`personal_sign`) hash of the transaction body bytes. The signer is
+//! identified by its Ethereum address, the last 20 bytes of the keccak256 hash of its uncompressed
+//! public key.
+
+use astria_core::primitive::v1::ADDRESS_LEN;
+use bytes::Bytes;
+use k256::ecdsa::{RecoveryId, Signature};
+pub use k256::ecdsa::{SigningKey, VerifyingKey};
+use sha3::{Digest as _, Keccak256};
+
+/// The length of a recoverable signature, `r || s || v`.
+pub const SIGNATURE_LEN: usize = 65;
+
+const EIP191_PREFIX: &[u8] = b"\x19Ethereum Signed Message:\n";
+
+/// Ethereum wallets add 27 to the recovery id.
+const ETHEREUM_RECOVERY_ID_OFFSET: u8 = 27;
+
+/// Returns the EIP-191 (`personal_sign`) hash of `message`.
+#[must_use]
+pub fn eip191_hash(message: &[u8]) -> [u8; 32] {
+    Keccak256::new()
+        .chain_update(EIP191_PREFIX)
+        .chain_update(message.len().to_string())
+        .chain_update(message)
+        .finalize()
+        .into()
+}
+
+/// Returns the Ethereum address of `verifying_key`.
+#[must_use]
+pub fn address_bytes(verifying_key: &VerifyingKey) -> [u8; ADDRESS_LEN] {
+    let point = verifying_key.to_encoded_point(false);
+    // skip the leading `0x04` tag of the uncompressed encoding
+    let hash = Keccak256::digest(&point.as_bytes()[1..]);
+    let mut address = [0; ADDRESS_LEN];
+    address.copy_from_slice(&hash[hash.len() - ADDRESS_LEN..]);
+    address
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct Secp256k1Error(Secp256k1ErrorKind);
+
+impl Secp256k1Error {
+    fn signature_length(got: usize) -> Self {
+        Self(Secp256k1ErrorKind::SignatureLength { got })
+    }
+
+    fn recovery_id(got: u8) -> Self {
+        Self(Secp256k1ErrorKind::RecoveryId { got })
+    }
+
+    fn signature(inner: k256::ecdsa::Error) -> Self {
+        Self(Secp256k1ErrorKind::Signature(inner))
+    }
+
+    fn recovery(inner: k256::ecdsa::Error) -> Self {
+        Self(Secp256k1ErrorKind::Recovery(inner))
+    }
+
+    fn public_key(inner: k256::ecdsa::Error) -> Self {
+        Self(Secp256k1ErrorKind::PublicKey(inner))
+    }
+
+    fn public_key_mismatch() -> Self {
+        Self(Secp256k1ErrorKind::PublicKeyMismatch)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum Secp256k1ErrorKind {
+    #[error("expected a signature of `{SIGNATURE_LEN}` bytes, got `{got}`")]
+    SignatureLength { got: usize },
+    #[error("expected a recovery id of 27 or 28, got `{got}`")]
+    RecoveryId { got: u8 },
+    #[error("`r || s` is not a valid signature")]
+    Signature(#[source] k256::ecdsa::Error),
+    #[error("no public key could be recovered from the signature")]
+    Recovery(#[source] k256::ecdsa::Error),
+    #[error("`public_key` is not a valid SEC1 encoded key")]
+    PublicKey(#[source] k256::ecdsa::Error),
+    #[error("`public_key` does not match the key recovered from the signature")]
+    PublicKeyMismatch,
+}
+
+/// A recoverable secp256k1 signature together with the key recovered from it.
+#[derive(Clone, Debug)]
+pub struct Secp256k1Signature {
+    signature: [u8; SIGNATURE_LEN],
+    public_key: Bytes,
+    verifying_key: VerifyingKey,
+    address_bytes: [u8; ADDRESS_LEN],
+}
+
+impl Secp256k1Signature {
+    /// Recovers the key that made `signature` over the EIP-191 hash of `message`.
+    ///
+    /// `public_key` is kept as is so that the transaction is encoded exactly as it was received.
+    ///
+    /// # Errors
+    /// Returns an error if `signature` is malformed, has a high `s` value or a recovery id `v`
+    /// other than 27 or 28, if no key can be recovered from it, or if `public_key` is set and is
+    /// not the recovered key.
+    pub(super) fn recover(
+        signature: &[u8],
+        public_key: &Bytes,
+        message: &[u8],
+    ) -> Result<Self, Secp256k1Error> {
+        let signature: [u8; SIGNATURE_LEN] = signature
+            .try_into()
+            .map_err(|_| Secp256k1Error::signature_length(signature.len()))?;
+        let (r_s, v) = signature.split_at(SIGNATURE_LEN - 1);
+        // only the form made by wallets is accepted, so that a signature has a single encoding
+        let recovery_id = match v[0] {
+            v @ (27 | 28) => v - ETHEREUM_RECOVERY_ID_OFFSET,
+            v => return Err(Secp256k1Error::recovery_id(v)),
+        };
+        let recovery_id =
+            RecoveryId::from_byte(recovery_id).expect("0 and 1 are valid recovery ids");
+        let r_s = Signature::from_slice(r_s).map_err(Secp256k1Error::signature)?;
+        // verifies the signature against the recovered key, which rejects high `s` values
+        let verifying_key =
+            VerifyingKey::recover_from_prehash(&eip191_hash(message), &r_s, recovery_id)
+                .map_err(Secp256k1Error::recovery)?;
+        if !public_key.is_empty() {
+            let expected =
+                VerifyingKey::from_sec1_bytes(public_key).map_err(Secp256k1Error::public_key)?;
+            if expected != verifying_key {
+                return Err(Secp256k1Error::public_key_mismatch());
+            }
+        }
+        Ok(Self {
+            signature,
+            public_key: public_key.clone(),
+            address_bytes: address_bytes(&verifying_key),
+            verifying_key,
+        })
+    }
+
+    /// Signs the EIP-191 hash of `message` the way an Ethereum wallet does.
+    pub(super) fn sign(signing_key: &SigningKey, message: &[u8]) -> Self {
+        let (r_s, recovery_id) = signing_key
+            .sign_prehash_recoverable(&eip191_hash(message))
+            .expect("signing a 32 byte hash is infallible");
+        let mut signature = [0; SIGNATURE_LEN];
+        signature[..SIGNATURE_LEN - 1].copy_from_slice(&r_s.to_bytes());
+        signature[SIGNATURE_LEN - 1] = recovery_id.to_byte() + ETHEREUM_RECOVERY_ID_OFFSET;
+        let verifying_key = *signing_key.verifying_key();
+        Self {
+            signature,
+            public_key: Bytes::new(),
+            address_bytes: address_bytes(&verifying_key),
+            verifying_key,
+        }
+    }
+
+    /// Returns the signature as `r || s || v`.
+    #[must_use]
+    pub fn to_bytes(&self) -> [u8; SIGNATURE_LEN] {
+        self.signature
+    }
+
+    /// Returns the public key as it was set in the transaction, which may be empty.
+    #[must_use]
+    pub fn public_key(&self) -> &Bytes {
+        &self.public_key
+    }
+
+    #[must_use]
+    pub fn verifying_key(&self) -> &VerifyingKey {
+        &self.verifying_key
+    }
+
+    /// Returns the Ethereum address of the signer.
+    #[must_use]
+    pub fn address_bytes(&self) -> &[u8; ADDRESS_LEN] {
+        &self.address_bytes
+    }
+}
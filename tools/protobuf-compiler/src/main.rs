@@ -63,6 +63,7 @@ fn main() {
         .bytes([
             ".transaction",
             ".genesis",
+            ".query",
             ".astria",
             ".celestia",
             ".cosmos",
@@ -106,6 +107,7 @@ fn main() {
         .build(&[
             ".transaction",
             ".genesis",
+            ".query",
             ".astria",
             ".astria_vendored",
             ".celestia",
@@ -191,7 +193,11 @@ fn clean_non_astria_code(generated: &mut ContentMap) {
     let mut foreign_file_names: HashSet<_> = generated
         .files
         .keys()
-        .filter(|name| !name.starts_with("transaction.") && !name.starts_with("genesis."))
+        .filter(|name| {
+            !name.starts_with("transaction.")
+                && !name.starts_with("genesis.")
+                && !name.starts_with("query.")
+        })
         .cloned()
         .collect();
     // also mask mod.rs because we need are defining it